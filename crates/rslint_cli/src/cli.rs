@@ -4,9 +4,12 @@ use crate::lint_err;
 use ansi_term::Color::{Green, White, RGB};
 use colored::Colorize;
 use regex::{Captures, Regex};
-use rslint_core::{get_rule_docs, CstRuleStore};
+use rslint_core::{
+    explain, get_rule_docs,
+    registry::{registry, RuleMeta},
+    Deprecation,
+};
 use rslint_lexer::{ansi_term, color};
-use std::collections::HashSet;
 
 /// A structure for converting user facing markdown docs to ANSI colored terminal explanations.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -18,18 +21,18 @@ pub struct ExplanationRunner {
 impl ExplanationRunner {
     /// Make a new runner and try to fetch the remote docs files for each rule.
     /// This automatically issues any linter errors for invalid rules.
-    pub fn new(mut rules: Vec<String>) -> Self {
-        let rule_names = rules.clone();
-        rules = rules
+    pub fn new(rules: Vec<String>) -> Self {
+        // invalid rules are dropped from both lists so the docs and names of rules stay paired
+        let (rule_names, rules) = rules
             .into_iter()
-            .filter_map(|rule| {
-                let res = get_rule_docs(&rule).map(|x| x.to_string());
-                if res.is_none() {
+            .filter_map(|rule| match get_rule_docs(&rule) {
+                Some(docs) => Some((rule, docs.to_string())),
+                None => {
                     lint_err!("Invalid rule: {}", rule);
+                    None
                 }
-                res
             })
-            .collect();
+            .unzip();
 
         Self { rules, rule_names }
     }
@@ -58,12 +61,7 @@ impl ExplanationRunner {
 
     pub fn strip_config_or_extra_examples(&mut self) {
         for rule in self.rules.iter_mut() {
-            if let Some(idx) = rule.find("# Config") {
-                rule.truncate(idx - 1);
-            }
-            if let Some(idx) = rule.find("<details>") {
-                rule.truncate(idx - 1);
-            }
+            explain::strip_config_or_extra_examples(rule);
         }
     }
 
@@ -83,16 +81,26 @@ impl ExplanationRunner {
         }
     }
 
+    /// Put the metadata of each rule, such as its category and whether it is deprecated, before its docs.
+    pub fn prepend_metadata(&mut self) {
+        for (docs, name) in self.rules.iter_mut().zip(self.rule_names.iter()) {
+            if let Some(metadata) = explain::metadata(name) {
+                docs.insert_str(0, &metadata);
+            }
+        }
+    }
+
     pub fn append_link_to_docs(&mut self) {
         for (docs, name) in self.rules.iter_mut().zip(self.rule_names.iter()) {
-            let group = rslint_core::get_rule_by_name(name).unwrap().group();
-            let link = format!("https://rslint.org/rules/{}/{}.html", group, name);
-            docs.push_str(&format!("{}: {}\n", Green.paint("Docs"), link));
+            if let Some((label, link)) = explain::docs_link(name) {
+                docs.push_str(&format!("{}: {}\n", Green.paint(label), link));
+            }
         }
     }
 
     pub fn render(&mut self) {
         self.strip_config_or_extra_examples();
+        self.prepend_metadata();
         self.replace_headers();
         self.replace_code_blocks();
         self.replace_inline_code_blocks();
@@ -108,28 +116,69 @@ impl ExplanationRunner {
     }
 }
 
-pub fn show_all_rules() {
-    let rules = CstRuleStore::new().builtins().rules;
-    let mut groups = HashSet::new();
-    rules.iter().for_each(|r| {
-        groups.insert(r.group());
-    });
+/// Print a table of the available rules, optionally only the fixable rules or the rules of a group.
+pub fn show_all_rules(fixable: bool, group: Option<&str>) {
+    let registry = registry();
+    if let Some(group) = group.filter(|group| !registry.groups().contains(group)) {
+        lint_err!("unknown rule group '{}'", group);
+        return;
+    }
 
-    for group in groups {
-        let group_rules = rules.iter().filter(|rule| rule.group() == group);
-        println!("{}:", group.bright_green());
-        let max_rule_len = group_rules
-            .clone()
-            .map(|r| r.name().len())
+    let rules = filter_rules(registry.rules(), fixable, group);
+    let width = |column: fn(&RuleMeta) -> String, header: &str| {
+        rules
+            .iter()
+            .map(|meta| column(meta).len())
+            .chain(std::iter::once(header.len()))
             .max()
-            .unwrap_or(0);
+            .unwrap_or_default()
+    };
+    let name_width = width(|meta| meta.name.to_string(), "name");
+    let category_width = width(category, "category");
+    let fix_width = width(fix, "fix");
+    let since_width = width(since, "since");
 
-        for rule in group_rules {
+    for group in registry.groups() {
+        let group_rules = rules.iter().filter(|meta| meta.group == *group);
+        if group_rules.clone().next().is_none() {
+            continue;
+        }
+        println!("{}:", group.bright_green());
+        println!(
+            " {:<name_width$}  {:<category_width$}  {:<fix_width$}  {:<since_width$}  description",
+            "name",
+            "category",
+            "fix",
+            "since",
+            name_width = name_width,
+            category_width = category_width,
+            fix_width = fix_width,
+            since_width = since_width,
+        );
+
+        for meta in group_rules {
+            let description = match meta.deprecated {
+                Some(Deprecation {
+                    replacement: Some(replacement),
+                }) => format!("deprecated, use `{}` instead", replacement),
+                Some(_) => "deprecated".to_string(),
+                None => meta.docs.lines().next().unwrap_or_default().to_string(),
+            };
+            let name = format!("{:<width$}", meta.name, width = name_width);
             println!(
-                " {}{} - {}",
-                rule.name().white(),
-                " ".repeat(max_rule_len - rule.name().len()),
-                rule.docs().lines().next().unwrap_or_default()
+                " {}  {:<category_width$}  {:<fix_width$}  {:<since_width$}  {}",
+                if meta.deprecated.is_some() {
+                    name.dimmed()
+                } else {
+                    name.white()
+                },
+                category(meta),
+                fix(meta),
+                since(meta),
+                description,
+                category_width = category_width,
+                fix_width = fix_width,
+                since_width = since_width,
             );
         }
         println!();
@@ -140,3 +189,68 @@ pub fn show_all_rules() {
         "help".bright_green()
     );
 }
+
+/// The rules listed by `rslint rules`, optionally only the fixable rules or the rules of a group.
+fn filter_rules<'a>(
+    rules: &'a [RuleMeta],
+    fixable: bool,
+    group: Option<&str>,
+) -> Vec<&'a RuleMeta> {
+    rules
+        .iter()
+        .filter(|meta| group.is_none() || group == Some(meta.group))
+        .filter(|meta| !fixable || meta.fixable)
+        .collect()
+}
+
+fn category(meta: &RuleMeta) -> String {
+    meta.category
+        .map_or_else(|| "-".to_string(), |category| category.to_string())
+}
+
+fn fix(meta: &RuleMeta) -> String {
+    meta.fix_kind().to_string()
+}
+
+fn since(meta: &RuleMeta) -> String {
+    meta.since.unwrap_or("-").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_rules_by_fix_and_group() {
+        let registry = registry();
+        let names = |fixable, group| {
+            filter_rules(registry.rules(), fixable, group)
+                .into_iter()
+                .map(|meta| meta.name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(false, None).len(), registry.rules().len());
+
+        let fixable = names(true, None);
+        assert!(fixable.contains(&"block-spacing"));
+        assert!(!fixable.contains(&"no-empty"));
+        assert!(fixable
+            .iter()
+            .all(|name| registry.rule(name).unwrap().fixable));
+
+        let regex = names(false, Some("regex"));
+        assert!(regex.contains(&"no-invalid-regexp"));
+        assert!(regex
+            .iter()
+            .all(|name| registry.rule(name).unwrap().group == "regex"));
+
+        let fixable_style = names(true, Some("style"));
+        assert!(fixable_style.contains(&"block-spacing"));
+        assert!(fixable_style.iter().all(|name| {
+            let meta = registry.rule(name).unwrap();
+            meta.fixable && meta.group == "style"
+        }));
+        assert!(names(false, Some("nope")).is_empty());
+    }
+}
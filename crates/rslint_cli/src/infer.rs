@@ -1,11 +1,20 @@
 use crate::*;
 use colored::Colorize;
 use glob::glob;
-use rslint_core::get_inferable_rules;
+use rslint_config::{configures_rule, find_config, key_path};
+use rslint_core::{get_inferable_rules, Inferable, InferredOption};
 use rslint_parser::SyntaxNode;
-use toml::to_string_pretty;
+use std::ops::Range;
+use std::path::PathBuf;
+use taplo::syntax::{SyntaxKind, SyntaxNode as TomlNode};
 
-pub fn infer(files: Vec<String>) {
+/// The options inferred for a rule, with their values as TOML.
+struct InferredRule {
+    name: &'static str,
+    options: Vec<(InferredOption, String)>,
+}
+
+pub fn infer(files: Vec<String>, write: bool) {
     let globs = files
         .into_iter()
         .filter_map(|x| match glob(&x) {
@@ -22,14 +31,489 @@ pub fn infer(files: Vec<String>) {
     let walker = FileWalker::from_glob_parallel(globs, 1);
     let parsed = walker.files.values().map(|f| f.parse());
     let nodes: Vec<SyntaxNode> = parsed.flat_map(|n| n.descendants()).collect();
-    let rules = get_inferable_rules();
-    let mut inferred = Vec::with_capacity(rules.len());
+    let mut inferred = vec![];
+
+    for mut rule in get_inferable_rules() {
+        let options = rule.infer(&nodes);
+        if !options.is_empty() {
+            inferred.push(InferredRule {
+                name: rule.name(),
+                options: option_values(&*rule, options),
+            });
+        }
+    }
+
+    if inferred.is_empty() {
+        lint_note!("no rule options could be inferred from the files");
+        return;
+    }
+
+    let tables = if write {
+        match write_inferred(&inferred) {
+            Ok((path, tables)) => {
+                println!(
+                    "{}\n",
+                    format!("Wrote inferred rules to {}:", path.display()).bright_green()
+                );
+                tables
+            }
+            Err(err) => {
+                lint_err!("{}", err);
+                return;
+            }
+        }
+    } else {
+        println!("{}\n", "Inferred rules:".bright_green());
+        inferred
+            .iter()
+            .map(|rule| format!("rules.errors.{}", rule.name))
+            .collect()
+    };
+
+    for (rule, table) in inferred.iter().zip(tables) {
+        println!("[{}]", table);
+        for (option, value) in &rule.options {
+            let line = format!("{} = {}", option.name, value);
+            println!("{:<24} {}", line, confidence(option.confidence));
+        }
+        println!();
+    }
+}
+
+/// Pair every inferred option with its new value rendered as TOML.
+fn option_values(
+    rule: &dyn Inferable,
+    options: Vec<InferredOption>,
+) -> Vec<(InferredOption, String)> {
+    // rules are serialized as `{ "RuleName": { ...options } }`
+    let serialized = serde_json::to_value(rule).ok();
+    let fields = serialized
+        .as_ref()
+        .and_then(|value| value.as_object())
+        .and_then(|tagged| tagged.values().next());
+
+    options
+        .into_iter()
+        .filter_map(|option| {
+            let value = fields?.get(option.name)?.clone();
+            let value = toml::Value::try_from(value).ok()?;
+            Some((option, value.to_string()))
+        })
+        .collect()
+}
+
+fn confidence(confidence: f32) -> String {
+    let text = format!("# {:.0}% confidence", confidence * 100.0);
+    if confidence >= 0.8 {
+        text.bright_green().to_string()
+    } else if confidence >= 0.5 {
+        text.bright_yellow().to_string()
+    } else {
+        text.bright_red().to_string()
+    }
+}
+
+/// Merge the inferred options into the config of the current directory or its ancestors,
+/// creating an `rslintrc.toml` if there is none. Returns the path of the config and the table
+/// the options of every rule were written to.
+fn write_inferred(inferred: &[InferredRule]) -> Result<(PathBuf, Vec<String>), String> {
+    let cwd = std::env::current_dir()
+        .map_err(|err| format!("failed to get the current directory: {}", err))?;
+    let path = find_config(&cwd).unwrap_or_else(|| cwd.join("rslintrc.toml"));
+    if path.extension() == Some("json".as_ref()) {
+        return Err(format!(
+            "inferred options can only be written to an `rslintrc.toml`, but the config is `{}`",
+            path.display()
+        ));
+    }
+
+    let text = if path.exists() {
+        std::fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read `{}`: {}", path.display(), err))?
+    } else {
+        String::new()
+    };
+
+    let mut new_text = text.clone();
+    let mut tables = vec![];
+    for rule in inferred {
+        let (edits, table) = set_options_edits(&new_text, rule);
+        new_text = apply_edits(&new_text, edits);
+        tables.push(table);
+    }
+
+    if let Err((_, diagnostics)) =
+        rslint_config::Config::from_source(new_text.clone(), path.clone())
+    {
+        let titles = diagnostics
+            .into_iter()
+            .map(|d| d.title)
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(format!(
+            "refusing to write `{}` because the merged config would be invalid: {}",
+            path.display(),
+            titles
+        ));
+    }
+
+    std::fs::write(&path, new_text)
+        .map_err(|err| format!("failed to write `{}`: {}", path.display(), err))?;
+    Ok((path, tables))
+}
+
+fn apply_edits(text: &str, mut edits: Vec<(Range<usize>, String)>) -> String {
+    let mut text = text.to_string();
+    edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+    for (range, new_text) in edits {
+        text.replace_range(range, &new_text);
+    }
+    text
+}
+
+/// Compute the edits to a config which set the inferred options of a rule. Options which are
+/// already configured get their values replaced, and missing options are added where the rule is
+/// configured, whether it is under `errors` or `warnings`. Rules which are not configured are added
+/// to the `errors` rules, everything else in the config is left untouched. Returns the edits and the
+/// key of the table the options are set in, such as `rules.warnings.block-spacing`.
+fn set_options_edits(text: &str, rule: &InferredRule) -> (Vec<(Range<usize>, String)>, String) {
+    let root = taplo::parser::parse(text).into_syntax();
+    let mut edits = vec![];
+    let mut missing: Vec<&(InferredOption, String)> = rule.options.iter().collect();
+
+    let mut table: Vec<String> = vec![];
+    let mut in_rule_table = false;
+    // the end of the last line of the rule table and of the `[rules.errors]` table
+    let mut rule_table_end = None;
+    let mut errors_table_end = None;
+    // the key of the first table header configuring the rule
+    let mut rule_table = None;
+    let mut inline_table = None;
+    // the end of the last entry setting an option with a dotted key, the key of the rule in it
+    // and the key of the rule from the root
+    let mut dotted_entry = None;
+
+    for node in root.children() {
+        let key = node
+            .children()
+            .find(|child| child.kind() == SyntaxKind::KEY)
+            .map(|key| key_path(&key.text().to_string()))
+            .unwrap_or_default();
+
+        match node.kind() {
+            SyntaxKind::TABLE_HEADER | SyntaxKind::TABLE_ARRAY_HEADER => {
+                in_rule_table = configures_rule(&key, rule.name);
+                let end = Some(usize::from(node.text_range().end()));
+                if in_rule_table {
+                    rule_table_end = end;
+                    rule_table = rule_table.or_else(|| Some(key[..3].join(".")));
+                } else if key == ["rules", "errors"] {
+                    errors_table_end = end;
+                }
+                table = key;
+            }
+            SyntaxKind::ENTRY => {
+                let end = usize::from(node.text_range().end());
+                if in_rule_table {
+                    rule_table_end = Some(end);
+                } else if table == ["rules", "errors"] {
+                    errors_table_end = Some(end);
+                }
 
-    for mut rule in rules {
-        rule.infer(&nodes);
-        inferred.push(rule);
+                let path = table.iter().cloned().chain(key).collect::<Vec<_>>();
+                if let [option] = path.get(3..).unwrap_or_default() {
+                    if configures_rule(&path, rule.name) {
+                        set_value(&node, option, &mut missing, &mut edits);
+                        if !in_rule_table {
+                            dotted_entry =
+                                Some((end, path[table.len()..3].join("."), path[..3].join(".")));
+                        }
+                    }
+                } else if path.len() <= 3 {
+                    inline_table =
+                        inline_table.or_else(|| rule_inline_table(&node, &path, rule.name));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((inline, inline_path)) = inline_table {
+        for entry in inline
+            .children()
+            .filter(|child| child.kind() == SyntaxKind::ENTRY)
+        {
+            if let Some(key) = entry
+                .children()
+                .find(|child| child.kind() == SyntaxKind::KEY)
+            {
+                if let [option] = &key_path(&key.text().to_string())[..] {
+                    set_value(&entry, option, &mut missing, &mut edits);
+                }
+            }
+        }
+        if !missing.is_empty() {
+            let entries = missing
+                .iter()
+                .map(|(option, value)| format!("{} = {}", option.name, value))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let last_entry = inline
+                .children()
+                .filter(|child| child.kind() == SyntaxKind::ENTRY)
+                .last();
+            let (range, new_text) = match last_entry {
+                // entries own the whitespace after them
+                Some(entry) => {
+                    let offset = entry
+                        .descendants_with_tokens()
+                        .filter_map(|elem| elem.into_token())
+                        .filter(|tok| {
+                            !matches!(tok.kind(), SyntaxKind::WHITESPACE | SyntaxKind::NEWLINE)
+                        })
+                        .last()
+                        .map_or(usize::from(entry.text_range().end()), |tok| {
+                            usize::from(tok.text_range().end())
+                        });
+                    (offset..offset, format!(", {}", entries))
+                }
+                // replace the whitespace inside of an empty table
+                None => {
+                    let range = inline.text_range();
+                    let start = usize::from(range.start()) + 1;
+                    let end = usize::from(range.end()) - 1;
+                    (start..end, format!(" {} ", entries))
+                }
+            };
+            edits.push((range, new_text));
+        }
+        return (edits, inline_path.join("."));
+    }
+
+    let table = rule_table
+        .or_else(|| dotted_entry.as_ref().map(|(_, _, table)| table.clone()))
+        .unwrap_or_else(|| format!("rules.errors.{}", rule.name));
+    if missing.is_empty() {
+        return (edits, table);
+    }
+    let lines = missing
+        .iter()
+        .map(|(option, value)| format!("{} = {}\n", option.name, value))
+        .collect::<String>();
+
+    if let Some(offset) = rule_table_end {
+        edits.push((offset..offset, format!("\n{}", lines.trim_end())));
+    } else if let Some((offset, rule_key, _)) = dotted_entry {
+        let new_text = missing
+            .iter()
+            .map(|(option, value)| format!("\n{}.{} = {}", rule_key, option.name, value))
+            .collect::<String>();
+        edits.push((offset..offset, new_text));
+    } else if let Some(offset) = errors_table_end {
+        let entries = missing
+            .iter()
+            .map(|(option, value)| format!("{} = {}", option.name, value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let new_text = format!("\n{} = {{ {} }}", rule.name, entries);
+        edits.push((offset..offset, new_text));
+    } else {
+        let separator = match text {
+            "" => "",
+            _ if text.ends_with('\n') => "\n",
+            _ => "\n\n",
+        };
+        let new_text = format!("{}[rules.errors.{}]\n{}", separator, rule.name, lines);
+        edits.push((text.len()..text.len(), new_text));
+    }
+    (edits, table)
+}
+
+/// Find the inline table configuring a rule in the value of an entry, or in the inline tables
+/// nested in it, such as `warnings = { no-empty = {} }` under `[rules]`, along with its key path.
+fn rule_inline_table(
+    entry: &TomlNode,
+    path: &[String],
+    rule: &str,
+) -> Option<(TomlNode, Vec<String>)> {
+    let inline = value(entry)?
+        .first_child()
+        .filter(|child| child.kind() == SyntaxKind::INLINE_TABLE)?;
+    if path.len() == 3 {
+        return Some((inline, path.to_vec())).filter(|_| configures_rule(path, rule));
+    }
+
+    inline
+        .children()
+        .filter(|child| child.kind() == SyntaxKind::ENTRY)
+        .find_map(|child| {
+            let key = child
+                .children()
+                .find(|child| child.kind() == SyntaxKind::KEY)?;
+            let path = path
+                .iter()
+                .cloned()
+                .chain(key_path(&key.text().to_string()))
+                .collect::<Vec<_>>();
+            if path.len() > 3 {
+                return None;
+            }
+            rule_inline_table(&child, &path, rule)
+        })
+}
+
+/// Replace the value of an entry if it sets one of the missing options.
+fn set_value(
+    entry: &TomlNode,
+    option: &str,
+    missing: &mut Vec<&(InferredOption, String)>,
+    edits: &mut Vec<(Range<usize>, String)>,
+) {
+    let idx = match missing
+        .iter()
+        .position(|(inferred, _)| inferred.name == option)
+    {
+        Some(idx) => idx,
+        None => return,
+    };
+    // the value node also contains the comments after the value
+    if let Some(value) = value(entry).and_then(|value| value.first_child_or_token()) {
+        let range = value.text_range();
+        edits.push((
+            usize::from(range.start())..usize::from(range.end()),
+            missing.remove(idx).1.clone(),
+        ));
+    }
+}
+
+fn value(entry: &TomlNode) -> Option<TomlNode> {
+    entry
+        .children()
+        .find(|child| child.kind() == SyntaxKind::VALUE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Set the options of `block-spacing` in a config.
+    fn set_options(text: &str, options: &[(&'static str, &str)]) -> String {
+        let (edits, _) = set_options_edits(text, &block_spacing(options));
+        apply_edits(text, edits)
+    }
+
+    /// The key of the table the options of `block-spacing` are set in.
+    fn options_table(text: &str) -> String {
+        set_options_edits(text, &block_spacing(&[("style", "\"always\"")])).1
+    }
+
+    fn block_spacing(options: &[(&'static str, &str)]) -> InferredRule {
+        InferredRule {
+            name: "block-spacing",
+            options: options
+                .iter()
+                .map(|(name, value)| {
+                    let option = InferredOption {
+                        name,
+                        confidence: 1.0,
+                    };
+                    (option, value.to_string())
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn creates_missing_config() {
+        assert_eq!(
+            set_options("", &[("style", "\"always\"")]),
+            "[rules.errors.block-spacing]\nstyle = \"always\"\n"
+        );
+        assert_eq!(
+            set_options("[rules]\ngroups = [\"errors\"]", &[("style", "\"always\"")]),
+            "[rules]\ngroups = [\"errors\"]\n\n[rules.errors.block-spacing]\nstyle = \"always\"\n"
+        );
     }
 
-    println!("{}\n", "Inferred rules:".bright_green());
-    println!("{}", to_string_pretty(&inferred).unwrap());
+    #[test]
+    fn sets_options_in_rule_table() {
+        assert_eq!(
+            set_options(
+                "[rules.errors.block-spacing]\nstyle = \"never\" # keep\n\n[parser]\n",
+                &[("style", "\"always\""), ("other", "1")]
+            ),
+            "[rules.errors.block-spacing]\nstyle = \"always\" # keep\nother = 1\n\n[parser]\n"
+        );
+    }
+
+    #[test]
+    fn adds_rule_to_errors_table() {
+        assert_eq!(
+            set_options(
+                "[rules.errors]\nno-empty = {}\n",
+                &[("style", "\"always\""), ("other", "1")]
+            ),
+            "[rules.errors]\nno-empty = {}\nblock-spacing = { style = \"always\", other = 1 }\n"
+        );
+    }
+
+    #[test]
+    fn sets_options_in_inline_table() {
+        assert_eq!(
+            set_options(
+                "[rules.errors]\nblock-spacing = { style = \"never\" }\n",
+                &[("style", "\"always\""), ("other", "1")]
+            ),
+            "[rules.errors]\nblock-spacing = { style = \"always\", other = 1 }\n"
+        );
+        assert_eq!(
+            set_options(
+                "[rules.errors]\nblock-spacing = {}\n",
+                &[("style", "\"always\"")]
+            ),
+            "[rules.errors]\nblock-spacing = { style = \"always\" }\n"
+        );
+    }
+
+    #[test]
+    fn keeps_rules_under_warnings() {
+        assert_eq!(
+            set_options(
+                "[rules.warnings.block-spacing]\nstyle = \"never\"\n",
+                &[("style", "\"always\""), ("other", "1")]
+            ),
+            "[rules.warnings.block-spacing]\nstyle = \"always\"\nother = 1\n"
+        );
+        assert_eq!(
+            set_options(
+                "[rules.errors]\n\n[rules.warnings]\nblock-spacing.style = \"never\"\n",
+                &[("style", "\"always\""), ("other", "1")]
+            ),
+            "[rules.errors]\n\n[rules.warnings]\nblock-spacing.style = \"always\"\nblock-spacing.other = 1\n"
+        );
+        assert_eq!(
+            set_options(
+                "[rules]\nwarnings = { block-spacing = { style = \"never\" } }\n",
+                &[("style", "\"always\""), ("other", "1")]
+            ),
+            "[rules]\nwarnings = { block-spacing = { style = \"always\", other = 1 } }\n"
+        );
+    }
+
+    #[test]
+    fn reports_the_table_of_the_options() {
+        assert_eq!(options_table(""), "rules.errors.block-spacing");
+        assert_eq!(
+            options_table("[rules.warnings.blockSpacing]\nstyle = \"never\"\n"),
+            "rules.warnings.blockSpacing"
+        );
+        assert_eq!(
+            options_table("[rules.warnings]\nblock-spacing.style = \"never\"\n"),
+            "rules.warnings.block-spacing"
+        );
+        assert_eq!(
+            options_table("[rules]\nwarnings = { block-spacing = {} }\n"),
+            "rules.warnings.block-spacing"
+        );
+    }
 }
@@ -1,11 +1,9 @@
 mod cli;
-mod files;
 mod infer;
 mod panic_hook;
 
 pub use self::{
     cli::{show_all_rules, ExplanationRunner},
-    files::*,
     infer::infer,
     panic_hook::*,
 };
@@ -14,12 +12,14 @@ pub use rslint_core::Outcome;
 pub use rslint_errors::{
     file, file::Files, Diagnostic, Emitter, Formatter, LongFormatter, Severity, ShortFormatter,
 };
+pub use rslint_files::*;
 
 use colored::*;
 use rslint_core::{autofix::recursively_apply_fixes, File};
 use rslint_core::{lint_file, util::find_best_match_for_name, LintResult, RuleLevel};
 use rslint_lexer::Lexer;
-use rslint_parser::FileKind;
+use rslint_text_edit::{apply_indels, Indel, TextRange, TextSize};
+use std::collections::HashMap;
 #[allow(unused_imports)]
 use std::process;
 use std::{fs::write, path::PathBuf};
@@ -38,6 +38,7 @@ pub fn run(
     no_ignore: bool,
     ignore_file: Option<PathBuf>,
     use_gitignore: bool,
+    max_warnings: Option<usize>,
 ) {
     let exit_code = run_inner(
         globs,
@@ -50,11 +51,23 @@ pub fn run(
         no_ignore,
         ignore_file,
         use_gitignore,
+        max_warnings,
     );
     #[cfg(not(debug_assertions))]
     process::exit(exit_code);
 }
 
+/// Load the plugins listed in the config, so their rules can be configured, listed and explained.
+pub fn load_config_plugins(no_global_config: bool) {
+    // SAFETY: the CLI is run by the user on their own project, so the plugins of its config are
+    // trusted like the rest of the project, such as its build scripts. The LSP does not load
+    // plugins unless the user opts in.
+    if let Err(err) = unsafe { config::Config::load_plugins(no_global_config) } {
+        let (file, d) = *err;
+        emit_diagnostic(&d, &file);
+    }
+}
+
 /// The inner function for run to call destructors before we call [`process::exit`]
 #[allow(clippy::too_many_arguments)] // this is temporary, and should be refactored soon
 fn run_inner(
@@ -68,34 +81,40 @@ fn run_inner(
     no_ignore: bool,
     ignore_file: Option<PathBuf>,
     use_gitignore: bool,
+    max_warnings: Option<usize>,
 ) -> i32 {
+    load_config_plugins(no_global_config);
     let config = match config::Config::new(no_global_config) {
         Ok(cfg) => cfg,
-        Err((file, d)) => {
-            emit_diagnostic(&d, &file);
+        Err((file, diagnostics)) => {
+            for d in diagnostics {
+                emit_diagnostic(&d, &file);
+            }
             config::Config::default()
         }
     };
 
     let mut walker = FileWalker::empty();
-    walker.load_files_parallel(
-        collect_globs(globs).into_iter(),
+    let options = LoadOptions {
         num_threads,
         no_ignore,
         ignore_file,
         use_gitignore,
-        config.parser().and_then(|cfg| {
-            Some(match cfg.source_type? {
-                config::SourceType::Module => FileKind::Module,
-                config::SourceType::Script => FileKind::Script,
-                config::SourceType::TypeScript => FileKind::TypeScript,
-            })
-        }),
-    );
+        overwrite_filetype: None,
+    };
+    walker.load_files_parallel(collect_globs(globs).into_iter(), &options, &config);
+
+    for file in walker.files.values_mut() {
+        let syntax = config.syntax(file.path.as_deref(), file.syntax);
+        file.overwrite_syntax(syntax);
+    }
 
     let mut formatter = formatter.unwrap_or_else(|| config.formatter());
     let (store, warnings) = config.rules_store();
-    emit_diagnostics("long", &warnings, &walker);
+    match config.file() {
+        Some(file) => emit_diagnostics("long", &warnings, file),
+        None => emit_diagnostics("long", &warnings, &walker),
+    }
 
     verify_formatter(&mut formatter);
 
@@ -132,10 +151,24 @@ fn run_inner(
 
     // print_results remaps the result to the appropriate severity
     // so these diagnostic severities should be accurate
-    if results
-        .iter()
-        .flat_map(|res| res.diagnostics())
-        .any(|d| matches!(d.severity, Severity::Bug | Severity::Error))
+    let diagnostics = || results.iter().flat_map(|res| res.diagnostics());
+    let warning_count = diagnostics()
+        .filter(|d| d.severity == Severity::Warning)
+        .count();
+    let too_many_warnings = match max_warnings {
+        Some(max) if warning_count > max => {
+            lint_err!(
+                "too many warnings, found {} but the maximum is {}",
+                warning_count,
+                max
+            );
+            true
+        }
+        _ => false,
+    };
+
+    if too_many_warnings
+        || diagnostics().any(|d| matches!(d.severity, Severity::Bug | Severity::Error))
     {
         1
     } else {
@@ -145,11 +178,13 @@ fn run_inner(
 
 pub fn apply_fixes(results: &mut Vec<LintResult>, walker: &mut FileWalker, dirty: bool) -> usize {
     let mut fix_count = 0;
+    // fixed scripts of host files, with the amount of issues they fixed, by the id of the host
+    let mut host_fixes: HashMap<usize, Vec<(usize, Indel, usize)>> = HashMap::new();
     // TODO: should we aquire a file lock if we know we need to run autofix?
     for res in results {
         let file = walker.files.get_mut(&res.file_id).unwrap();
-        // skip virtual files
-        if file.path.is_none() {
+        // skip virtual files, unless they are scripts which are written back into their host
+        if file.path.is_none() && !walker.embedded.contains_key(&res.file_id) {
             continue;
         }
         if res
@@ -160,7 +195,10 @@ pub fn apply_fixes(results: &mut Vec<LintResult>, walker: &mut FileWalker, dirty
         {
             lint_note!(
                 "skipping autofix for `{}` because it contains syntax errors",
-                file.path.as_ref().unwrap().to_string_lossy()
+                file.path.as_ref().map_or_else(
+                    || file.name.clone(),
+                    |path| path.to_string_lossy().to_string()
+                )
             );
             continue;
         }
@@ -177,17 +215,77 @@ pub fn apply_fixes(results: &mut Vec<LintResult>, walker: &mut FileWalker, dirty
             .filter(|(_, x)| x.outcome() == Outcome::Warning || x.outcome() == Outcome::Failure)
             .map(|(_, res)| res.diagnostics.len())
             .sum::<usize>();
+        let fixed_num = original_problem_num.saturating_sub(new_problem_num);
+
+        // scripts are written back into their host once all of the scripts of the host are fixed
+        if let Some(embedded) = walker.embedded.get(&res.file_id) {
+            let range = TextRange::up_to(TextSize::of(file.source.as_str()));
+            let indel = embedded.remap_indel(Indel::replace(range, fixed));
+            host_fixes
+                .entry(embedded.host)
+                .or_default()
+                .push((res.file_id, indel, fixed_num));
+            continue;
+        }
+
         let path = file.path.as_ref().unwrap();
-        if let Err(err) = write(path, fixed.clone()) {
+        if let Err(err) = write(path, file.disk_source(&fixed)) {
             lint_err!("failed to write to `{:#?}`: {}", path, err.to_string());
         } else {
             file.update_src(fixed);
-            fix_count += original_problem_num.saturating_sub(new_problem_num);
+            fix_count += fixed_num;
+        }
+    }
+
+    for (host_id, mut fixes) in host_fixes {
+        fixes.sort_by_key(|(_, indel, _)| indel.delete.start());
+        let host = walker.hosts.get_mut(&host_id).unwrap();
+        let mut source = host.source.clone();
+        apply_indels(
+            &fixes
+                .iter()
+                .map(|(_, indel, _)| indel.clone())
+                .collect::<Vec<_>>(),
+            &mut source,
+        );
+
+        let path = host.path.as_ref().unwrap();
+        if let Err(err) = write(path, host.disk_source(&source)) {
+            lint_err!("failed to write to `{:#?}`: {}", path, err.to_string());
+            continue;
+        }
+        host.update_src(source);
+
+        for embedded in walker
+            .embedded
+            .values_mut()
+            .filter(|embedded| embedded.host == host_id)
+        {
+            embedded.offset =
+                shift_offset(fixes.iter().map(|(_, indel, _)| indel), embedded.offset);
+        }
+        for (file_id, indel, fixed_num) in fixes {
+            walker
+                .files
+                .get_mut(&file_id)
+                .unwrap()
+                .update_src(indel.insert);
+            fix_count += fixed_num;
         }
     }
     fix_count
 }
 
+/// Shift an offset in a host file by the indels applied before it, the scripts after a fixed
+/// script moved by the difference in length of the fixed script.
+fn shift_offset<'a>(indels: impl Iterator<Item = &'a Indel>, offset: usize) -> usize {
+    indels
+        .filter(|indel| usize::from(indel.delete.end()) <= offset)
+        .fold(offset as isize, |offset, indel| {
+            offset + indel.insert.len() as isize - usize::from(indel.delete.len()) as isize
+        }) as usize
+}
+
 pub fn dump_ast(globs: Vec<String>) {
     use rslint_parser::{NodeOrToken, WalkEvent};
 
@@ -336,7 +434,11 @@ pub(crate) fn print_results(
     for result in results.iter_mut() {
         emit_diagnostics(
             formatter,
-            &result.diagnostics().cloned().collect::<Vec<_>>(),
+            &result
+                .diagnostics()
+                .cloned()
+                .map(|diagnostic| walker.remap_diagnostic(diagnostic))
+                .collect::<Vec<_>>(),
             walker,
         );
     }
@@ -408,18 +510,9 @@ fn output_overall(failures: usize, warnings: usize, successes: usize, fix_count:
     );
 }
 
-/// Remap each error diagnostic to a warning diagnostic based on the rule's level.
-/// this leaves warnings untouched because rules should be able to emit errors and warnings for context without
-/// the warnings being remapped to errors.
-pub fn remap_diagnostics_to_level(diagnostics: &mut Vec<Diagnostic>, level: RuleLevel) {
-    for diagnostic in diagnostics.iter_mut() {
-        match diagnostic.severity {
-            Severity::Error if level == RuleLevel::Warning => {
-                diagnostic.severity = Severity::Warning
-            }
-            _ => {}
-        }
-    }
+/// Remap the diagnostics of a rule to the severity of the rule's level, see [`RuleLevel::remap`].
+pub fn remap_diagnostics_to_level(diagnostics: &mut [Diagnostic], level: RuleLevel) {
+    level.remap(diagnostics);
 }
 
 pub fn emit_diagnostic(diagnostic: &Diagnostic, walker: &dyn file::Files) {
@@ -429,41 +522,93 @@ pub fn emit_diagnostic(diagnostic: &Diagnostic, walker: &dyn file::Files) {
         .expect("failed to throw linter diagnostic")
 }
 
-// TODO: don't use expect because we treat panics as linter bugs
-#[macro_export]
-macro_rules! lint_diagnostic {
-    ($severity:ident, $($format_args:tt)*) => {
-    use rslint_errors::Emitter;
-
-    let diag = $crate::Diagnostic::$severity(1, "", format!($($format_args)*));
-    let file = rslint_errors::file::SimpleFile::new("".into(), "".into());
-    let mut emitter = Emitter::new(&file);
-    emitter
-        .emit_stderr(&diag, true)
-        .expect("failed to throw linter diagnostic")
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rslint_core::{get_rule_by_name, CstRuleStore};
+    use std::fs;
+
+    #[test]
+    fn shifts_offsets_after_fixes() {
+        let indels = [
+            Indel::replace(TextRange::new(2.into(), 5.into()), "a".to_string()),
+            Indel::replace(TextRange::new(10.into(), 12.into()), "xyz".to_string()),
+        ];
+        assert_eq!(shift_offset(indels.iter(), 1), 1);
+        assert_eq!(shift_offset(indels.iter(), 5), 3);
+        assert_eq!(shift_offset(indels.iter(), 12), 11);
     }
-}
 
-/// Construct a simple linter error and immediately throw it to stderr
-#[macro_export]
-macro_rules! lint_err {
-    ($($format_args:tt)*) => {{
-        $crate::lint_diagnostic!(error, $($format_args)*);
-    }};
-}
-
-/// Construct a simple linter warning and immediately throw it to stderr
-#[macro_export]
-macro_rules! lint_warn {
-    ($($format_args:tt)*) => {{
-        $crate::lint_diagnostic!(warning, $($format_args)*);
-    }};
-}
+    #[test]
+    fn fixes_scripts_of_host_files() {
+        let dir = std::env::temp_dir().join(format!("rslint-host-fixes-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("page.html");
+        fs::write(
+            &path,
+            "<p>text</p>\r\n<script>\r\nlet a = 5;;\r\nlet b = 6;;\r\n</script>\r\n<p>more</p>\r\n<script type=\"module\">\r\nlet c = 7;;;\r\n</script>\r\n",
+        )
+        .unwrap();
+
+        let mut walker = FileWalker::from_glob_parallel(vec![path.clone()], 1);
+        let mut store = CstRuleStore::new();
+        store.load_rules(get_rule_by_name("no-extra-semi"));
+        let mut results = walker
+            .files
+            .values()
+            .map(|file| lint_file(file, &store, false))
+            .collect::<Vec<_>>();
+
+        assert_eq!(apply_fixes(&mut results, &mut walker, false), 4);
+        let fixed = "<p>text</p>\r\n<script>\r\nlet a = 5;\r\nlet b = 6;\r\n</script>\r\n<p>more</p>\r\n<script type=\"module\">\r\nlet c = 7;\r\n</script>\r\n";
+        assert_eq!(fs::read_to_string(&path).unwrap(), fixed);
+
+        // the offsets of the scripts still point to their source in the host
+        let host = walker.hosts.values().next().unwrap();
+        assert_eq!(host.source, fixed);
+        assert_eq!(walker.embedded.len(), 2);
+        for (id, embedded) in &walker.embedded {
+            let script = &walker.files[id].source;
+            assert_eq!(
+                &host.source[embedded.offset..embedded.offset + script.len()],
+                script
+            );
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
 
-/// Construct a simple linter note and immediately throw it to stderr
-#[macro_export]
-macro_rules! lint_note {
-    ($($format_args:tt)*) => {{
-        $crate::lint_diagnostic!(note, $($format_args)*);
-    }};
+    #[test]
+    fn exit_code_with_max_warnings() {
+        let dir = std::env::temp_dir().join(format!("rslint-max-warnings-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("warnings.js");
+        // ignoring rules after ignoring all of them warns once for every redundant directive
+        fs::write(
+            &path,
+            "// rslint-ignore\n// rslint-ignore no-empty\n// rslint-ignore no-debugger\n{}\n",
+        )
+        .unwrap();
+
+        let run = |max_warnings| {
+            let globs = vec![path.to_string_lossy().to_string()];
+            run_inner(
+                globs,
+                false,
+                false,
+                false,
+                None,
+                true,
+                1,
+                true,
+                None,
+                false,
+                max_warnings,
+            )
+        };
+        assert_eq!(run(None), 0);
+        assert_eq!(run(Some(3)), 0);
+        assert_eq!(run(Some(2)), 0);
+        assert_eq!(run(Some(1)), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
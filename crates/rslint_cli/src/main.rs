@@ -49,6 +49,9 @@ pub(crate) struct Options {
     /// The error formatter to use, either "short" or "long" (default)
     #[structopt(short = "F", long)]
     formatter: Option<String>,
+    /// Fail the run if there are more warnings than this, even if there are no errors.
+    #[structopt(long)]
+    max_warnings: Option<usize>,
     /// Developer only flags. See `-Z help` for more information.
     #[structopt(name = "FLAG", short = "Z")]
     dev_flag: Option<DevFlag>,
@@ -68,10 +71,21 @@ pub(crate) enum SubCommand {
     /// Explain a list of rules, ex: `explain getter-return, no-cond-assign`
     Explain { rules: Vec<String> },
     /// Show all of the available rules
-    // TODO: show only rules of particular groups
-    Rules,
+    Rules {
+        /// Only show rules which can automatically fix issues
+        #[structopt(long)]
+        fixable: bool,
+        /// Only show the rules of a group
+        #[structopt(long)]
+        group: Option<String>,
+    },
     /// Try to infer the options of some rules from various files and print the results
-    Infer { files: Vec<String> },
+    Infer {
+        files: Vec<String>,
+        /// Merge the inferred options into the `rslintrc.toml` of the current directory
+        #[structopt(long)]
+        write: bool,
+    },
 }
 
 fn main() {
@@ -89,9 +103,19 @@ fn execute(opt: Options) {
         (Some(DevFlag::Tokenize), _) => rslint_cli::tokenize(opt.files),
         (Some(DevFlag::DumpAst), _) => rslint_cli::dump_ast(opt.files),
 
-        (_, Some(SubCommand::Explain { rules })) => ExplanationRunner::new(rules).print(),
-        (_, Some(SubCommand::Rules)) => rslint_cli::show_all_rules(),
-        (_, Some(SubCommand::Infer { files })) => rslint_cli::infer(files),
+        (_, Some(SubCommand::Explain { rules })) => {
+            rslint_cli::load_config_plugins(opt.no_global_config);
+            ExplanationRunner::new(rules).print()
+        }
+        (_, Some(SubCommand::Rules { fixable, group })) => {
+            rslint_cli::load_config_plugins(opt.no_global_config);
+            rslint_cli::show_all_rules(fixable, group.as_deref())
+        }
+        (_, Some(SubCommand::Infer { files, write })) => {
+            // the merged config is validated, which needs the rules of the plugins
+            rslint_cli::load_config_plugins(opt.no_global_config);
+            rslint_cli::infer(files, write)
+        }
         (_, None) => rslint_cli::run(
             opt.files,
             opt.verbose,
@@ -103,6 +127,7 @@ fn execute(opt: Options) {
             opt.no_ignore,
             opt.ignore_file,
             opt.use_gitignore,
+            opt.max_warnings,
         ),
     }
 }
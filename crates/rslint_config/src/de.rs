@@ -1,7 +1,10 @@
 //! Deserialization of rules objects.
 
-use heck::{CamelCase, KebabCase};
-use rslint_core::{get_rule_by_name, get_rule_suggestion, CstRule};
+use heck::{CamelCase, KebabCase, MixedCase};
+use rslint_core::{
+    get_rule_by_name, get_rule_suggestion, plugin::get_plugin_rule, util::find_best_match_for_name,
+    CstRule, RuleLevel,
+};
 use serde::{
     de::{
         value::MapAccessDeserializer, DeserializeSeed, Error, IntoDeserializer, MapAccess, Visitor,
@@ -10,6 +13,162 @@ use serde::{
 };
 use std::{fmt, marker::PhantomData};
 
+/// Check that a rule name used as a key in a rules object refers to an existing rule.
+pub fn check_rule_name(key: &str) -> Result<(), String> {
+    if get_rule_by_name(&key.to_kebab_case()).is_some() {
+        return Ok(());
+    }
+    match get_rule_suggestion(&key.to_kebab_case()) {
+        Some(suggestion) => Err(format!(
+            "Unknown rule '{}'. did you mean '{}'?",
+            key, suggestion
+        )),
+        None => Err(format!("Unknown rule '{}'", key)),
+    }
+}
+
+/// Deserialize the config of a single rule the same way it is deserialized inside of a rules object.
+pub fn rule_from_value(key: &str, value: serde_json::Value) -> Result<Box<dyn CstRule>, String> {
+    check_rule_name(key)?;
+    if let Some(plugin_rule) = get_plugin_rule(&key.to_kebab_case()) {
+        return plugin_rule.deserialize(value);
+    }
+    let mut object = serde_json::Map::new();
+    object.insert(key.to_camel_case(), value);
+    Box::<dyn CstRule>::deserialize(serde_json::Value::Object(object))
+        .map_err(|err| err.to_string())
+}
+
+/// An option in the config of a rule which fails to deserialize.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionError {
+    /// The rule has no option with the name, `suggestion` is the option with the closest name.
+    Unknown {
+        option: String,
+        suggestion: Option<String>,
+    },
+    /// The value of the option is invalid, such as a value of the wrong type.
+    Invalid { option: String, message: String },
+}
+
+impl OptionError {
+    /// The name of the option as it is written in the config.
+    pub fn option(&self) -> &str {
+        match self {
+            OptionError::Unknown { option, .. } | OptionError::Invalid { option, .. } => option,
+        }
+    }
+
+    /// The message of the error for the config of a rule.
+    pub fn title(&self, rule: &str) -> String {
+        match self {
+            OptionError::Unknown { option, .. } => {
+                format!("unknown option `{}` for rule '{}'", option, rule)
+            }
+            OptionError::Invalid { option, .. } => {
+                format!("invalid value for option `{}` of rule '{}'", option, rule)
+            }
+        }
+    }
+
+    /// A suggestion of the option which was likely meant, if the option is unknown.
+    pub fn help(&self) -> Option<String> {
+        match self {
+            OptionError::Unknown {
+                suggestion: Some(suggestion),
+                ..
+            } => Some(format!("did you mean `{}`?", suggestion)),
+            _ => None,
+        }
+    }
+}
+
+/// Check every option of the config of a rule on its own, returning the options which fail to deserialize.
+///
+/// The options can only be checked one by one with the `schema` feature, without it no errors are returned.
+pub fn check_rule_options(
+    key: &str,
+    options: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<OptionError> {
+    let rule = match rule_by_key(key) {
+        Some(rule) => rule,
+        None => return vec![],
+    };
+    let names = match option_names(&*rule) {
+        Some(names) => names,
+        None => return vec![],
+    };
+    let defaults = default_options(&*rule);
+
+    let mut errors = vec![];
+    for (option, value) in options {
+        if !names.contains(option) {
+            // options are camelCase, so `allow_single_line` is most likely `allowSingleLine`
+            let suggestion = find_best_match_for_name(
+                names.iter().map(String::as_str),
+                &option.to_mixed_case(),
+                None,
+            );
+            errors.push(OptionError::Unknown {
+                option: option.clone(),
+                suggestion: suggestion.map(ToOwned::to_owned),
+            });
+            continue;
+        }
+
+        // the other options keep their defaults so rules without defaults for their options
+        // only fail because of this option
+        let mut single = defaults.clone();
+        single.insert(option.clone(), value.clone());
+        if let Err(message) = rule_from_value(key, serde_json::Value::Object(single)) {
+            errors.push(OptionError::Invalid {
+                option: option.clone(),
+                message,
+            });
+        }
+    }
+    errors
+}
+
+/// The rule with its default options for the key of a rule in a rules object, if the rule exists.
+fn rule_by_key(key: &str) -> Option<Box<dyn CstRule>> {
+    check_rule_name(key).ok()?;
+    let name = key.to_kebab_case();
+    match get_plugin_rule(&name) {
+        Some(plugin_rule) => Some(plugin_rule.rule),
+        None => get_rule_by_name(&name),
+    }
+}
+
+/// The names of the options of a rule as they are written in a config, taken from the schema of its config.
+#[cfg(feature = "schema")]
+fn option_names(rule: &dyn CstRule) -> Option<Vec<String>> {
+    let schema = rule.schema()?;
+    let names = match schema.schema.object {
+        Some(object) => object.properties.into_keys().collect(),
+        None => vec![],
+    };
+    Some(names)
+}
+
+#[cfg(not(feature = "schema"))]
+fn option_names(_rule: &dyn CstRule) -> Option<Vec<String>> {
+    None
+}
+
+/// The options of a rule with their default values, taken from the serialized rule. Options which
+/// are not serialized, such as options without a value, are missing.
+fn default_options(rule: &dyn CstRule) -> serde_json::Map<String, serde_json::Value> {
+    // rules are serialized externally tagged, as `{ "RuleName": { ...options } }`
+    match serde_json::to_value(rule) {
+        Ok(serde_json::Value::Object(tagged)) => match tagged.into_iter().next() {
+            Some((_, serde_json::Value::Object(options))) => options,
+            _ => serde_json::Map::new(),
+        },
+        _ => serde_json::Map::new(),
+    }
+}
+
 pub(crate) fn from_rule_objects<'de, D>(deserializer: D) -> Result<Vec<Box<dyn CstRule>>, D::Error>
 where
     D: Deserializer<'de>,
@@ -31,22 +190,18 @@ where
         {
             let mut vec = Vec::new();
             while let Some(key) = map.next_key::<String>()? {
+                if let Some(plugin_rule) = get_plugin_rule(&key.to_kebab_case()) {
+                    let value = map.next_value::<serde_json::Value>()?;
+                    let rule = plugin_rule.deserialize(value).map_err(M::Error::custom)?;
+                    vec.push(rule);
+                    continue;
+                }
                 let de = MapAccessDeserializer::new(Entry {
                     key: Some(key.to_camel_case().into_deserializer()),
                     value: &mut map,
                 });
-                if get_rule_by_name(&key.to_kebab_case()).is_none() {
-                    if let Some(suggestion) = get_rule_suggestion(&key.to_kebab_case()) {
-                        return Err(M::Error::custom(format!(
-                            "Unknown rule '{}'. did you mean '{}'?",
-                            key, suggestion
-                        )));
-                    } else {
-                        return Err(M::Error::custom(format!("Unknown rule '{}'", key)));
-                    }
-                } else {
-                    vec.push(Box::<dyn CstRule>::deserialize(de)?);
-                }
+                check_rule_name(&key).map_err(M::Error::custom)?;
+                vec.push(Box::<dyn CstRule>::deserialize(de)?);
             }
             Ok(vec)
         }
@@ -81,3 +236,36 @@ where
 
     deserializer.deserialize_map(TypetagObjects { _type: PhantomData })
 }
+
+/// Deserialize a map of rule names to their levels, the names are normalized to kebab case.
+pub(crate) fn from_rule_levels<'de, D>(
+    deserializer: D,
+) -> Result<Vec<(String, RuleLevel)>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct RuleLevels;
+
+    impl<'de> Visitor<'de> for RuleLevels {
+        type Value = Vec<(String, RuleLevel)>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("zero or more rule-to-level pairs")
+        }
+
+        fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+        where
+            M: MapAccess<'de>,
+        {
+            let mut vec = Vec::new();
+            while let Some(key) = map.next_key::<String>()? {
+                check_rule_name(&key).map_err(M::Error::custom)?;
+                let level = map.next_value::<RuleLevel>()?;
+                vec.push((key.to_kebab_case(), level));
+            }
+            Ok(vec)
+        }
+    }
+
+    deserializer.deserialize_map(RuleLevels)
+}
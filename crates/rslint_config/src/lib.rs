@@ -4,19 +4,82 @@
 #![allow(clippy::field_reassign_with_default)]
 
 mod de;
+#[cfg(test)]
+mod tests;
+
+pub use de::{check_rule_name, check_rule_options, rule_from_value, OptionError};
 use dirs_next::config_dir;
-use rslint_core::{get_group_rules_by_name, CstRule, CstRuleStore, Diagnostic, RuleLevel};
+use glob::{MatchOptions, Pattern};
+use heck::KebabCase;
+use rslint_core::{
+    get_group_rules_by_name, get_rule_by_name, plugin::load_plugin, registry::registry, CstRule,
+    CstRuleStore, Diagnostic, RuleLevel,
+};
 use rslint_errors::file::{Files, SimpleFile};
-use serde::{de::Error, Deserialize, Serialize};
+use rslint_parser::{EcmaVersion, FileKind, Syntax};
+use serde::{
+    de::{Error, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+#[cfg(feature = "schema")]
+use std::collections::HashMap;
 use std::{
-    env,
+    collections::BTreeMap,
+    env, fmt,
     fs::read_to_string,
+    ops::Range,
     path::{Path, PathBuf},
 };
+use toml::Spanned;
 
 /// The name of the config files to search for.
 pub const CONFIG_NAMES: [&str; 2] = ["rslintrc.json", "rslintrc.toml"];
 
+/// Find the config file of a directory, which is the first config in the directory or its ancestors.
+/// The global config is not included.
+pub fn find_config(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors().find_map(config_in)
+}
+
+fn config_in(dir: &Path) -> Option<PathBuf> {
+    CONFIG_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+}
+
+/// Whether a toml key path is (part of) the configuration of a rule in the `errors` or `warnings` rules,
+/// such as `rules.warnings.no-empty` or `rules.errors.noEmpty.allowEmptyCatch`.
+pub fn configures_rule(path: &[String], rule: &str) -> bool {
+    match path {
+        [rules, level, name, ..] => {
+            rules == "rules"
+                && (level == "errors" || level == "warnings")
+                && name.to_kebab_case() == rule
+        }
+        _ => false,
+    }
+}
+
+/// The components of a dotted toml key such as `rules."errors".no-empty`, without quotes.
+pub fn key_path(key: &str) -> Vec<String> {
+    let mut path = vec![];
+    let mut component = String::new();
+    let mut quote = None;
+    for c in key.chars() {
+        match (c, quote) {
+            ('"', None) | ('\'', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            ('.', None) => path.push(std::mem::take(&mut component)),
+            (c, None) if c.is_whitespace() => {}
+            (c, _) => component.push(c),
+        }
+    }
+    path.push(component);
+    path
+}
+
 /// A list of boxed rule implementations.
 pub type RuleList = Vec<Box<dyn CstRule>>;
 
@@ -44,6 +107,16 @@ impl<'de> Deserialize<'de> for SourceType {
     }
 }
 
+impl From<SourceType> for FileKind {
+    fn from(source_type: SourceType) -> Self {
+        match source_type {
+            SourceType::Module => FileKind::Module,
+            SourceType::Script => FileKind::Script,
+            SourceType::TypeScript => FileKind::TypeScript,
+        }
+    }
+}
+
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Default, Debug, Deserialize, Serialize)]
 pub struct ConfigRepr {
@@ -51,6 +124,28 @@ pub struct ConfigRepr {
     #[serde(default)]
     errors: ErrorsConfigRepr,
     parser: Option<ParserConfig>,
+    #[serde(default)]
+    overrides: Vec<OverrideConfig>,
+    #[serde(default, rename = "file-types")]
+    #[cfg_attr(feature = "schema", schemars(with = "HashMap<String, SourceType>"))]
+    file_types: FileTypes,
+    /// Paths of dynamic libraries with third party rules, relative to the config.
+    #[serde(default)]
+    plugins: Vec<PathBuf>,
+}
+
+/// The plugins of a json config, see [`Config::load_plugins_in`].
+#[derive(Default, Deserialize)]
+struct PluginsRepr {
+    #[serde(default)]
+    plugins: Vec<String>,
+}
+
+/// The plugins of a toml config with the spans of their paths.
+#[derive(Default, Deserialize)]
+struct SpannedPluginsRepr {
+    #[serde(default)]
+    plugins: Vec<Spanned<String>>,
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -58,6 +153,206 @@ pub struct ConfigRepr {
 #[serde(rename_all = "kebab-case")]
 pub struct ParserConfig {
     pub source_type: Option<SourceType>,
+    /// The ECMAScript version targeted by the linted files, from es2015 to es2022.
+    #[serde(
+        default,
+        deserialize_with = "de_ecma_version",
+        serialize_with = "ser_ecma_version"
+    )]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "ecma_version_schema"))]
+    pub ecma_version: Option<EcmaVersion>,
+    /// Allow top level `await` in scripts, and in modules targeting versions before ES2022.
+    pub top_level_await: Option<bool>,
+    /// Allow `return` outside of functions, such as in CommonJS modules.
+    pub global_return: Option<bool>,
+    /// Allow class fields and private class members when targeting versions before ES2022.
+    pub class_fields: Option<bool>,
+    /// Allow decorators on classes and class members.
+    pub decorators: Option<bool>,
+}
+
+impl ParserConfig {
+    /// Apply the options which are set on top of a syntax.
+    pub fn apply(&self, mut syntax: Syntax) -> Syntax {
+        if let Some(source_type) = self.source_type {
+            syntax = with_file_kind(syntax, source_type.into());
+        }
+        if let Some(ecma_version) = self.ecma_version {
+            syntax.ecma_version = ecma_version;
+        }
+
+        syntax.top_level_await = self.top_level_await.unwrap_or(syntax.top_level_await);
+        syntax.global_return = self.global_return.unwrap_or(syntax.global_return);
+        syntax.class_fields = self.class_fields.unwrap_or(syntax.class_fields);
+        syntax.decorators = self.decorators.unwrap_or(syntax.decorators);
+        syntax
+    }
+}
+
+/// Change the kind of a syntax, keeping the rest of it. TypeScript also enables the syntax TypeScript always accepts.
+fn with_file_kind(mut syntax: Syntax, file_kind: FileKind) -> Syntax {
+    syntax.file_kind = file_kind;
+    if file_kind == FileKind::TypeScript {
+        syntax = syntax.typescript();
+    }
+    syntax
+}
+
+/// Options which apply to the files matching some glob patterns, on top of the top level options.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OverrideConfig {
+    /// The glob patterns of the files the override applies to. Patterns without a `/` match
+    /// the file name, other patterns match the path relative to the config.
+    #[serde(deserialize_with = "de_globs")]
+    pub files: Vec<String>,
+    pub parser: Option<ParserConfig>,
+}
+
+impl OverrideConfig {
+    /// Whether the override applies to a file, `root` is the directory of the config.
+    pub fn matches(&self, path: &Path, root: Option<&Path>) -> bool {
+        self.files.iter().any(|glob| glob_matches(glob, path, root))
+    }
+}
+
+/// Whether a glob pattern matches a file. Patterns without a `/` match the file name, other
+/// patterns match the path relative to `root`, the directory of the config.
+fn glob_matches(glob: &str, path: &Path, root: Option<&Path>) -> bool {
+    let pattern = match Pattern::new(glob) {
+        Ok(pattern) => pattern,
+        Err(_) => return false,
+    };
+    let options = MatchOptions {
+        require_literal_separator: true,
+        ..MatchOptions::new()
+    };
+
+    if glob.contains('/') {
+        let absolute = env::current_dir()
+            .map(|dir| dir.join(path))
+            .unwrap_or_else(|_| path.to_owned());
+        let relative = root
+            .and_then(|root| absolute.strip_prefix(root).ok())
+            .unwrap_or(path);
+        pattern.matches_path_with(relative, options)
+    } else {
+        let name = path.file_name().map(Path::new).unwrap_or(path);
+        pattern.matches_path_with(name, options)
+    }
+}
+
+/// A table mapping glob patterns to the source type of the files they match, such as
+/// `"*.es6" = "module"`. The patterns are kept in the order they are written in.
+#[derive(Debug, Clone, Default)]
+pub struct FileTypes(Vec<(String, SourceType)>);
+
+impl FileTypes {
+    /// The source type of a file, if any pattern matches it. Later patterns take precedence.
+    pub fn get(&self, path: &Path, root: Option<&Path>) -> Option<SourceType> {
+        self.0
+            .iter()
+            .rev()
+            .find(|(glob, _)| glob_matches(glob, path, root))
+            .map(|(_, source_type)| *source_type)
+    }
+}
+
+impl Serialize for FileTypes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (glob, source_type) in &self.0 {
+            map.serialize_entry(glob, source_type)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for FileTypes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FileTypesVisitor;
+
+        impl<'de> Visitor<'de> for FileTypesVisitor {
+            type Value = FileTypes;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map of glob patterns to source types")
+            }
+
+            fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mut file_types = Vec::new();
+                while let Some((glob, source_type)) = map.next_entry::<String, SourceType>()? {
+                    if let Err(err) = Pattern::new(&glob) {
+                        return Err(M::Error::custom(format!(
+                            "invalid glob pattern '{}': {}",
+                            glob, err
+                        )));
+                    }
+                    file_types.push((glob, source_type));
+                }
+                Ok(FileTypes(file_types))
+            }
+        }
+
+        deserializer.deserialize_map(FileTypesVisitor)
+    }
+}
+
+fn de_ecma_version<'de, D>(deserializer: D) -> Result<Option<EcmaVersion>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map(Some)
+        .map_err(D::Error::custom)
+}
+
+fn ser_ecma_version<S>(version: &Option<EcmaVersion>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    version.map(EcmaVersion::name).serialize(serializer)
+}
+
+#[cfg(feature = "schema")]
+fn ecma_version_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+        instance_type: Some(schemars::schema::InstanceType::String.into()),
+        enum_values: Some(
+            EcmaVersion::ALL
+                .iter()
+                .map(|version| version.name().into())
+                .collect(),
+        ),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn de_globs<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let globs = Vec::<String>::deserialize(deserializer)?;
+    for glob in &globs {
+        if let Err(err) = Pattern::new(glob) {
+            return Err(D::Error::custom(format!(
+                "invalid glob pattern '{}': {}",
+                glob, err
+            )));
+        }
+    }
+    Ok(globs)
 }
 
 #[derive(Debug, Deserialize, Serialize, Default)]
@@ -71,11 +366,16 @@ struct RulesConfigRepr {
 
     groups: Vec<String>,
     allowed: Vec<String>,
+
+    /// The levels of rules by their names, rules which are not enabled otherwise are
+    /// enabled with their default options unless their level is `off`.
+    #[serde(deserialize_with = "de::from_rule_levels")]
+    levels: Vec<(String, RuleLevel)>,
 }
 
 #[cfg(feature = "schema")]
 impl schemars::JsonSchema for RulesConfigRepr {
-    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
         use schemars::schema::*;
         use schemars::*;
 
@@ -109,11 +409,11 @@ impl schemars::JsonSchema for RulesConfigRepr {
             }};
         }
 
-        let rules = CstRuleStore::new().builtins().rules;
+        let registry = registry();
         let mut rule_items = vec![];
 
-        for rule in &rules {
-            rule_items.push(string_schema!(rule.name(), rule.docs()));
+        for meta in registry.rules() {
+            rule_items.push(string_schema!(meta.name, meta.docs));
         }
         let rule_items_schema = Schema::Object(SchemaObject {
             array: Some(Box::new(ArrayValidation {
@@ -123,12 +423,11 @@ impl schemars::JsonSchema for RulesConfigRepr {
             ..Default::default()
         });
 
-        // TODO(RDambrosio016): dont hardcode it like this
-        let group_items = vec![
-            string_schema!("errors"),
-            string_schema!("style"),
-            string_schema!("regex"),
-        ];
+        let group_items = registry
+            .groups()
+            .iter()
+            .map(|group| string_schema!(group))
+            .collect::<Vec<_>>();
 
         let groups_schema = Schema::Object(SchemaObject {
             array: Some(Box::new(ArrayValidation {
@@ -139,9 +438,15 @@ impl schemars::JsonSchema for RulesConfigRepr {
         });
 
         let mut rule_obj_items = Map::new();
-        for rule in &rules {
-            if let Some(schema) = rule.schema() {
-                rule_obj_items.insert(rule.name().to_string(), Schema::Object(schema.schema));
+        for meta in registry.rules() {
+            if let Some(root) = meta.schema() {
+                // enums and other types used by rule options are referenced from the definitions
+                gen.definitions_mut().extend(root.definitions);
+                let mut schema = root.schema;
+                if meta.deprecated.is_some() {
+                    schema.metadata().deprecated = true;
+                }
+                rule_obj_items.insert(meta.name.to_string(), Schema::Object(schema));
             }
         }
         let rules_schema = Schema::Object(SchemaObject {
@@ -152,11 +457,25 @@ impl schemars::JsonSchema for RulesConfigRepr {
             ..Default::default()
         });
 
+        let level_schema = gen.subschema_for::<RuleLevel>();
+        let levels_schema = Schema::Object(SchemaObject {
+            object: Some(Box::new(ObjectValidation {
+                properties: registry
+                    .rules()
+                    .iter()
+                    .map(|meta| (meta.name.to_string(), level_schema.clone()))
+                    .collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        });
+
         let mut map = Map::new();
         map.insert("groups".to_string(), groups_schema);
         map.insert("allowed".to_string(), rule_items_schema);
         map.insert("errors".to_string(), rules_schema.clone());
         map.insert("warnings".to_string(), rules_schema);
+        map.insert("levels".to_string(), levels_schema);
 
         Schema::Object(SchemaObject {
             object: Some(Box::new(ObjectValidation {
@@ -189,8 +508,13 @@ impl Default for ErrorsConfigRepr {
 #[derive(Debug, Default)]
 pub struct Config {
     repr: ConfigRepr,
+    /// The directory of the config file, if one was found.
+    dir: Option<PathBuf>,
+    /// The config file, which the diagnostics of [`Config::rules_store`] point into.
+    file: Option<(SimpleFile, ConfigStyle)>,
 }
 
+#[derive(Debug, Clone, Copy)]
 enum ConfigStyle {
     Toml,
     Json,
@@ -200,8 +524,19 @@ impl Config {
     /// Creates a new config by first searching for a config in the current
     /// dir and all of it ancestors, and if `no_global_config` is `false`,
     /// look in the systems config directory.
-    pub fn new(no_global_config: bool) -> Result<Self, (SimpleFile, Diagnostic)> {
-        let path = Self::find_config(no_global_config);
+    pub fn new(no_global_config: bool) -> Result<Self, (SimpleFile, Vec<Diagnostic>)> {
+        match env::current_dir() {
+            Ok(dir) => Self::new_in(&dir, no_global_config),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Creates a new config like [`Config::new`], but searching from `dir` instead of the current dir.
+    pub fn new_in(
+        dir: &Path,
+        no_global_config: bool,
+    ) -> Result<Self, (SimpleFile, Vec<Diagnostic>)> {
+        let path = Self::find_config(dir, no_global_config);
         let (source, (path, style)) = match path
             .as_ref()
             .and_then(|(path, _)| read_to_string(path).ok())
@@ -210,10 +545,44 @@ impl Config {
             None => return Ok(Self::default()),
         };
 
+        Self::from_source_with_style(source, &path, style)
+    }
+
+    /// Creates a new config from the source of a config file, which is in toml unless its
+    /// path has a `json` extension.
+    pub fn from_source(
+        source: String,
+        path: PathBuf,
+    ) -> Result<Self, (SimpleFile, Vec<Diagnostic>)> {
+        let style = match path.extension() {
+            Some(ext) if ext == "json" => ConfigStyle::Json,
+            _ => ConfigStyle::Toml,
+        };
+        Self::from_source_with_style(source, &path, style)
+    }
+
+    fn from_source_with_style(
+        source: String,
+        path: &Path,
+        style: ConfigStyle,
+    ) -> Result<Self, (SimpleFile, Vec<Diagnostic>)> {
+        let dir = path.parent().map(Path::to_owned);
         match style {
             ConfigStyle::Json => match serde_json::from_str::<ConfigRepr>(&source) {
-                Ok(repr) => Ok(Self { repr }),
+                Ok(repr) => Ok(Self {
+                    repr,
+                    dir,
+                    file: Some((
+                        SimpleFile::new(path.to_string_lossy().into(), source),
+                        style,
+                    )),
+                }),
                 Err(err) => {
+                    let option_errors = json_option_errors(&source);
+                    if !option_errors.is_empty() {
+                        let config_file = SimpleFile::new(path.to_string_lossy().into(), source);
+                        return Err((config_file, option_errors));
+                    }
                     let config_file = SimpleFile::new(path.to_string_lossy().into(), source);
                     let (line, col) = (err.line() - 1, err.column() - 1);
                     let idx = config_file
@@ -224,13 +593,25 @@ impl Config {
 
                     let diag =
                         Diagnostic::error(1, "config", err.to_string()).primary(idx..idx, "");
-                    Err((config_file, diag))
+                    Err((config_file, vec![diag]))
                 }
             },
             ConfigStyle::Toml => match toml::from_str::<ConfigRepr>(&source) {
-                Ok(repr) => Ok(Self { repr }),
+                Ok(repr) => Ok(Self {
+                    repr,
+                    dir,
+                    file: Some((
+                        SimpleFile::new(path.to_string_lossy().into(), source),
+                        style,
+                    )),
+                }),
 
                 Err(err) => {
+                    let option_errors = toml_option_errors(&source);
+                    if !option_errors.is_empty() {
+                        let config_file = SimpleFile::new(path.to_string_lossy().into(), source);
+                        return Err((config_file, option_errors));
+                    }
                     let config_file = SimpleFile::new(path.to_string_lossy().into(), source);
                     let d = if let Some(idx) = err
                         .line_col()
@@ -243,42 +624,68 @@ impl Config {
                     } else {
                         Diagnostic::error(1, "config", err.to_string())
                     };
-                    Err((config_file, d))
+                    Err((config_file, vec![d]))
                 }
             },
         }
     }
 
-    fn find_config(global_config: bool) -> Option<(PathBuf, ConfigStyle)> {
-        let path = env::current_dir().ok()?;
-        fn search_path(path: &Path) -> Option<(PathBuf, ConfigStyle)> {
-            for config_name in CONFIG_NAMES.iter() {
-                let new_path = path.join(config_name);
-                let style = if config_name.ends_with("json") {
-                    ConfigStyle::Json
-                } else {
-                    ConfigStyle::Toml
-                };
-
-                if new_path.exists() {
-                    return Some((new_path, style));
-                }
-            }
-            None
+    /// Load the plugins listed in the config of the current dir or its ancestors, like [`Config::new`].
+    ///
+    /// # Safety
+    ///
+    /// See [`Config::load_plugins_in`].
+    pub unsafe fn load_plugins(
+        no_global_config: bool,
+    ) -> Result<(), Box<(SimpleFile, Diagnostic)>> {
+        match env::current_dir() {
+            Ok(dir) => Self::load_plugins_in(&dir, no_global_config),
+            Err(_) => Ok(()),
         }
+    }
 
-        for path in path.ancestors() {
-            if let Some(res) = search_path(path) {
-                return Some(res);
-            }
-        }
+    /// Load the plugins listed in the config found from `dir` like [`Config::new_in`], so the rules of the
+    /// plugins can be configured once the config is loaded. Parsing a config never loads its plugins,
+    /// they are only loaded from the config file on disk by calling this.
+    ///
+    /// # Safety
+    ///
+    /// Loading a plugin runs the code of its library in this process, see [`load_plugin`]. The caller must
+    /// trust the config and the libraries it lists, such as the config of a project the user lints themselves.
+    pub unsafe fn load_plugins_in(
+        dir: &Path,
+        no_global_config: bool,
+    ) -> Result<(), Box<(SimpleFile, Diagnostic)>> {
+        let (path, style) = match Self::find_config(dir, no_global_config) {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+        let source = match read_to_string(&path) {
+            Ok(source) => source,
+            Err(_) => return Ok(()),
+        };
+        let config_dir = path.parent().unwrap_or(dir);
 
-        let path = config_dir()?;
-        if let Some(res) = search_path(&path).filter(|_| global_config) {
-            return Some(res);
+        for (plugin, range) in plugin_paths(&source, &style) {
+            if let Err(err) = load_plugin(&config_dir.join(&plugin)) {
+                let msg = format!("failed to load the plugin `{}`: {}", plugin, err);
+                let d = Diagnostic::error(1, "config", msg).primary(range, "");
+                let config_file = SimpleFile::new(path.to_string_lossy().into(), source);
+                return Err(Box::new((config_file, d)));
+            }
         }
+        Ok(())
+    }
 
-        None
+    fn find_config(path: &Path, global_config: bool) -> Option<(PathBuf, ConfigStyle)> {
+        let path =
+            find_config(path).or_else(|| config_in(&config_dir()?).filter(|_| global_config))?;
+        let style = if path.extension() == Some("json".as_ref()) {
+            ConfigStyle::Json
+        } else {
+            ConfigStyle::Toml
+        };
+        Some((path, style))
     }
 
     /// Returns a copy of the parser config.
@@ -286,6 +693,55 @@ impl Config {
         self.repr.parser.clone()
     }
 
+    /// Returns the directory of the config file, if one was found.
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// The source type the `file-types` table sets for a file, if any of its patterns match it.
+    pub fn file_type(&self, path: &Path) -> Option<FileKind> {
+        self.repr
+            .file_types
+            .get(path, self.dir())
+            .map(FileKind::from)
+    }
+
+    /// The syntax a file is parsed with before applying any parser options. It is inferred from
+    /// the extension of the file, with the kind set by the `file-types` table if one of its
+    /// patterns matches the file, so `.d.ts` and `.cjs` files keep their semantics. Returns `None`
+    /// if the file is neither a JavaScript nor a TypeScript file and no pattern matches it.
+    pub fn file_syntax(&self, path: &Path) -> Option<Syntax> {
+        match (self.file_type(path), Syntax::from_path(path)) {
+            (Some(kind), Some(syntax)) => Some(with_file_kind(syntax, kind)),
+            (Some(kind), None) => Some(Syntax::new(kind)),
+            (None, syntax) => syntax,
+        }
+    }
+
+    /// Build the syntax a file is parsed with, applying the parser options and then the parser
+    /// options of every override matching the file, in order, on top of the syntax inferred
+    /// for the file (e.g. from its extension).
+    pub fn syntax(&self, path: Option<&Path>, mut syntax: Syntax) -> Syntax {
+        if let Some(parser) = &self.repr.parser {
+            syntax = parser.apply(syntax);
+        }
+
+        let path = match path {
+            Some(path) => path,
+            None => return syntax,
+        };
+        for config in &self.repr.overrides {
+            if let Some(parser) = config
+                .parser
+                .as_ref()
+                .filter(|_| config.matches(path, self.dir()))
+            {
+                syntax = parser.apply(syntax);
+            }
+        }
+        syntax
+    }
+
     /// Returns the formatter that should be used.
     pub fn formatter(&self) -> String {
         self.repr.errors.formatter.clone()
@@ -299,8 +755,19 @@ impl Config {
             .map(|rule| rule.name())
     }
 
+    /// The level of a rule, a level in the `levels` table takes precedence over the table the rule is configured in.
     pub fn rule_level_by_name(&self, rule_name: &str) -> RuleLevel {
-        if self.warning_rule_names().any(|name| name == rule_name) {
+        let level = self.repr.rules.as_ref().and_then(|rules| {
+            rules
+                .levels
+                .iter()
+                .rev()
+                .find(|(name, _)| name == rule_name)
+                .map(|(_, level)| *level)
+        });
+        if let Some(level) = level {
+            level
+        } else if self.warning_rule_names().any(|name| name == rule_name) {
             RuleLevel::Warning
         } else {
             RuleLevel::Error
@@ -320,15 +787,53 @@ impl Config {
         };
 
         let mut warnings = vec![];
-        let rules = unique_rules(rule_cfg.errors.clone(), rule_cfg.warnings.clone());
+        let rules = unique_rules(rule_cfg.errors.clone(), rule_cfg.warnings.clone()).collect();
+        let leveled = rule_cfg
+            .levels
+            .iter()
+            .filter(|(_, level)| *level != RuleLevel::Off)
+            .filter_map(|(name, _)| get_rule_by_name(name))
+            .collect();
+        let rules = unique_rules(rules, leveled);
         let mut rules = self
             .intersect_allowed(rules, &mut warnings)
             .collect::<Vec<_>>();
 
+        for rule in &rules {
+            if let Some(msg) = registry()
+                .rule(rule.name())
+                .and_then(|meta| meta.deprecation_message())
+            {
+                let mut d = Diagnostic::warning(1, "config", msg);
+                if let Some(range) = ["errors", "warnings", "levels"]
+                    .iter()
+                    .find_map(|field| self.name_range(field, rule.name()))
+                {
+                    d = d.primary(range, "");
+                }
+                warnings.push(d);
+            }
+        }
+
         for group in &rule_cfg.groups {
             if let Some(group_rules) = get_group_rules_by_name(group) {
                 let list = self.intersect_allowed(group_rules.into_iter(), &mut warnings);
-                let list = list.collect::<Vec<_>>();
+                let list = list
+                    .filter(|rule| !rules.iter().any(|prev| prev.name() == rule.name()))
+                    .collect::<Vec<_>>();
+                for rule in &list {
+                    if let Some(msg) = registry()
+                        .rule(rule.name())
+                        .and_then(|meta| meta.deprecation_message())
+                    {
+                        let mut d = Diagnostic::warning(1, "config", msg)
+                            .footer_note(format!("the rule is enabled by the group '{}'", group));
+                        if let Some(range) = self.name_range("groups", group) {
+                            d = d.primary(range, "");
+                        }
+                        warnings.push(d);
+                    }
+                }
                 rules = unique_rules(rules, list).collect();
             } else {
                 let d = Diagnostic::warning(1, "config", format!("unknown rule group '{}'", group));
@@ -336,11 +841,57 @@ impl Config {
             }
         }
 
+        rules.retain(|rule| self.rule_level_by_name(rule.name()) != RuleLevel::Off);
         let mut store = CstRuleStore::new();
         store.load_rules(rules);
         (store, warnings)
     }
 
+    /// The config file the config was loaded from, which the diagnostics of [`Config::rules_store`] point into.
+    pub fn file(&self) -> Option<&SimpleFile> {
+        self.file.as_ref().map(|(file, _)| file)
+    }
+
+    /// The range of the name of a rule in the `errors`, `warnings` or `levels` rules of the config file,
+    /// or of a group in its `groups`.
+    fn name_range(&self, field: &str, name: &str) -> Option<Range<usize>> {
+        let (file, style) = self.file.as_ref()?;
+        let source = file.source(1)?;
+        match style {
+            ConfigStyle::Toml => {
+                let rules = toml::from_str::<SpannedNamesRepr>(source).ok()?.rules;
+                let names = match field {
+                    "groups" => rules.groups,
+                    "errors" => rules.errors.into_keys().collect(),
+                    "warnings" => rules.warnings.into_keys().collect(),
+                    "levels" => rules.levels.into_keys().collect(),
+                    _ => return None,
+                };
+                let key = names
+                    .into_iter()
+                    .find(|key| key.get_ref().to_kebab_case() == name.to_kebab_case())?;
+                Some(key.start()..key.end())
+            }
+            ConfigStyle::Json if field == "groups" => {
+                let start = json_key_range(source, &["rules", "groups"])?.end;
+                let text = serde_json::to_string(name).ok()?;
+                let start = start + source[start..].find(&text)?;
+                Some(start..start + text.len())
+            }
+            ConfigStyle::Json => {
+                let repr = serde_json::from_str::<serde_json::Value>(source).ok()?;
+                // keys may be written in another case than the name of the rule
+                let key = repr
+                    .get("rules")?
+                    .get(field)?
+                    .as_object()?
+                    .keys()
+                    .find(|key| key.to_kebab_case() == name)?;
+                json_key_range(source, &["rules", field, key])
+            }
+        }
+    }
+
     /// Remove any rules which are explicitly allowed by the `allowed` field.
     fn intersect_allowed<'s>(
         &'s self,
@@ -375,6 +926,178 @@ impl Config {
     }
 }
 
+/// The names of the rules and groups of a toml config with their spans.
+#[derive(Default, Deserialize)]
+struct SpannedNamesRepr {
+    #[serde(default)]
+    rules: SpannedRuleNames,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct SpannedRuleNames {
+    groups: Vec<Spanned<String>>,
+    errors: BTreeMap<Spanned<String>, toml::Value>,
+    warnings: BTreeMap<Spanned<String>, toml::Value>,
+    levels: BTreeMap<Spanned<String>, toml::Value>,
+}
+
+/// The rule objects of a toml config with the spans of the names and values of their options.
+#[derive(Default, Deserialize)]
+struct SpannedRulesRepr {
+    #[serde(default)]
+    rules: SpannedRuleObjects,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct SpannedRuleObjects {
+    errors: BTreeMap<String, BTreeMap<Spanned<String>, Spanned<toml::Value>>>,
+    warnings: BTreeMap<String, BTreeMap<Spanned<String>, Spanned<toml::Value>>>,
+}
+
+/// Find the options of rules in a toml config which fail to deserialize, serde does not know the
+/// location of errors inside of rule objects so the options are checked on their own.
+fn toml_option_errors(source: &str) -> Vec<Diagnostic> {
+    let repr = match toml::from_str::<SpannedRulesRepr>(source) {
+        Ok(repr) => repr,
+        Err(_) => return vec![],
+    };
+    let rules = repr.rules.errors.into_iter().chain(repr.rules.warnings);
+
+    let mut diagnostics = vec![];
+    for (rule, options) in rules {
+        let values = match options
+            .iter()
+            .map(|(key, value)| {
+                let value = serde_json::to_value(value.get_ref()).ok()?;
+                Some((key.get_ref().clone(), value))
+            })
+            .collect::<Option<_>>()
+        {
+            Some(values) => values,
+            None => continue,
+        };
+
+        for error in check_rule_options(&rule, &values) {
+            let (key, value) = match options
+                .iter()
+                .find(|(key, _)| key.get_ref() == error.option())
+            {
+                Some(option) => option,
+                None => continue,
+            };
+            let range = match error {
+                OptionError::Unknown { .. } => key.start()..key.end(),
+                OptionError::Invalid { .. } => value.start()..value.end(),
+            };
+            diagnostics.push((range.start, option_diagnostic(&rule, &error, range)));
+        }
+    }
+    diagnostics.sort_by_key(|(start, _)| *start);
+    diagnostics.into_iter().map(|(_, d)| d).collect()
+}
+
+/// Find the options of rules in a json config which fail to deserialize, like [`toml_option_errors`].
+fn json_option_errors(source: &str) -> Vec<Diagnostic> {
+    let repr = match serde_json::from_str::<serde_json::Value>(source) {
+        Ok(repr) => repr,
+        Err(_) => return vec![],
+    };
+
+    // serde_json does not keep spans, so the errors are ordered and located by their text
+    let mut diagnostics = vec![];
+    for level in &["errors", "warnings"] {
+        let rules = match repr
+            .get("rules")
+            .and_then(|rules| rules.get(level))
+            .and_then(|rules| rules.as_object())
+        {
+            Some(rules) => rules,
+            None => continue,
+        };
+        for (rule, options) in rules {
+            let options = match options.as_object() {
+                Some(options) => options,
+                None => continue,
+            };
+            for error in check_rule_options(rule, options) {
+                if let Some(range) = json_key_range(source, &["rules", level, rule, error.option()])
+                {
+                    diagnostics.push((range.start, option_diagnostic(rule, &error, range)));
+                }
+            }
+        }
+    }
+    diagnostics.sort_by_key(|(start, _)| *start);
+    diagnostics.into_iter().map(|(_, d)| d).collect()
+}
+
+/// The range of the last key of a path of object keys in json source, each key is searched for
+/// after the previous key.
+fn json_key_range(source: &str, path: &[&str]) -> Option<Range<usize>> {
+    let mut offset = 0;
+    let mut range = None;
+    for key in path {
+        let key = serde_json::to_string(key).ok()?;
+        let pattern = regex::Regex::new(&format!("{}\\s*:", regex::escape(&key))).ok()?;
+        let start = offset + pattern.find(&source[offset..])?.start();
+        offset = start + key.len();
+        range = Some(start..offset);
+    }
+    range
+}
+
+/// The paths of the plugins listed in a config with their ranges in the source, a malformed config
+/// is reported when the config is loaded.
+fn plugin_paths(source: &str, style: &ConfigStyle) -> Vec<(String, Range<usize>)> {
+    match style {
+        ConfigStyle::Toml => toml::from_str::<SpannedPluginsRepr>(source)
+            .map(|repr| {
+                repr.plugins
+                    .into_iter()
+                    .map(|plugin| {
+                        let range = plugin.start()..plugin.end();
+                        (plugin.into_inner(), range)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        ConfigStyle::Json => {
+            let plugins = serde_json::from_str::<PluginsRepr>(source)
+                .map(|repr| repr.plugins)
+                .unwrap_or_default();
+            // serde_json does not keep spans, so the paths are located in order after the `plugins` key
+            let mut offset = match json_key_range(source, &["plugins"]) {
+                Some(range) => range.end,
+                None => return vec![],
+            };
+            plugins
+                .into_iter()
+                .filter_map(|plugin| {
+                    let text = serde_json::to_string(&plugin).ok()?;
+                    let start = offset + source[offset..].find(&text)?;
+                    offset = start + text.len();
+                    Some((plugin, start..offset))
+                })
+                .collect()
+        }
+    }
+}
+
+fn option_diagnostic(rule: &str, error: &OptionError, range: Range<usize>) -> Diagnostic {
+    let label = match error {
+        OptionError::Invalid { message, .. } => message.as_str(),
+        OptionError::Unknown { .. } => "",
+    };
+    let d =
+        Diagnostic::error(1, "config", error.title(&rule.to_kebab_case())).primary(range, label);
+    match error.help() {
+        Some(help) => d.footer_help(help),
+        None => d,
+    }
+}
+
 fn unique_rules(first: RuleList, mut second: RuleList) -> impl Iterator<Item = Box<dyn CstRule>> {
     second.retain(|rule| !first.iter().any(|prev| prev.name() == rule.name()));
     first.into_iter().chain(second)
@@ -0,0 +1,417 @@
+use crate::{configures_rule, glob_matches, key_path, Config, FileTypes, SourceType};
+use rslint_core::RuleLevel;
+use rslint_parser::{EcmaVersion, FileKind, Syntax};
+use std::path::{Path, PathBuf};
+
+fn toml(source: &str) -> Config {
+    match Config::from_source(source.to_string(), PathBuf::from("rslintrc.toml")) {
+        Ok(config) => config,
+        Err((_, diagnostics)) => panic!("invalid config: {}", diagnostics[0].title),
+    }
+}
+
+/// The title of the error produced by an invalid config.
+fn toml_error(source: &str) -> String {
+    match Config::from_source(source.to_string(), PathBuf::from("rslintrc.toml")) {
+        Ok(_) => panic!("config should be invalid"),
+        Err((_, diagnostics)) => diagnostics[0].title.clone(),
+    }
+}
+
+#[test]
+fn ecma_version() {
+    let config = toml("[parser]\necma-version = \"ES2019\"\n");
+    let syntax = config.syntax(Some(Path::new("a.js")), Syntax::new(FileKind::Module));
+    assert_eq!(syntax.ecma_version, EcmaVersion::ES2019);
+
+    let config = toml("");
+    let syntax = config.syntax(Some(Path::new("a.js")), Syntax::new(FileKind::Module));
+    assert_eq!(syntax.ecma_version, EcmaVersion::LATEST);
+}
+
+#[test]
+fn unsupported_ecma_version() {
+    assert!(toml_error("[parser]\necma-version = \"es5\"\n")
+        .contains("the oldest supported version is es2015"));
+    assert!(toml_error("[parser]\necma-version = \"es2077\"\n").contains("invalid ecma version"));
+}
+
+#[test]
+fn glob_matching() {
+    let root = Some(Path::new("/project"));
+    // patterns without a separator match the file name
+    assert!(glob_matches("*.es6", Path::new("/project/src/a.es6"), root));
+    assert!(!glob_matches("*.es6", Path::new("/project/src/a.js"), root));
+    // other patterns match the path relative to the directory of the config
+    assert!(glob_matches(
+        "src/*.js",
+        Path::new("/project/src/a.js"),
+        root
+    ));
+    assert!(!glob_matches(
+        "src/*.js",
+        Path::new("/project/src/lib/a.js"),
+        root
+    ));
+    assert!(glob_matches(
+        "src/**/*.js",
+        Path::new("/project/src/lib/a.js"),
+        root
+    ));
+    assert!(!glob_matches(
+        "src/*.js",
+        Path::new("/other/src/lib/a.js"),
+        root
+    ));
+    assert!(!glob_matches("[", Path::new("/project/["), root));
+}
+
+#[test]
+fn file_types_last_match_wins() {
+    let file_types = FileTypes(vec![
+        ("*.js".to_string(), SourceType::Module),
+        ("legacy/*.js".to_string(), SourceType::Script),
+    ]);
+    let kind = |path: &str| {
+        file_types
+            .get(Path::new(path), Some(Path::new("/project")))
+            .map(FileKind::from)
+    };
+    assert_eq!(kind("/project/a.js"), Some(FileKind::Module));
+    assert_eq!(kind("/project/legacy/a.js"), Some(FileKind::Script));
+    assert_eq!(kind("/project/a.ts"), None);
+}
+
+#[test]
+fn file_syntax() {
+    let config = toml(
+        r#"
+[file-types]
+"*.es6" = "module"
+"*.cjs" = "module"
+"*.ts" = "module"
+"*.mts" = "typescript"
+"#,
+    );
+    let syntax = |path: &str| config.file_syntax(Path::new(path));
+
+    assert_eq!(syntax("a.es6"), Some(Syntax::new(FileKind::Module)));
+    assert_eq!(syntax("a.mjs"), Syntax::from_path(Path::new("a.mjs")));
+    assert_eq!(syntax("a.md"), None);
+
+    // the mapping only overrides the kind inferred from the extension
+    let cjs = syntax("a.cjs").unwrap();
+    assert_eq!(cjs.file_kind, FileKind::Module);
+    assert!(cjs.global_return);
+    let declaration = syntax("a.d.ts").unwrap();
+    assert_eq!(declaration.file_kind, FileKind::Module);
+    assert!(declaration.declaration);
+    assert_eq!(syntax("a.mts"), Syntax::from_path(Path::new("a.mts")));
+}
+
+#[test]
+fn parsing_does_not_load_plugins() {
+    toml(r#"plugins = ["missing/librslint_missing.so"]"#);
+}
+
+/// Load the plugins of a config written to a temporary directory, returning the text of the
+/// primary span of the error.
+fn plugin_error(name: &str, source: &str) -> String {
+    let dir = std::env::temp_dir().join(format!("rslint-plugins-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join(name), source).unwrap();
+    let res = unsafe { Config::load_plugins_in(&dir, true) };
+    std::fs::remove_file(dir.join(name)).unwrap();
+
+    let (_, diagnostic) = *res.expect_err("the plugin should fail to load");
+    assert!(diagnostic.title.starts_with("failed to load the plugin"));
+    source[diagnostic.primary.unwrap().span.range].to_string()
+}
+
+#[test]
+fn plugin_errors_point_at_the_plugin() {
+    let toml = r#"
+# the libraries are built in plugins/
+plugins = ["plugins", "other"]
+"#;
+    assert_eq!(plugin_error("rslintrc.toml", toml), r#""plugins""#);
+
+    let json = r#"{ "file-types": { "plugins": "module" }, "plugins": ["plugins"] }"#;
+    assert_eq!(plugin_error("rslintrc.json", json), r#""plugins""#);
+}
+
+#[test]
+fn rule_key_paths() {
+    assert_eq!(
+        key_path("rules.errors.no-empty"),
+        ["rules", "errors", "no-empty"]
+    );
+    assert_eq!(
+        key_path("rules . \"warnings\".'no.empty'"),
+        ["rules", "warnings", "no.empty"]
+    );
+
+    let configures = |key: &str| configures_rule(&key_path(key), "no-empty");
+    assert!(configures("rules.errors.no-empty"));
+    assert!(configures("rules.warnings.noEmpty.allowEmptyCatch"));
+    assert!(!configures("rules.errors"));
+    assert!(!configures("rules.allowed.no-empty"));
+    assert!(!configures("rules.errors.getter-return"));
+}
+
+mod legacy {
+    use rslint_core::rule_prelude::*;
+
+    declare_lint! {
+        /// A rule replaced by `no-empty`.
+        #[derive(Default)]
+        OldEmpty,
+        legacy,
+        deprecated("no-empty"),
+        "old-empty",
+        /// A message which is only serialized if it is set.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub message: Option<String>
+    }
+
+    #[typetag::serde]
+    impl CstRule for OldEmpty {}
+}
+
+/// Register the rules of the `legacy` module once for all tests.
+fn register_legacy() {
+    static REGISTER: std::sync::Once = std::sync::Once::new();
+    REGISTER.call_once(|| {
+        rslint_core::registry::register_rules(vec![Box::new(legacy::OldEmpty::new())]).unwrap()
+    });
+}
+
+/// The text of the primary spans of the warnings of the rules store of a config.
+fn rules_store_warnings(name: &str, source: &str) -> Vec<(String, Option<String>)> {
+    register_legacy();
+
+    let config = match Config::from_source(source.to_string(), PathBuf::from(name)) {
+        Ok(config) => config,
+        Err((_, diagnostics)) => panic!("invalid config: {}", diagnostics[0].title),
+    };
+    config
+        .rules_store()
+        .1
+        .into_iter()
+        .map(|d| {
+            let span = d
+                .primary
+                .map(|primary| source[primary.span.range].to_string());
+            (d.title, span)
+        })
+        .collect()
+}
+
+#[test]
+fn deprecated_rules_point_at_their_config() {
+    let message = "the rule 'old-empty' is deprecated, use 'no-empty' instead".to_string();
+    let toml = "[rules.errors]\nold-empty = {}\n";
+    assert_eq!(
+        rules_store_warnings("rslintrc.toml", toml),
+        [(message.clone(), Some("old-empty".to_string()))]
+    );
+    let toml = "[rules.levels]\nold-empty = \"warn\"\n";
+    assert_eq!(
+        rules_store_warnings("rslintrc.toml", toml),
+        [(message.clone(), Some("old-empty".to_string()))]
+    );
+    let json = r#"{ "rules": { "groups": ["legacy"], "warnings": { "oldEmpty": {} } } }"#;
+    assert_eq!(
+        rules_store_warnings("rslintrc.json", json),
+        [(message, Some(r#""oldEmpty""#.to_string()))]
+    );
+}
+
+#[test]
+fn deprecated_rules_enabled_by_groups() {
+    let message = "the rule 'old-empty' is deprecated, use 'no-empty' instead".to_string();
+    let toml = "[rules]\ngroups = [\"errors\", \"legacy\"]\n";
+    assert_eq!(
+        rules_store_warnings("rslintrc.toml", toml),
+        [(message.clone(), Some(r#""legacy""#.to_string()))]
+    );
+    let json = r#"{ "rules": { "groups": ["errors", "legacy"] } }"#;
+    assert_eq!(
+        rules_store_warnings("rslintrc.json", json),
+        [(message, Some(r#""legacy""#.to_string()))]
+    );
+}
+
+/// The titles of the errors of an invalid config with the text of their primary spans and their help.
+fn config_errors(name: &str, source: &str) -> Vec<(String, String, Option<String>)> {
+    let diagnostics = match Config::from_source(source.to_string(), PathBuf::from(name)) {
+        Ok(_) => panic!("config should be invalid"),
+        Err((_, diagnostics)) => diagnostics,
+    };
+    diagnostics
+        .into_iter()
+        .map(|d| {
+            let span = source[d.primary.unwrap().span.range].to_string();
+            let help = d.footers.first().map(|footer| footer.msg.clone());
+            (d.title, span, help)
+        })
+        .collect()
+}
+
+#[test]
+fn unknown_options_suggest_the_option() {
+    let title =
+        "unknown option `assertion_style` for rule 'consistent-type-assertions'".to_string();
+    let help = Some("did you mean `assertionStyle`?".to_string());
+
+    let toml = "[rules.errors.consistent-type-assertions]\nassertion_style = \"never\"\n";
+    assert_eq!(
+        config_errors("rslintrc.toml", toml),
+        [(title.clone(), "assertion_style".to_string(), help.clone())]
+    );
+    let json = r#"{ "rules": { "errors": { "consistentTypeAssertions": { "assertion_style": "never" } } } }"#;
+    assert_eq!(
+        config_errors("rslintrc.json", json),
+        [(title, r#""assertion_style""#.to_string(), help)]
+    );
+}
+
+#[test]
+fn invalid_options_point_at_the_value() {
+    let title = "invalid value for option `assertionStyle` of rule 'consistent-type-assertions'";
+
+    let toml = "[rules.warnings]\nconsistent-type-assertions = { assertionStyle = 5 }\n";
+    let errors = config_errors("rslintrc.toml", toml);
+    assert_eq!(errors.len(), 1);
+    assert_eq!((errors[0].0.as_str(), errors[0].1.as_str()), (title, "5"));
+
+    let json =
+        r#"{ "rules": { "warnings": { "consistentTypeAssertions": { "assertionStyle": 5 } } } }"#;
+    let errors = config_errors("rslintrc.json", json);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        (errors[0].0.as_str(), errors[0].1.as_str()),
+        (title, r#""assertionStyle""#)
+    );
+}
+
+#[test]
+fn every_invalid_option_is_reported() {
+    let toml = r#"
+[rules.errors]
+consistent-type-assertions = { assertionStyle = "maybe" }
+
+[rules.warnings]
+block-spacing = { styl = "always" }
+"#;
+    let spans = config_errors("rslintrc.toml", toml)
+        .into_iter()
+        .map(|(_, span, _)| span)
+        .collect::<Vec<_>>();
+    assert_eq!(spans, [r#""maybe""#, "styl"]);
+
+    // the same rule in both levels is located in the level with the invalid option
+    let json = r#"{ "rules": {
+        "errors": { "blockSpacing": { "style": "always" } },
+        "warnings": { "blockSpacing": { "style": 1, "other": true } }
+    } }"#;
+    let (_, diagnostics) = Config::from_source(json.to_string(), PathBuf::from("rslintrc.json"))
+        .expect_err("config should be invalid");
+    let warnings = json.find("\"warnings\"").unwrap();
+    let spans = diagnostics
+        .into_iter()
+        .map(|d| d.primary.unwrap().span.range)
+        .inspect(|range| assert!(range.start > warnings))
+        .map(|range| &json[range])
+        .collect::<Vec<_>>();
+    assert_eq!(spans, [r#""style""#, r#""other""#]);
+}
+
+#[test]
+fn options_without_a_default_value_are_known() {
+    register_legacy();
+    let toml = "[rules.errors.old-empty]\nmessage = \"use no-empty\"\n";
+    assert!(Config::from_source(toml.to_string(), PathBuf::from("rslintrc.toml")).is_ok());
+
+    let toml = "[rules.errors.old-empty]\nmesage = \"use no-empty\"\n";
+    assert_eq!(
+        config_errors("rslintrc.toml", toml),
+        [(
+            "unknown option `mesage` for rule 'old-empty'".to_string(),
+            "mesage".to_string(),
+            Some("did you mean `message`?".to_string())
+        )]
+    );
+}
+
+#[test]
+fn default_options_are_valid() {
+    for meta in rslint_core::registry::registry().rules() {
+        let value = serde_json::to_value(&meta.rule).unwrap();
+        let options = value.as_object().unwrap().values().next().unwrap();
+        let errors = crate::check_rule_options(meta.name, options.as_object().unwrap());
+        assert!(errors.is_empty(), "{}: {:?}", meta.name, errors);
+    }
+}
+
+/// The names of the rules in the rules store of a toml config.
+fn store_rules(config: &Config) -> Vec<&'static str> {
+    let (store, _) = config.rules_store();
+    store.rules.iter().map(|rule| rule.name()).collect()
+}
+
+#[test]
+fn levels_override_errors_and_warnings() {
+    let config = toml(
+        r#"
+[rules.errors]
+no-empty = {}
+
+[rules.warnings]
+block-spacing = {}
+
+[rules.levels]
+no-empty = "warn"
+block-spacing = "hint"
+"#,
+    );
+    assert_eq!(config.rule_level_by_name("no-empty"), RuleLevel::Warning);
+    assert_eq!(config.rule_level_by_name("block-spacing"), RuleLevel::Hint);
+    assert_eq!(config.rule_level_by_name("no-debugger"), RuleLevel::Error);
+    assert_eq!(store_rules(&config), ["no-empty", "block-spacing"]);
+}
+
+#[test]
+fn rules_only_in_levels_use_default_options() {
+    let config = toml("[rules.levels]\nblock-spacing = \"info\"\n");
+    assert_eq!(config.rule_level_by_name("block-spacing"), RuleLevel::Info);
+
+    let (store, _) = config.rules_store();
+    let defaults = rslint_core::get_rule_by_name("block-spacing").unwrap();
+    assert_eq!(store.rules.len(), 1);
+    assert_eq!(
+        serde_json::to_value(&store.rules[0]).unwrap(),
+        serde_json::to_value(&defaults).unwrap()
+    );
+}
+
+#[test]
+fn off_rules_are_removed_from_groups() {
+    let config = toml(
+        r#"
+[rules]
+groups = ["errors"]
+
+[rules.errors]
+no-debugger = {}
+
+[rules.levels]
+no-empty = "off"
+no-debugger = "off"
+"#,
+    );
+    let rules = store_rules(&config);
+    assert!(rules.contains(&"no-extra-semi"));
+    assert!(!rules.contains(&"no-empty"));
+    assert!(!rules.contains(&"no-debugger"));
+}
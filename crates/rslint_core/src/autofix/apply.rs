@@ -31,7 +31,6 @@ fn get_runnable_indels(mut tagged: Vec<TaggedIndel>) -> Vec<TaggedIndel> {
 }
 
 pub fn recursively_apply_fixes(result: &mut LintResult, file: &File) -> String {
-    let script = result.parsed.kind() == SyntaxKind::SCRIPT;
     let mut parsed = result.parsed.clone();
     let file_id = result.file_id;
     let mut cur_results = result.rule_results.clone();
@@ -47,16 +46,10 @@ pub fn recursively_apply_fixes(result: &mut LintResult, file: &File) -> String {
             &indels.iter().map(|x| x.indel.clone()).collect::<Vec<_>>(),
             &mut string,
         );
-        parsed = if script {
-            let res = parse_text(&string, file_id);
-            // this needs to be updated for when fixes are applied "dirty" (when there are parser errors)
-            result.parser_diagnostics = res.errors().to_owned();
-            res.syntax()
-        } else {
-            let res = parse_module(&string, file_id);
-            result.parser_diagnostics = res.errors().to_owned();
-            res.syntax()
-        };
+        let res = parse_with_syntax(&string, file_id, file.syntax);
+        // this needs to be updated for when fixes are applied "dirty" (when there are parser errors)
+        result.parser_diagnostics = res.errors().to_owned();
+        parsed = res.syntax();
 
         // TODO: should we panic on Err? autofix causing the linter to fail should always be incorrect
         let res = lint_file_inner(parsed.clone(), vec![], file, result.store, result.verbose);
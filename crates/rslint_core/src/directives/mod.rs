@@ -285,3 +285,35 @@ rule_tests! {
         "
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{groups::errors::NoEmpty, lint_file, CstRuleStore, File};
+    use rslint_parser::FileKind;
+
+    fn no_empty_count(code: &str) -> usize {
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![Box::new(NoEmpty::default()) as Box<_>]);
+        let file = File::from_string(code, FileKind::Module, "test.js");
+        lint_file(&file, &store, false)
+            .rule_results
+            .get("no-empty")
+            .map_or(0, |result| result.diagnostics.len())
+    }
+
+    #[test]
+    fn file_directives_after_a_shebang() {
+        assert_eq!(no_empty_count("#!/usr/bin/env node\n{}\n{}"), 2);
+        // the directive applies to the whole file, not only to the first statement
+        assert_eq!(
+            no_empty_count("#!/usr/bin/env node\n// rslint-ignore no-empty\n{}\n{}"),
+            0
+        );
+        assert_eq!(no_empty_count("// rslint-ignore no-empty\n{}\n{}"), 0);
+        // a directive for a statement only applies to that statement
+        assert_eq!(
+            no_empty_count("#!/usr/bin/env node\nlet a;\n// rslint-ignore no-empty\n{}\n{}"),
+            1
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Explanations of rules built from their docs and metadata, shown by the CLI and editors.
+
+use crate::{plugin::get_plugin_rule, registry::registry};
+
+/// The explanation of a rule as markdown, or `None` if the rule does not exist.
+///
+/// The explanation contains the metadata of the rule, its docs without the config and extra
+/// examples, and a link to the full docs.
+pub fn markdown(rule_name: &str) -> Option<String> {
+    let mut docs = registry().rule(rule_name)?.docs.to_string();
+    strip_config_or_extra_examples(&mut docs);
+    docs.insert_str(0, &metadata(rule_name)?);
+    if let Some((label, link)) = docs_link(rule_name) {
+        docs.push_str(&format!("\n[{}]({})\n", label, link));
+    }
+    Some(docs)
+}
+
+/// Remove the config section and the collapsed extra examples from the docs of a rule.
+pub fn strip_config_or_extra_examples(docs: &mut String) {
+    if let Some(idx) = docs.find("# Config") {
+        docs.truncate(idx.saturating_sub(1));
+    }
+    if let Some(idx) = docs.find("<details>") {
+        docs.truncate(idx.saturating_sub(1));
+    }
+}
+
+/// The metadata of a rule shown before its docs, such as its category and whether it is deprecated.
+pub fn metadata(rule_name: &str) -> Option<String> {
+    let registry = registry();
+    let meta = registry.rule(rule_name)?;
+
+    let mut items = vec![format!("group: {}", meta.group)];
+    if let Some(category) = meta.category {
+        items.push(format!("category: {}", category));
+    }
+    if meta.fixable || meta.suggestions {
+        items.push(format!("fix: {}", meta.fix_kind()));
+    }
+    if let Some(since) = meta.since {
+        items.push(format!("since: {}", since));
+    }
+
+    let mut metadata = items.join(" | ");
+    metadata.push_str("\n\n");
+    if let Some(deprecation) = meta.deprecated {
+        let line = match deprecation.replacement {
+            Some(replacement) => format!("Deprecated, use `{}` instead.\n\n", replacement),
+            None => "Deprecated.\n\n".to_string(),
+        };
+        metadata.insert_str(0, &line);
+    }
+    Some(metadata)
+}
+
+/// The label and the location of the docs of a rule, the docs of plugin rules are not on the website.
+pub fn docs_link(rule_name: &str) -> Option<(&'static str, String)> {
+    if let Some(plugin_rule) = get_plugin_rule(rule_name) {
+        return Some(("Plugin", plugin_rule.plugin.display().to_string()));
+    }
+    let group = registry().rule(rule_name)?.group;
+    Some((
+        "Docs",
+        format!("https://rslint.org/rules/{}/{}.html", group, rule_name),
+    ))
+}
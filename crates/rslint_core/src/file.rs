@@ -1,6 +1,7 @@
 //! Representation of a file for the linter
 
-use rslint_parser::{parse_with_syntax, FileKind, ParserError, SyntaxNode};
+use rslint_errors::file::LineIndex;
+use rslint_parser::{parse_with_syntax, FileKind, ParserError, Syntax, SyntaxNode};
 use std::ops::Range;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -20,24 +21,24 @@ pub struct File {
     pub id: usize,
     /// The kind of file this is.
     pub kind: FileKind,
+    /// The syntax the file is parsed with.
+    pub syntax: Syntax,
     /// The cached line start locations in this file.
-    pub line_starts: Vec<usize>,
+    pub lines: LineIndex,
+    /// Whether the file on disk starts with a byte order mark. The mark is not part of the source
+    /// and is written back when fixing the file.
+    pub bom: bool,
 }
 
+/// The UTF-8 byte order mark.
+const BOM: char = '\u{feff}';
+
 impl File {
-    pub fn new_concrete(source: String, path: PathBuf) -> Self {
+    /// Make a new file from a path on disk which is parsed with a specific syntax.
+    pub fn with_syntax(source: String, path: PathBuf, syntax: Syntax) -> Self {
         let id = FILE_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
-        let ext = path
-            .extension()
-            .map_or("".into(), |ext| ext.to_string_lossy());
-
-        let kind = match ext.as_ref() {
-            "mjs" => FileKind::Module,
-            "js" => FileKind::Script,
-            "ts" => FileKind::TypeScript,
-            _ => panic!("tried to make a file with extensions outside of `mjs`, `js`, or `ts`"),
-        };
-        let line_starts = Self::line_starts(&source).collect();
+        let (source, bom) = strip_bom(source);
+        let lines = LineIndex::new(&source);
 
         Self {
             source,
@@ -46,15 +47,17 @@ impl File {
                 .map_or(String::new(), |osstr| osstr.to_string_lossy().to_string()),
             path: Some(path),
             id,
-            kind,
-            line_starts,
+            kind: syntax.file_kind,
+            syntax,
+            lines,
+            bom,
         }
     }
 
     pub fn from_string(source: impl ToString, kind: FileKind, name: impl ToString) -> Self {
         let id = FILE_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
         let source = source.to_string();
-        let line_starts = Self::line_starts(&source).collect();
+        let lines = LineIndex::new(&source);
 
         Self {
             source,
@@ -62,39 +65,49 @@ impl File {
             path: None,
             id,
             kind,
-            line_starts,
+            syntax: kind.into(),
+            lines,
+            bom: false,
         }
     }
 
     pub fn overwrite_kind(&mut self, kind: FileKind) {
         self.kind = kind;
+        self.syntax = kind.into();
+    }
+
+    pub fn overwrite_syntax(&mut self, syntax: Syntax) {
+        self.kind = syntax.file_kind;
+        self.syntax = syntax;
     }
 
     pub fn update_src(&mut self, new: String) {
-        self.line_starts = Self::line_starts(&new).collect();
+        self.lines = LineIndex::new(&new);
         self.source = new;
     }
 
-    // TODO: Needs to work correctly for \u2028, \u2029, and \r line endings
-    pub fn line_starts(source: &str) -> impl Iterator<Item = usize> + '_ {
-        std::iter::once(0).chain(source.match_indices('\n').map(|(i, _)| i + 1))
+    /// Update the source of a concrete file after it was reread from disk.
+    pub fn reload_src(&mut self, new: String) {
+        let (new, bom) = strip_bom(new);
+        self.bom = bom;
+        self.update_src(new);
     }
 
-    pub fn line_start(&self, line_index: usize) -> Option<usize> {
-        use std::cmp::Ordering;
-
-        match line_index.cmp(&self.line_starts.len()) {
-            Ordering::Less => self.line_starts.get(line_index).cloned(),
-            Ordering::Equal => Some(self.source.len()),
-            Ordering::Greater => None,
+    /// The source as it should be written to disk, with the byte order mark if the file had one.
+    pub fn disk_source(&self, source: &str) -> String {
+        if self.bom {
+            format!("{}{}", BOM, source)
+        } else {
+            source.to_string()
         }
     }
 
+    pub fn line_start(&self, line_index: usize) -> Option<usize> {
+        self.lines.line_start(line_index)
+    }
+
     pub fn line_index(&self, byte_index: usize) -> usize {
-        match self.line_starts.binary_search(&byte_index) {
-            Ok(line) => line,
-            Err(next_line) => next_line - 1,
-        }
+        self.lines.line_index(byte_index)
     }
 
     pub fn line_col_to_index(&self, line: usize, column: usize) -> Option<usize> {
@@ -103,19 +116,24 @@ impl File {
     }
 
     pub fn line_range(&self, line_index: usize) -> Option<Range<usize>> {
-        let line_start = self.line_start(line_index)?;
-        let next_line_start = self.line_start(line_index + 1)?;
-
-        Some(line_start..next_line_start)
+        self.lines.line_range(line_index)
     }
 
     /// Parse this file into a syntax node, ignoring any errors produced. This
     pub fn parse(&self) -> SyntaxNode {
-        parse_with_syntax(&self.source, self.id, self.kind.into()).syntax()
+        parse_with_syntax(&self.source, self.id, self.syntax).syntax()
     }
 
     pub fn parse_with_errors(&self) -> (Vec<ParserError>, SyntaxNode) {
-        let parse = parse_with_syntax(&self.source, self.id, self.kind.into());
+        let parse = parse_with_syntax(&self.source, self.id, self.syntax);
         (parse.errors().to_vec(), parse.syntax())
     }
 }
+
+/// Strip a leading byte order mark from a source, returns whether the source had one.
+fn strip_bom(source: String) -> (String, bool) {
+    match source.strip_prefix(BOM) {
+        Some(stripped) => (stripped.to_string(), true),
+        None => (source, false),
+    }
+}
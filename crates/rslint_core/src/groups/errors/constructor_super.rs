@@ -69,6 +69,8 @@ declare_lint! {
     ConstructorSuper,
     errors,
     tags(Recommended),
+    category(Correctness),
+    since("0.1.0"),
     "constructor-super",
 }
 
@@ -38,6 +38,8 @@ declare_lint! {
     ForDirection,
     errors,
     tags(Recommended),
+    category(Correctness),
+    since("0.1.0"),
     "for-direction"
 }
 
@@ -61,6 +61,8 @@ declare_lint! {
     GetterReturn,
     errors,
     tags(Recommended),
+    category(Correctness),
+    since("0.1.0"),
     "getter-return",
     /// Whether to allow implicitly returning undefined with `return;`.
     /// `true` by default.
@@ -16,14 +16,14 @@ declare_lint! {
 
     ```js
     let foo = new Promise(async (resolve, reject) => {
-        doSomething(bar, (err, res)) => {
+        doSomething(bar, (err, res) => {
            /* */
         });
     });
     ```
 
     ```js
-    let foo = new Promise(async function(resolve, reject) => {
+    let foo = new Promise(async function(resolve, reject) {
         /* */
     });
     ```
@@ -33,7 +33,7 @@ declare_lint! {
     Use a normal non-async function.
 
     ```js
-    let foo = new Promise(function(resolve, reject) => {
+    let foo = new Promise(function(resolve, reject) {
         /* */
     })
     ```
@@ -42,6 +42,8 @@ declare_lint! {
     NoAsyncPromiseExecutor,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-async-promise-executor"
 }
 
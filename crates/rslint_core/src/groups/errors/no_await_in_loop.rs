@@ -42,6 +42,8 @@ declare_lint! {
     NoAwaitInLoop,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-await-in-loop"
 }
 
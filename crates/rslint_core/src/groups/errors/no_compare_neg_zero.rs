@@ -33,7 +33,9 @@ declare_lint! {
     #[derive(Default)]
     NoCompareNegZero,
     errors,
-    tags(Recommended),
+    tags(Recommended, Fixable, Suggestions),
+    category(Correctness),
+    since("0.1.0"),
     "no-compare-neg-zero"
 }
 
@@ -102,3 +104,9 @@ rule_tests! {
         "Object.is(x, -0)"
     }
 }
+
+rule_fix_tests! {
+    NoCompareNegZero::default(),
+    "x == -0" => "Object.is(x, -0)",
+    "if (-0 === x) {}" => "if (Object.is(x, -0)) {}",
+}
@@ -37,7 +37,9 @@ declare_lint! {
     #[serde(default)]
     NoCondAssign,
     errors,
-    tags(Recommended),
+    tags(Recommended, Suggestions),
+    category(Suspicious),
+    since("0.1.0"),
     "no-cond-assign",
     /// Allow an assignment if they are enclosed in parentheses to allow
     /// things like reassigning a variable.
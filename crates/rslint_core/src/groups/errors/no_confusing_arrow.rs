@@ -31,6 +31,8 @@ declare_lint! {
     NoConfusingArrow,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-confusing-arrow",
     /// Relaxes the rule and accepts parenthesis as a valid "confusion-preventing" syntax.
     /// `true` by default.
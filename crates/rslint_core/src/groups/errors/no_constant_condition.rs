@@ -40,6 +40,8 @@ declare_lint! {
     NoConstantCondition,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-constant-condition"
 }
 
@@ -21,6 +21,8 @@ declare_lint! {
     NoDebugger,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-debugger"
 }
 
@@ -23,6 +23,8 @@ declare_lint! {
     NoDupeKeys,
     errors,
     tags(Recommended),
+    category(Correctness),
+    since("0.1.0"),
     "no-dupe-keys"
 }
 
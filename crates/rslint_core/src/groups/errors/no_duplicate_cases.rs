@@ -37,6 +37,8 @@ declare_lint! {
     NoDuplicateCases,
     errors,
     tags(Recommended),
+    category(Correctness),
+    since("0.1.0"),
     "no-duplicate-cases"
 }
 
@@ -31,6 +31,8 @@ declare_lint! {
     NoDuplicateImports,
     errors,
     tags(Recommended),
+    category(Style),
+    since("0.1.0"),
     "no-duplicate-imports",
     /// Whether to check if re-exported
     pub include_exports: bool
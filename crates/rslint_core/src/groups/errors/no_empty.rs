@@ -34,6 +34,8 @@ declare_lint! {
     NoEmpty,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-empty",
     /// Whether to disallow empty block statements in function declarations, arrow functions,
     /// getters, setters, and methods.
@@ -24,6 +24,8 @@ declare_lint! {
     NoExtraBooleanCast,
     errors,
     tags(Recommended),
+    category(Complexity),
+    since("0.1.0"),
     "no-extra-boolean-cast",
     /// If this option is `true`, this rule will also check for unnecessary boolean
     /// cast inside logical expression, which is disabled by default.
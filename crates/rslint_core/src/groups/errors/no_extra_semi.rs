@@ -25,7 +25,9 @@ declare_lint! {
     #[derive(Default)]
     NoExtraSemi,
     errors,
-    tags(Recommended),
+    tags(Recommended, Fixable, Suggestions),
+    category(Style),
+    since("0.1.0"),
     "no-extra-semi"
 }
 
@@ -85,3 +87,9 @@ rule_tests! {
       "
   }
 }
+
+rule_fix_tests! {
+    NoExtraSemi::default(),
+    "let a = 5;;" => "let a = 5;",
+    "class Foo { bar() {}; }" => "class Foo { bar() {} }",
+}
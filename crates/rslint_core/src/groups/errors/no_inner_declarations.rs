@@ -39,6 +39,8 @@ declare_lint! {
     NoInnerDeclarations,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-inner-declarations",
     /// What declarations to disallow in nested blocks, it can include two possible options:
     /// "functions" and "variables", you can include either or, or both. Disallows only functions
@@ -48,6 +48,8 @@ declare_lint! {
     NoIrregularWhitespace,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-irregular-whitespace",
     /// Whether to allow any whitespace in string literals (true by default)
     pub skip_strings: bool,
@@ -25,7 +25,9 @@ declare_lint! {
     #[derive(Default)]
     NoNewSymbol,
     errors,
-    tags(Recommended),
+    tags(Recommended, Fixable, Suggestions),
+    category(Correctness),
+    since("0.1.0"),
     "no-new-symbol",
 }
 
@@ -72,3 +74,9 @@ rule_tests! {
         "
     }
 }
+
+rule_fix_tests! {
+    NoNewSymbol::default(),
+    "let foo = new Symbol('foo');" => "let foo = Symbol('foo');",
+    "new  Symbol()" => "Symbol()",
+}
@@ -36,7 +36,9 @@ declare_lint! {
     #[derive(Default)]
     NoPrototypeBuiltins,
     errors,
-    tags(Recommended),
+    tags(Recommended, Suggestions),
+    category(Suspicious),
+    since("0.1.0"),
     "no-prototype-builtins"
 }
 
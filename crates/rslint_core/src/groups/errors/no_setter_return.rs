@@ -100,6 +100,8 @@ declare_lint! {
     NoSetterReturn,
     errors,
     tags(Recommended),
+    category(Correctness),
+    since("0.1.0"),
     "no-setter-return",
 }
 
@@ -25,6 +25,8 @@ declare_lint! {
     NoSparseArrays,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-sparse-arrays"
 }
 
@@ -78,6 +78,8 @@ declare_lint! {
     NoThisBeforeSuper,
     errors,
     tags(Recommended),
+    category(Correctness),
+    since("0.3.0"),
     "no-this-before-super",
 }
 
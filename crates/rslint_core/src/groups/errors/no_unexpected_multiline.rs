@@ -65,6 +65,8 @@ declare_lint! {
     NoUnexpectedMultiline,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-unexpected-multiline"
 }
 
@@ -47,6 +47,8 @@ declare_lint! {
     NoUnsafeFinally,
     errors,
     tags(Recommended),
+    category(Suspicious),
+    since("0.1.0"),
     "no-unsafe-finally"
 }
 
@@ -29,7 +29,9 @@ declare_lint! {
     #[derive(Default)]
     NoUnsafeNegation,
     errors,
-    tags(Recommended),
+    tags(Recommended, Fixable, Suggestions),
+    category(Correctness),
+    since("0.1.0"),
     "no-unsafe-negation"
 }
 
@@ -99,3 +101,9 @@ rule_tests! {
         "1 in [1, 1, 1, ((!1) in [1111111111, 111])]"
     }
 }
+
+rule_fix_tests! {
+    NoUnsafeNegation::default(),
+    "!foo in bar" => "!(foo in bar)",
+    "if (!foo instanceof Bar) {}" => "if (!(foo instanceof Bar)) {}",
+}
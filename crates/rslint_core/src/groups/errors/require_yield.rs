@@ -31,6 +31,8 @@ declare_lint! {
   #[derive(Default)]
   RequireYield,
   errors,
+  category(Suspicious),
+  since("0.1.0"),
   "require_yield"
 }
 
@@ -40,7 +40,9 @@ declare_lint! {
     #[serde(default)]
     UseIsnan,
     errors,
-    tags(Recommended),
+    tags(Recommended, Suggestions),
+    category(Correctness),
+    since("0.1.0"),
     "use-isnan",
     /// Switch statements use `===` internally to match an expression, therefore `switch (NaN)` and `case NaN` will never match.
     /// This rule disables uses like that which are always incorrect (true by default)
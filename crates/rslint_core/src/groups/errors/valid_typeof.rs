@@ -24,6 +24,8 @@ declare_lint! {
     ValidTypeof,
     errors,
     tags(Recommended),
+    category(Correctness),
+    since("0.1.0"),
     "valid-typeof",
 
     /**
@@ -3,10 +3,12 @@
 pub mod errors;
 pub mod regex;
 pub mod style;
+pub mod typescript;
 
 pub use errors::errors;
 pub use regex::regex;
 pub use style::style;
+pub use typescript::typescript;
 
 /// Macro for easily making a rule group hashmap.
 /// This will call `::new()` on each rule.  
@@ -25,3 +27,46 @@ macro_rules! group {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{markdown, CstRuleStore};
+
+    /// The code examples in the docs of every rule should at least be valid code.
+    #[test]
+    fn rule_doc_examples_parse() {
+        let mut failures = vec![];
+        for rule in CstRuleStore::new().builtins().rules {
+            // the docs are the raw text of a block comment, so they are indented like the rule
+            let indent = rule
+                .docs()
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| line.len() - line.trim_start().len())
+                .min()
+                .unwrap_or_default();
+            let docs = rule
+                .docs()
+                .lines()
+                .map(|line| line.get(indent..).unwrap_or_default())
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            for block in markdown::code_blocks(&docs) {
+                let code = &docs[block.range];
+                let errors = rslint_parser::parse_with_syntax(code, 0, block.syntax)
+                    .errors()
+                    .len();
+                if errors != 0 {
+                    failures.push(format!(
+                        "\nExample of `{}`:\n```\n{}```\nhas {} parsing errors",
+                        rule.name(),
+                        code,
+                        errors
+                    ));
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -13,8 +13,10 @@ use std::sync::Mutex;
 use std::{collections::HashMap, ops::Range};
 
 type RegexResult = Result<(Regex, Range<usize>), (Range<usize>, String)>;
+/// Regexes are parsed differently depending on the targeted ECMAScript version.
+type RegexKey = (Range<usize>, EcmaVersion);
 
-pub(crate) static REGEX_MAP: Lazy<Mutex<HashMap<Range<usize>, RegexResult>>> =
+pub(crate) static REGEX_MAP: Lazy<Mutex<HashMap<RegexKey, RegexResult>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
 group! {
@@ -27,17 +29,38 @@ group! {
 pub(crate) fn maybe_parse_and_store_regex(
     node: &SyntaxNode,
     file_id: usize,
+    ecma_version: rslint_parser::EcmaVersion,
 ) -> Option<RegexResult> {
+    let ecma_version = regex_version(ecma_version);
     let mut map_handle = REGEX_MAP.lock().unwrap();
-    if let Some(r) = map_handle.get(&node.as_range()) {
+    let key = (node.as_range(), ecma_version);
+    if let Some(r) = map_handle.get(&key) {
         return Some(r.to_owned());
     }
-    let r = collect_regex_from_node(node, file_id)?;
-    map_handle.insert(node.as_range(), r.clone());
+    let r = collect_regex_from_node(node, file_id, ecma_version)?;
+    map_handle.insert(key, r.clone());
     Some(r)
 }
 
-fn collect_regex_from_node(node: &SyntaxNode, file_id: usize) -> Option<RegexResult> {
+/// The version of the regex syntax of files targeting an ECMAScript version.
+fn regex_version(version: rslint_parser::EcmaVersion) -> EcmaVersion {
+    match version {
+        rslint_parser::EcmaVersion::ES2015 => EcmaVersion::ES2015,
+        rslint_parser::EcmaVersion::ES2016 => EcmaVersion::ES2016,
+        rslint_parser::EcmaVersion::ES2017 => EcmaVersion::ES2017,
+        rslint_parser::EcmaVersion::ES2018 => EcmaVersion::ES2018,
+        rslint_parser::EcmaVersion::ES2019 => EcmaVersion::ES2019,
+        rslint_parser::EcmaVersion::ES2020 => EcmaVersion::ES2020,
+        rslint_parser::EcmaVersion::ES2021 => EcmaVersion::ES2021,
+        rslint_parser::EcmaVersion::ES2022 => EcmaVersion::ES2022,
+    }
+}
+
+fn collect_regex_from_node(
+    node: &SyntaxNode,
+    file_id: usize,
+    ecma_version: EcmaVersion,
+) -> Option<RegexResult> {
     match node.kind() {
         SyntaxKind::NEW_EXPR | SyntaxKind::CALL_EXPR => {
             let name = node.child_with_kind(SyntaxKind::NAME_REF);
@@ -65,7 +88,7 @@ fn collect_regex_from_node(node: &SyntaxNode, file_id: usize) -> Option<RegexRes
                     let range = range.as_range();
                     let new_range = range.start + 1..range.end - 1;
                     let flags = if let Some((flags, flag_range)) = flags {
-                        match validate_flags(&flags.to_string(), EcmaVersion::ES2021) {
+                        match validate_flags(&flags.to_string(), ecma_version) {
                             Ok(f) => f,
                             Err(err) => {
                                 return Some(Err((flag_range.as_range(), err)));
@@ -80,7 +103,7 @@ fn collect_regex_from_node(node: &SyntaxNode, file_id: usize) -> Option<RegexRes
                         pattern,
                         file_id,
                         range.as_range().start + 1,
-                        EcmaVersion::ES2021,
+                        ecma_version,
                         false,
                         flags,
                     );
@@ -97,13 +120,7 @@ fn collect_regex_from_node(node: &SyntaxNode, file_id: usize) -> Option<RegexRes
         }
         SyntaxKind::LITERAL if node.to::<Literal>().kind() == LiteralKind::Regex => {
             let pattern = &node.text().to_string();
-            let parser = Parser::new(
-                pattern,
-                file_id,
-                node.as_range().start,
-                EcmaVersion::ES2021,
-                false,
-            );
+            let parser = Parser::new(pattern, file_id, node.as_range().start, ecma_version, false);
             let range = node.as_range();
             let new_range = range.start + 1..range.end - 1;
             let res = match parser {
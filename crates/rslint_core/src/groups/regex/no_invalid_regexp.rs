@@ -23,13 +23,15 @@ declare_lint! {
     NoInvalidRegexp,
     regex,
     tags(Recommended),
+    category(Correctness),
+    since("0.3.0"),
     "no-invalid-regexp"
 }
 
 #[typetag::serde]
 impl CstRule for NoInvalidRegexp {
     fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
-        let res = maybe_parse_and_store_regex(node, ctx.file_id)?;
+        let res = maybe_parse_and_store_regex(node, ctx.file_id, ctx.ecma_version)?;
         if let Err((range, string)) = res {
             let err = ctx
                 .err(self.name(), "invalid regex pattern")
@@ -55,13 +55,16 @@ declare_lint! {
     #[derive(Default)]
     SimplifyRegex,
     regex,
+    tags(Fixable),
+    category(Complexity),
+    since("0.3.0"),
     "simplify-regex"
 }
 
 #[typetag::serde]
 impl CstRule for SimplifyRegex {
     fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
-        let (mut regex, range) = maybe_parse_and_store_regex(node, ctx.file_id)?.ok()?;
+        let (mut regex, range) = maybe_parse_and_store_regex(node, ctx.file_id, ctx.ecma_version)?.ok()?;
         let mut err = ctx
             .err(self.name(), "this regular expression can be simplified")
             .primary(range.clone(), "");
@@ -253,3 +256,9 @@ impl SimplifyRegex {
         }
     }
 }
+
+rule_fix_tests! {
+    SimplifyRegex::default(),
+    "/[0-9]/" => r"/\d/",
+    "/a{0,}b{1,}/" => "/a*b+/",
+}
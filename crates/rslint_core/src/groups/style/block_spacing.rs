@@ -1,6 +1,6 @@
 use crate::rule_prelude::*;
 use crate::util::StyleExt;
-use crate::Inferable;
+use crate::{Inferable, InferredOption};
 use ast::{BlockStmt, SwitchStmt};
 use SyntaxKind::{BLOCK_STMT, L_CURLY, R_CURLY, SWITCH_STMT};
 
@@ -51,6 +51,9 @@ declare_lint! {
     #[derive(rslint_macros::Mergeable)]
     BlockSpacing,
     style,
+    tags(Fixable),
+    category(Style),
+    since("0.3.0"),
     "block-spacing",
     /// The style of spacing, either "always" (default) to require one or more spaces, or
     /// "never" to disallow spaces
@@ -129,7 +132,7 @@ fn is_empty(node: &SyntaxNode) -> bool {
 
 #[typetag::serde]
 impl Inferable for BlockSpacing {
-    fn infer(&mut self, nodes: &[SyntaxNode]) {
+    fn infer(&mut self, nodes: &[SyntaxNode]) -> Vec<InferredOption> {
         let mut inferred_structs = vec![];
         for node in nodes {
             if matches!(node.kind(), SWITCH_STMT | BLOCK_STMT) {
@@ -144,8 +147,12 @@ impl Inferable for BlockSpacing {
                 }
             }
         }
-        if let Some(new) = Self::merge(inferred_structs) {
-            *self = new;
+        match Self::merge_with_confidence(inferred_structs) {
+            Some((new, options)) => {
+                *self = new;
+                options
+            }
+            None => vec![],
         }
     }
 }
@@ -242,3 +249,11 @@ rule_tests! {
         "(() => {bar();});",
     }
 }
+
+rule_fix_tests! {
+    BlockSpacing::default(),
+    "{foo();}" => "{ foo(); }",
+    "if (a) {foo(); }" => "if (a) { foo(); }",
+    (BlockSpacing { style: "never".to_string() }) "{ foo(); }" => "{foo();}",
+    (BlockSpacing { style: "never".to_string() }) "(() => {   bar();   });" => "(() => {bar();});",
+}
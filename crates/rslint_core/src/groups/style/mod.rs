@@ -5,5 +5,6 @@ use crate::group;
 group! {
     /// Rules which relate to code style and formatting.
     style,
-    block_spacing::BlockSpacing
+    block_spacing::BlockSpacing,
+    no_restricted_syntax::NoRestrictedSyntax
 }
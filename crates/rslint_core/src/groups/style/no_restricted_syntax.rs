@@ -0,0 +1,126 @@
+use crate::rule_prelude::*;
+use crate::selector::Selector;
+
+declare_lint! {
+    /**
+    Disallow syntax matched by selectors.
+
+    Some syntax is valid but not wanted in a codebase, such as calls to a deprecated library or default exports.
+    This rule reports every node matched by one of the configured selectors, which allows banning such syntax
+    without writing a new rule.
+
+    A selector matches nodes by their syntax kind, and optionally their text and their fields:
+
+    | Selector                                       | Matches                                      |
+    | ---------------------------------------------- | -------------------------------------------- |
+    | `WITH_STMT`                                    | any `with` statement                         |
+    | `CALL_EXPR[callee=NAME_REF("moment")]`         | `moment()`                                   |
+    | `CALL_EXPR[callee=DOT_EXPR[object="console"]]` | `console.log()`, `console.warn()`, etc       |
+    | `BIN_EXPR[op="=="], BIN_EXPR[op="!="]`         | loose comparisons                            |
+    | `FN_DECL > BLOCK_STMT > EXPR_STMT`             | expression statements directly in functions  |
+    | `CLASS_DECL METHOD`                            | methods of class declarations, even nested   |
+
+    `[field]` matches nodes which have the field, and `[field!=value]` nodes which do not match the value.
+    Fields are named like the accessors of the AST nodes, for instance `callee` and `arguments` for calls,
+    `object` and `prop` for member expressions, and `lhs`, `op`, and `rhs` for binary expressions.
+
+    ## Incorrect Code Examples
+
+    With `selectors = [{ selector = "CALL_EXPR[callee=NAME_REF(\"moment\")]", message = "use `Temporal` instead of moment" }]`:
+
+    ```js
+    let now = moment();
+    ```
+
+    With `selectors = [{ selector = "EXPORT_DEFAULT_DECL, EXPORT_DEFAULT_EXPR" }]`:
+
+    ```js
+    export default function foo() {}
+    ```
+
+    ## Correct Code Examples
+
+    With `selectors = [{ selector = "CALL_EXPR[callee=NAME_REF(\"moment\")]" }]`:
+
+    ```js
+    let now = Temporal.now.instant();
+    let then = moment.unix(1000);
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    NoRestrictedSyntax,
+    style,
+    category(Restriction),
+    since("0.4.0"),
+    "no-restricted-syntax",
+    /// A list of the syntax to disallow, each entry has a `selector` and an optional `message`
+    /// which is shown instead of the default message.
+    pub selectors: Vec<RestrictedSyntax>
+}
+
+/// A selector disallowed by the rule with the message to show when it matches.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RestrictedSyntax {
+    pub selector: Selector,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl RestrictedSyntax {
+    pub fn new(selector: Selector, message: impl Into<Option<String>>) -> Self {
+        Self {
+            selector,
+            message: message.into(),
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoRestrictedSyntax {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        for restricted in self.selectors.iter() {
+            if restricted.selector.matches(node) {
+                let message = restricted.message.clone().unwrap_or_else(|| {
+                    format!("using `{}` is not allowed", restricted.selector)
+                });
+                let err = ctx
+                    .err(self.name(), message)
+                    .primary(node.trimmed_range(), "");
+                ctx.add_err(err);
+            }
+        }
+        None
+    }
+}
+
+rule_tests! {
+    NoRestrictedSyntax {
+        selectors: vec![
+            RestrictedSyntax::new(
+                r#"CALL_EXPR[callee=NAME_REF("moment")]"#.parse().unwrap(),
+                "use `Temporal` instead of moment".to_string(),
+            ),
+            RestrictedSyntax::new("DEBUGGER_STMT".parse().unwrap(), None),
+            RestrictedSyntax::new(r#"BIN_EXPR[op="=="], BIN_EXPR[op="!="]"#.parse().unwrap(), None),
+            RestrictedSyntax::new("EXPORT_DEFAULT_DECL, EXPORT_DEFAULT_EXPR".parse().unwrap(), None),
+        ]
+    },
+    err: {
+        "let now = moment();",
+        "foo(moment())",
+        "debugger;",
+        "if (a == b) {}",
+        "a != null",
+        "export default function foo() {}",
+        "export default 5",
+    },
+    ok: {
+        "let now = Temporal.now.instant();",
+        "moment.unix(1000)",
+        "new moment()",
+        "a === b",
+        "export function foo() {}",
+    }
+}
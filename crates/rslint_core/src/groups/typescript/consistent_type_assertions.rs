@@ -0,0 +1,178 @@
+use crate::rule_prelude::*;
+use ast::{TsAssertion, TsConstAssertion};
+use SyntaxKind::{TS_ASSERTION, TS_CONST_ASSERTION};
+
+declare_lint! {
+    /**
+    Enforce a consistent style of type assertions.
+
+    TypeScript has two syntaxes for type assertions, `foo as Bar` and `<Bar>foo`. They do the same thing,
+    but angle bracket assertions cannot be used in `.tsx` files because they look like JSX elements.
+    This rule enforces one of the styles, or disallows type assertions altogether, since they override
+    the types the compiler inferred.
+
+    ## As (default)
+
+    ### Incorrect code examples
+
+    ```ts
+    let foo = <string>bar;
+    let baz = <const>["a", "b"];
+    ```
+
+    ### Correct code examples
+
+    ```ts
+    let foo = bar as string;
+    let baz = ["a", "b"] as const;
+    ```
+
+    ## Angle bracket
+
+    ### Incorrect code examples
+
+    ```ts
+    let foo = bar as string;
+    ```
+
+    ### Correct code examples
+
+    ```ts
+    let foo = <string>bar;
+    ```
+
+    ## Never
+
+    Const assertions (`as const`) are still allowed with this style because they do not override any types.
+
+    ### Incorrect code examples
+
+    ```ts
+    let foo = bar as string;
+    let baz = <string>bar;
+    ```
+
+    ### Correct code examples
+
+    ```ts
+    let foo: string = bar;
+    let baz = ["a", "b"] as const;
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    ConsistentTypeAssertions,
+    typescript,
+    tags(OnlyTS),
+    category(Style),
+    since("0.4.0"),
+    "consistent-type-assertions",
+    /// The style of type assertions, either "as" (default) to require `foo as Bar`,
+    /// "angle-bracket" to require `<Bar>foo`, or "never" to disallow type assertions other
+    /// than const assertions.
+    pub assertion_style: AssertionStyle
+}
+
+/// The style of type assertions enforced by the rule.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AssertionStyle {
+    /// Require `foo as Bar`.
+    #[default]
+    As,
+    /// Require `<Bar>foo`.
+    AngleBracket,
+    /// Disallow type assertions other than const assertions.
+    Never,
+}
+
+#[typetag::serde]
+impl CstRule for ConsistentTypeAssertions {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let (angle_bracket, expr, ty) = match node.kind() {
+            TS_ASSERTION => {
+                let assertion = node.to::<TsAssertion>();
+                let ty = assertion.ty()?.text();
+                (assertion.l_angle_token().is_some(), assertion.expr()?, ty)
+            }
+            TS_CONST_ASSERTION => {
+                let assertion = node.to::<TsConstAssertion>();
+                let ty = "const".to_string();
+                (assertion.l_angle_token().is_some(), assertion.expr()?, ty)
+            }
+            _ => return None,
+        };
+        let expr = expr.text();
+
+        let err = match self.assertion_style {
+            AssertionStyle::As if angle_bracket => ctx
+                .err(
+                    self.name(),
+                    "use `as` instead of angle bracket type assertions",
+                )
+                .primary(node, format!("help: write this as `{} as {}`", expr, ty)),
+            AssertionStyle::AngleBracket if !angle_bracket => ctx
+                .err(
+                    self.name(),
+                    "use angle bracket type assertions instead of `as`",
+                )
+                .primary(node, format!("help: write this as `<{}>{}`", ty, expr)),
+            AssertionStyle::Never if node.kind() == TS_ASSERTION => ctx
+                .err(self.name(), "type assertions are not allowed")
+                .primary(node, "")
+                .footer_help("annotate the type of the variable or narrow the type of the value instead"),
+            _ => return None,
+        };
+        ctx.add_err(err);
+        None
+    }
+}
+
+ts_rule_tests! {
+    ConsistentTypeAssertions::default(),
+    err: {
+        "let foo = <string>bar;",
+        "let foo = <const>['a'];",
+        "foo(<any>bar, <Baz>qux);",
+    },
+    ok: {
+        "let foo = bar as string;",
+        "let foo = ['a'] as const;",
+        "let foo = bar as unknown as Baz;",
+        "let foo: string = bar;",
+    }
+}
+
+ts_rule_tests! {
+    angle_bracket_valid,
+    angle_bracket_invalid,
+    ConsistentTypeAssertions {
+        assertion_style: AssertionStyle::AngleBracket,
+    },
+    err: {
+        "let foo = bar as string;",
+        "let foo = ['a'] as const;",
+    },
+    ok: {
+        "let foo = <string>bar;",
+        "let foo = <const>['a'];",
+    }
+}
+
+ts_rule_tests! {
+    never_valid,
+    never_invalid,
+    ConsistentTypeAssertions {
+        assertion_style: AssertionStyle::Never,
+    },
+    err: {
+        "let foo = bar as string;",
+        "let foo = <string>bar;",
+    },
+    ok: {
+        "let foo: string = bar;",
+        "let foo = ['a'] as const;",
+        "let foo = <const>['a'];",
+    }
+}
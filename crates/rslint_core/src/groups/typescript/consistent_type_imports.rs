@@ -0,0 +1,264 @@
+use crate::rule_prelude::*;
+use ast::{ExportDecl, ExportNamed, ImportClause, ImportDecl, Specifier};
+use std::collections::HashSet;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce `import type` for imports which are only used as types.
+
+    TypeScript removes imports which are only used as types when compiling, but it cannot always know
+    which imports those are, for instance when files are compiled one at a time by other tools. Marking
+    them with `import type` makes it explicit that the import has no runtime effect and is always removed.
+
+    Imports which are only used as types are marked as a whole if every binding of the import is only
+    used as a type, otherwise the named imports which are only used as types get an inline `type` modifier.
+    Names are matched across the whole file without considering scopes, so a binding is assumed to be
+    used as a value if any value of the same name is used.
+
+    ## Incorrect Code Examples
+
+    ```ts
+    import { Foo } from "foo";
+    let foo: Foo;
+    ```
+
+    ```ts
+    import { Bar, Baz } from "bar";
+    let bar: Bar = new Baz();
+    ```
+
+    ## Correct Code Examples
+
+    ```ts
+    import type { Foo } from "foo";
+    let foo: Foo;
+    ```
+
+    ```ts
+    import { type Bar, Baz } from "bar";
+    let bar: Bar = new Baz();
+    ```
+    */
+    #[derive(Default)]
+    ConsistentTypeImports,
+    typescript,
+    tags(OnlyTS, Fixable),
+    category(Style),
+    since("0.4.0"),
+    "consistent-type-imports"
+}
+
+#[typetag::serde]
+impl CstRule for ConsistentTypeImports {
+    fn check_root(&self, root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let usages = Usages::new(root);
+        for import in root.children().filter_map(|child| child.try_to::<ImportDecl>()) {
+            if import.type_token().is_none() {
+                self.check_import(&import, &usages, ctx);
+            }
+        }
+        None
+    }
+}
+
+impl ConsistentTypeImports {
+    fn check_import(&self, import: &ImportDecl, usages: &Usages, ctx: &mut RuleCtx) -> Option<()> {
+        let clauses = import.imports().collect::<Vec<_>>();
+        // bindings already marked as types do not matter for the rest of the import
+        let mut bindings = vec![];
+        for clause in &clauses {
+            match clause {
+                ImportClause::Name(name) => bindings.push((name.text(), None)),
+                ImportClause::WildcardImport(wildcard) => {
+                    bindings.push((wildcard.alias()?.text(), None))
+                }
+                ImportClause::NamedImports(named) => {
+                    for specifier in named.specifiers().filter(|s| s.type_token().is_none()) {
+                        let local = specifier
+                            .alias()
+                            .map(|alias| alias.text())
+                            .or_else(|| Some(specifier.name()?.text().to_string()))?;
+                        bindings.push((local, Some(specifier)));
+                    }
+                }
+                ImportClause::ImportStringSpecifier(_) => return None,
+            }
+        }
+
+        let type_only = |name: &str| usages.types.contains(name) && !usages.values.contains(name);
+        if bindings.iter().all(|(name, _)| !usages.values.contains(name))
+            && bindings.iter().any(|(name, _)| type_only(name))
+        {
+            let mut err = ctx
+                .err(
+                    self.name(),
+                    "all imports in this declaration are only used as types",
+                )
+                .primary(import.syntax(), "");
+            // a type only import cannot have both a default import and named imports
+            if clauses.len() > 1 {
+                err = err.footer_note(
+                    "a type only import cannot have both a default import and other imports, move them into separate declarations",
+                );
+                ctx.add_err(err);
+                return None;
+            }
+            ctx.add_err(err);
+
+            let fix = ctx.fix();
+            fix.insert_after(import.import_token()?, " type");
+            for type_token in named_specifiers(&clauses).filter_map(|s| s.type_token()) {
+                fix.delete(type_token.clone())
+                    .eat_trailing_whitespace(type_token);
+            }
+            return None;
+        }
+
+        for (name, specifier) in bindings {
+            if let Some(specifier) = specifier.filter(|_| type_only(&name)) {
+                let err = ctx
+                    .err(self.name(), format!("`{}` is only used as a type", name))
+                    .primary(specifier.syntax(), "");
+                ctx.add_err(err);
+                ctx.fix().insert_before(specifier.syntax(), "type ");
+            }
+        }
+        None
+    }
+}
+
+fn named_specifiers(clauses: &[ImportClause]) -> impl Iterator<Item = Specifier> + '_ {
+    clauses.iter().flat_map(|clause| match clause {
+        ImportClause::NamedImports(named) => named.specifiers().collect(),
+        _ => vec![],
+    })
+}
+
+/// The names used as values and as types in a file.
+struct Usages {
+    values: HashSet<String>,
+    types: HashSet<String>,
+}
+
+impl Usages {
+    fn new(root: &SyntaxNode) -> Self {
+        let mut values = HashSet::new();
+        let mut types = HashSet::new();
+        for node in root.descendants() {
+            match node.kind() {
+                // shorthand properties such as `{ foo }` use the value
+                NAME_REF | IDENT_PROP => {
+                    values.insert(node.text().to_string());
+                }
+                // only the first name of a qualified name such as `Foo.Bar` refers to a binding
+                TS_TYPE_NAME
+                    if node.parent().map(|parent| parent.kind()) != Some(TS_QUALIFIED_PATH)
+                        || node.prev_sibling().is_none() =>
+                {
+                    types.insert(node.text().to_string());
+                }
+                SPECIFIER => {
+                    let export = match node.parent().and_then(|p| p.try_to::<ExportNamed>()) {
+                        Some(export) if export.from_token().is_none() => export,
+                        _ => continue,
+                    };
+                    let specifier = node.to::<Specifier>();
+                    let name = match specifier.name() {
+                        Some(name) => name.text().to_string(),
+                        None => continue,
+                    };
+                    // `export type { foo }` has the `type` keyword in the declaration
+                    let type_export = specifier.type_token().is_some()
+                        || export.type_token().is_some()
+                        || export
+                            .syntax()
+                            .parent()
+                            .and_then(|decl| decl.try_to::<ExportDecl>()?.type_token())
+                            .is_some();
+                    if type_export {
+                        types.insert(name);
+                    } else {
+                        values.insert(name);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self { values, types }
+    }
+}
+
+ts_rule_tests! {
+    ConsistentTypeImports::default(),
+    err: {
+        "
+        import { Foo } from 'foo';
+        let foo: Foo;
+        ",
+        "
+        import Foo from 'foo';
+        let foo: Foo;
+        ",
+        "
+        import * as foo from 'foo';
+        let bar: foo.Bar;
+        ",
+        "
+        import { Foo, Bar } from 'foo';
+        let foo: Foo = new Bar();
+        ",
+        "
+        import { Foo as Baz, Bar } from 'foo';
+        let foo: Baz = new Bar();
+        ",
+        "
+        import Foo, { Bar } from 'foo';
+        let foo: Foo<Bar>;
+        ",
+        "
+        import { Foo } from 'foo';
+        export type { Foo };
+        ",
+        "
+        import { Foo, type Bar } from 'foo';
+        let foo: Foo<Bar>;
+        ",
+    },
+    ok: {
+        "
+        import type { Foo } from 'foo';
+        let foo: Foo;
+        ",
+        "
+        import { type Foo, Bar } from 'foo';
+        let foo: Foo = new Bar();
+        ",
+        "
+        import { Foo } from 'foo';
+        let foo: Foo = new Foo();
+        ",
+        "
+        import { Foo } from 'foo';
+        let foo: typeof Foo = { Foo };
+        export { Foo };
+        ",
+        "
+        import { Foo } from 'foo';
+        ",
+        "
+        import 'foo';
+        ",
+        "
+        import { Foo } from 'foo';
+        let bar: Bar.Foo;
+        ",
+    }
+}
+
+ts_rule_fix_tests! {
+    ConsistentTypeImports::default(),
+    "import { Foo } from 'foo';\nlet foo: Foo;" => "import type { Foo } from 'foo';\nlet foo: Foo;",
+    "import { Foo, type Bar } from 'foo';\nlet foo: Foo<Bar>;" => "import type { Foo, Bar } from 'foo';\nlet foo: Foo<Bar>;",
+    "import { Foo, Bar } from 'foo';\nlet foo: Foo = new Bar();" => "import { type Foo, Bar } from 'foo';\nlet foo: Foo = new Bar();",
+}
@@ -0,0 +1,12 @@
+//! Rules which only apply to TypeScript code.
+
+use crate::group;
+
+group! {
+    /// Rules which only apply to TypeScript code.
+    typescript,
+    no_explicit_any::NoExplicitAny,
+    consistent_type_assertions::ConsistentTypeAssertions,
+    no_non_null_assertion::NoNonNullAssertion,
+    consistent_type_imports::ConsistentTypeImports
+}
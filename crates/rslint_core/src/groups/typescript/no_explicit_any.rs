@@ -0,0 +1,93 @@
+use crate::rule_prelude::*;
+use SyntaxKind::TS_ANY;
+
+declare_lint! {
+    /**
+    Disallow the `any` type.
+
+    `any` turns off type checking for everything it touches, values of type `any` can be used
+    in any way and assigned to anything, which hides bugs the compiler would otherwise catch.
+    `unknown` is a type safe alternative, it also accepts any value but requires the value to be
+    narrowed before it is used.
+
+    ## Incorrect Code Examples
+
+    ```ts
+    function parse(input: string): any {
+        return JSON.parse(input);
+    }
+
+    let values: Array<any> = [];
+    ```
+
+    ## Correct Code Examples
+
+    ```ts
+    function parse(input: string): unknown {
+        return JSON.parse(input);
+    }
+
+    let values: Array<string> = [];
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    NoExplicitAny,
+    typescript,
+    tags(OnlyTS, Fixable, Suggestions),
+    category(Suspicious),
+    since("0.4.0"),
+    "no-explicit-any",
+    /// Automatically replace `any` with `unknown`, `false` by default because the fix
+    /// can introduce type errors where the value is used.
+    pub fix_to_unknown: bool
+}
+
+#[typetag::serde]
+impl CstRule for NoExplicitAny {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if node.kind() != TS_ANY {
+            return None;
+        }
+
+        let err = ctx
+            .err(self.name(), "unexpected `any` type")
+            .primary(node, "")
+            .suggestion(
+                node,
+                "use `unknown` instead",
+                "unknown",
+                Applicability::MaybeIncorrect,
+            );
+        ctx.add_err(err);
+
+        if self.fix_to_unknown {
+            ctx.fix().replace(node, "unknown");
+        }
+        None
+    }
+}
+
+ts_rule_tests! {
+    NoExplicitAny::default(),
+    err: {
+        "let foo: any;",
+        "function foo(bar: any): any {}",
+        "let foo: Array<any> = [];",
+        "let foo = bar as any;",
+        "type Foo = { bar: any };",
+        "function foo(...args: any[]) {}",
+    },
+    ok: {
+        "let foo: unknown;",
+        "let foo: Array<string> = [];",
+        "let any = 5;",
+        "let foo = any as unknown;",
+    }
+}
+
+ts_rule_fix_tests! {
+    NoExplicitAny { fix_to_unknown: true },
+    "let foo: any = 5;" => "let foo: unknown = 5;",
+    "function foo(bar: any): Array<any> {}" => "function foo(bar: unknown): Array<unknown> {}",
+}
@@ -0,0 +1,109 @@
+use crate::rule_prelude::*;
+use ast::TsNonNull;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow non-null assertions using the `!` postfix operator.
+
+    A non-null assertion (`foo!`) tells the compiler that a value is not `null` or `undefined` without checking it,
+    if the value turns out to be `null` or `undefined` the code fails at runtime where the compiler could have
+    caught it. Optional chaining or an explicit check are safer.
+
+    ## Incorrect Code Examples
+
+    ```ts
+    let name = user!.name;
+    let first = items![0];
+    ```
+
+    ## Correct Code Examples
+
+    ```ts
+    let name = user?.name;
+
+    if (items) {
+        let first = items[0];
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoNonNullAssertion,
+    typescript,
+    tags(OnlyTS, Suggestions),
+    category(Suspicious),
+    since("0.4.0"),
+    "no-non-null-assertion"
+}
+
+#[typetag::serde]
+impl CstRule for NoNonNullAssertion {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let excl = node.try_to::<TsNonNull>()?.excl_token()?;
+        let mut err = ctx
+            .err(self.name(), "forbidden non-null assertion")
+            .primary(excl.text_range(), "");
+
+        if let Some(replacement) = optional_chain(node) {
+            let msg = if replacement.is_empty() {
+                "the member access is already optional, remove the assertion"
+            } else {
+                "use optional chaining instead"
+            };
+            err = err.suggestion(
+                excl.text_range(),
+                msg,
+                replacement,
+                Applicability::MaybeIncorrect,
+            );
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// The text to replace the `!` of a non-null assertion with to turn the member access or call
+/// on it into an optional chain, `None` if it is not the object of a member access or call.
+fn optional_chain(node: &SyntaxNode) -> Option<&'static str> {
+    let parent = node.parent()?;
+    if parent.first_child()? != *node {
+        return None;
+    }
+    // optional chains cannot be assigned to
+    if parent
+        .parent()
+        .filter(|grandparent| grandparent.first_child().as_ref() == Some(&parent))
+        .map(|grandparent| matches!(grandparent.kind(), ASSIGN_EXPR | UNARY_EXPR))
+        == Some(true)
+    {
+        return None;
+    }
+
+    if parent.token_with_kind(QUESTIONDOT).is_some() {
+        return Some("");
+    }
+    match parent.kind() {
+        DOT_EXPR => Some("?"),
+        BRACKET_EXPR | CALL_EXPR => Some("?."),
+        _ => None,
+    }
+}
+
+ts_rule_tests! {
+    NoNonNullAssertion::default(),
+    err: {
+        "let name = user!.name;",
+        "let first = items![0];",
+        "callback!();",
+        "let name = user!?.name;",
+        "let value = foo.bar!;",
+        "user!.name = 'foo';",
+        "items![0]++;",
+    },
+    ok: {
+        "let name = user?.name;",
+        "let first = items?.[0];",
+        "let value = foo !== null;",
+        "let value = !foo;",
+    }
+}
@@ -32,17 +32,25 @@
 mod file;
 mod rule;
 mod store;
-mod testing;
 
 pub mod autofix;
 pub mod directives;
+pub mod explain;
 pub mod groups;
+pub mod markdown;
+pub mod plugin;
+pub mod registry;
 pub mod rule_prelude;
+pub mod selector;
+pub mod testing;
 pub mod util;
 
 pub use self::{
     file::File,
-    rule::{CstRule, Inferable, Outcome, Rule, RuleCtx, RuleLevel, RuleResult, Tag},
+    rule::{
+        Category, CstRule, Deprecation, Inferable, InferredOption, Outcome, Rule, RuleCtx,
+        RuleLevel, RuleResult, Tag,
+    },
     store::CstRuleStore,
 };
 pub use rslint_errors::{Diagnostic, Severity, Span};
@@ -53,7 +61,7 @@ pub use crate::directives::{
 };
 
 use dyn_clone::clone_box;
-use rslint_parser::{util::SyntaxNodeExt, SyntaxKind, SyntaxNode};
+use rslint_parser::{util::SyntaxNodeExt, EcmaVersion, SyntaxKind, SyntaxNode};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -139,23 +147,21 @@ pub(crate) fn lint_file_inner<'s>(
 
     let src: Arc<str> = Arc::from(node.to_string());
 
+    let ctx = LintCtx {
+        file_id: file.id,
+        root: node.clone(),
+        src,
+        directives: &directives,
+        ecma_version: file.syntax.ecma_version,
+        verbose,
+    };
+
     // FIXME: Replace with thread pool
     let results = new_store
         .rules
         .into_iter()
-        .map(|rule| {
-            (
-                rule.name(),
-                run_rule(
-                    &*rule,
-                    file.id,
-                    node.clone(),
-                    verbose,
-                    &directives,
-                    src.clone(),
-                ),
-            )
-        })
+        .filter(|rule| rule.runs_on(file.kind))
+        .map(|rule| (rule.name(), run_rule(&*rule, &ctx)))
         .collect();
 
     LintResult {
@@ -170,33 +176,42 @@ pub(crate) fn lint_file_inner<'s>(
     }
 }
 
+/// The context shared by every rule run on a parsed file, see [`run_rule`].
+#[derive(Clone)]
+pub struct LintCtx<'a> {
+    pub file_id: usize,
+    /// The root of the file, which must be a `SCRIPT` or `MODULE` node.
+    pub root: SyntaxNode,
+    pub src: Arc<str>,
+    /// The directives of the file, nodes they disable a rule for are skipped.
+    pub directives: &'a [Directive],
+    /// The ECMAScript version targeted by the file.
+    pub ecma_version: EcmaVersion,
+    pub verbose: bool,
+}
+
 /// Run a single run on an entire parsed file.
 ///
 /// # Panics
-/// Panics if `root`'s kind is not `SCRIPT` or `MODULE`
-pub fn run_rule(
-    rule: &dyn CstRule,
-    file_id: usize,
-    root: SyntaxNode,
-    verbose: bool,
-    directives: &[Directive],
-    src: Arc<str>,
-) -> RuleResult {
+/// Panics if the kind of the root of the file is not `SCRIPT` or `MODULE`
+pub fn run_rule(rule: &dyn CstRule, lint_ctx: &LintCtx) -> RuleResult {
+    let root = &lint_ctx.root;
     assert!(root.kind() == SyntaxKind::SCRIPT || root.kind() == SyntaxKind::MODULE);
     let mut ctx = RuleCtx {
-        file_id,
-        verbose,
+        file_id: lint_ctx.file_id,
+        verbose: lint_ctx.verbose,
         diagnostics: vec![],
         fixer: None,
-        src,
+        src: Arc::clone(&lint_ctx.src),
+        ecma_version: lint_ctx.ecma_version,
     };
 
-    rule.check_root(&root, &mut ctx);
+    rule.check_root(root, &mut ctx);
 
     root.descendants_with_tokens_with(&mut |elem| {
         match elem {
             rslint_parser::NodeOrToken::Node(node) => {
-                if skip_node(directives, node, rule) || node.kind() == SyntaxKind::ERROR {
+                if skip_node(lint_ctx.directives, node, rule) || node.kind() == SyntaxKind::ERROR {
                     return false;
                 }
                 rule.check_node(node, &mut ctx);
@@ -210,37 +225,21 @@ pub fn run_rule(
     RuleResult::new(ctx.diagnostics, ctx.fixer)
 }
 
-/// Get a rule by its kebab-case name.
+/// Get a rule by its kebab-case name from the [`registry`](registry::registry).
 pub fn get_rule_by_name(name: &str) -> Option<Box<dyn CstRule>> {
-    CstRuleStore::new()
-        .builtins()
-        .rules
-        .iter()
-        .find(|rule| rule.name() == name)
-        .map(|rule| clone_box(&**rule))
+    registry::registry()
+        .rule(name)
+        .map(|meta| clone_box(&*meta.rule))
 }
 
-/// Get a group's rules by the group name.
-// TODO: there should be a good way to not have to hardcode all of this
+/// Get a group's rules by the group name from the [`registry`](registry::registry).
 pub fn get_group_rules_by_name(group_name: &str) -> Option<Vec<Box<dyn CstRule>>> {
-    use groups::*;
-
-    Some(match group_name {
-        "errors" => errors(),
-        "style" => style(),
-        "regex" => regex(),
-        _ => return None,
-    })
+    registry::registry().group_rules(group_name)
 }
 
 /// Get a suggestion for an incorrect rule name for things such as "did you mean ...?"
-pub fn get_rule_suggestion(incorrect_rule_name: &str) -> Option<&str> {
-    let rules = CstRuleStore::new()
-        .builtins()
-        .rules
-        .into_iter()
-        .map(|rule| rule.name());
-    util::find_best_match_for_name(rules, incorrect_rule_name, None)
+pub fn get_rule_suggestion(incorrect_rule_name: &str) -> Option<&'static str> {
+    registry::registry().suggestion(incorrect_rule_name)
 }
 
 /// Get a rule and its documentation.
@@ -248,7 +247,7 @@ pub fn get_rule_suggestion(incorrect_rule_name: &str) -> Option<&str> {
 /// This will always be `Some` for valid rule names and it will be an empty string
 /// if the rule has no docs
 pub fn get_rule_docs(rule: &str) -> Option<&'static str> {
-    get_rule_by_name(rule).map(|rule| rule.docs())
+    registry::registry().rule(rule).map(|meta| meta.docs)
 }
 
 macro_rules! trait_obj_helper {
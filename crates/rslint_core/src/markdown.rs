@@ -0,0 +1,222 @@
+//! Extraction of the JavaScript and TypeScript code blocks of Markdown documents.
+//!
+//! Only fenced code blocks are extracted, their language is taken from the first word of
+//! the info string of the fence:
+//!
+//! | Info string               | Syntax                                |
+//! | ------------------------- | ------------------------------------- |
+//! | `js`, `javascript`, `mjs` | module                                |
+//! | `cjs`                     | script with a global return           |
+//! | `ts`, `typescript`        | typescript                            |
+//! | `mts`                     | typescript ES module                  |
+//! | `cts`                     | typescript without top level await    |
+//!
+//! A block directly preceded by `<!-- rslint-ignore -->` is skipped.
+
+use rslint_parser::Syntax;
+use std::ops::Range;
+use std::path::Path;
+
+/// The comment which makes the next code block of a Markdown document be skipped.
+pub const IGNORE_DIRECTIVE: &str = "<!-- rslint-ignore -->";
+
+/// A fenced JavaScript or TypeScript code block of a Markdown document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// The range of the content of the block in the document, the fences are excluded.
+    pub range: Range<usize>,
+    /// The syntax the content is parsed with.
+    pub syntax: Syntax,
+}
+
+/// A fence which was opened but not yet closed.
+struct OpenFence {
+    fence: char,
+    len: usize,
+    content_start: usize,
+    syntax: Option<Syntax>,
+}
+
+/// Find the fenced code blocks of a Markdown document which should be linted.
+///
+/// Fences inside of blockquotes or indented by four or more spaces are not recognized, and the content
+/// of a block is not dedented, so ranges in the content can be remapped to the document with a single offset.
+pub fn code_blocks(source: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut open: Option<OpenFence> = None;
+    let mut ignore_next = false;
+    let mut offset = 0;
+
+    for line in source.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+
+        match &open {
+            Some(fence) => {
+                if let Some((fence_char, len, rest)) = fence_marker(line) {
+                    if fence_char == fence.fence && len >= fence.len && rest.trim().is_empty() {
+                        if let Some(syntax) = fence.syntax {
+                            blocks.push(CodeBlock {
+                                range: fence.content_start..line_start,
+                                syntax,
+                            });
+                        }
+                        open = None;
+                    }
+                }
+            }
+            None => {
+                if let Some((fence, len, info)) = fence_marker(line) {
+                    // backtick fences may not have backticks in their info string
+                    if fence == '`' && info.contains('`') {
+                        ignore_next = false;
+                        continue;
+                    }
+                    open = Some(OpenFence {
+                        fence,
+                        len,
+                        content_start: offset,
+                        syntax: if ignore_next { None } else { info_syntax(info) },
+                    });
+                    ignore_next = false;
+                } else if !line.trim().is_empty() {
+                    ignore_next = line.trim() == IGNORE_DIRECTIVE;
+                }
+            }
+        }
+    }
+
+    // an unclosed fence runs until the end of the document
+    if let Some(OpenFence {
+        content_start,
+        syntax: Some(syntax),
+        ..
+    }) = open
+    {
+        blocks.push(CodeBlock {
+            range: content_start..source.len(),
+            syntax,
+        });
+    }
+    blocks
+}
+
+/// Get the fence character, the length of the fence, and the rest of the line if the line
+/// starts with a code fence.
+fn fence_marker(line: &str) -> Option<(char, usize, &str)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let fence = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = trimmed.len() - trimmed.trim_start_matches(fence).len();
+    if len < 3 {
+        return None;
+    }
+    Some((fence, len, &trimmed[len..]))
+}
+
+/// The syntax of a code block from the info string of its fence, or `None` if it is not JavaScript or TypeScript.
+///
+/// Languages which are file extensions get the syntax of a file with that extension, except `js`
+/// which is a module, because code blocks are usually written as modules.
+fn info_syntax(info: &str) -> Option<Syntax> {
+    let lang = info
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '{')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    let extension = match lang.as_str() {
+        "js" | "javascript" => "mjs",
+        "typescript" => "ts",
+        "mjs" | "cjs" | "ts" | "mts" | "cts" => &lang,
+        _ => return None,
+    };
+    Syntax::from_path(Path::new(&format!("block.{}", extension)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rslint_parser::FileKind;
+
+    /// The contents of the code blocks of a document.
+    fn contents(source: &str) -> Vec<&str> {
+        code_blocks(source)
+            .into_iter()
+            .map(|block| &source[block.range])
+            .collect()
+    }
+
+    #[test]
+    fn backtick_fences() {
+        let source = "# Title\n\n```js\nlet a = 5;\n```\n\n```json\n{}\n```\n";
+        let blocks = code_blocks(source);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(&source[blocks[0].range.clone()], "let a = 5;\n");
+        assert_eq!(blocks[0].syntax.file_kind, FileKind::Module);
+    }
+
+    #[test]
+    fn tilde_fences() {
+        let source =
+            "~~~ts\nlet a: number;\n```\nstill code\n~~~\n~~~~js title=`a.js`\nfoo();\n~~~~~\n";
+        assert_eq!(
+            contents(source),
+            vec!["let a: number;\n```\nstill code\n", "foo();\n"]
+        );
+    }
+
+    #[test]
+    fn closing_fences_are_at_least_as_long() {
+        let source = "````js\n```\nfoo();\n````\n";
+        assert_eq!(contents(source), vec!["```\nfoo();\n"]);
+    }
+
+    #[test]
+    fn unclosed_fences_run_to_the_end() {
+        let source = "```js\nfoo();\nbar();";
+        assert_eq!(contents(source), vec!["foo();\nbar();"]);
+    }
+
+    #[test]
+    fn indented_fences() {
+        let source = "   ```js\nfoo();\n   ```\n    ```js\nbar();\n    ```\n";
+        assert_eq!(contents(source), vec!["foo();\n"]);
+    }
+
+    #[test]
+    fn backtick_info_strings_without_backticks() {
+        let source = "```js`\nfoo();\n```\n";
+        assert!(contents(source).is_empty());
+    }
+
+    #[test]
+    fn ignore_directive() {
+        let source = "<!-- rslint-ignore -->\n\n```js\nignored();\n```\n\n```js\nlinted();\n```\n<!-- rslint-ignore -->\nSome text\n```js\nalso_linted();\n```\n";
+        assert_eq!(contents(source), vec!["linted();\n", "also_linted();\n"]);
+    }
+
+    #[test]
+    fn crlf_documents() {
+        let source = "```js\r\nfoo();\r\n```\r\n";
+        assert_eq!(contents(source), vec!["foo();\r\n"]);
+    }
+
+    #[test]
+    fn info_string_syntax() {
+        let syntax = |info: &str| info_syntax(info);
+        assert_eq!(syntax("js"), Some(Syntax::default().module()));
+        assert_eq!(syntax("JavaScript {1,3}"), Some(Syntax::default().module()));
+        assert_eq!(
+            syntax("cjs"),
+            Some(Syntax::default().script().global_return())
+        );
+        assert_eq!(syntax("typescript"), Some(Syntax::default().typescript()));
+        assert!(syntax("mts").unwrap().is_module());
+        assert!(!syntax("cts").unwrap().top_level_await);
+        assert_eq!(syntax("json"), None);
+        assert_eq!(syntax(""), None);
+    }
+}
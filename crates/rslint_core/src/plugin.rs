@@ -0,0 +1,307 @@
+//! Third party rules loaded from dynamic libraries at runtime.
+//!
+//! A plugin is a crate compiled as a `cdylib` which depends on `rslint_core` and declares its
+//! rules with [`declare_plugin`]:
+//!
+//! ```ignore
+//! use rslint_core::{declare_plugin, plugin::PluginRegistrar};
+//!
+//! fn register(registrar: &mut PluginRegistrar) {
+//!     registrar.register_rule::<NoMoment>();
+//! }
+//!
+//! declare_plugin!(register);
+//! ```
+//!
+//! The rules are declared with `declare_lint!` like built in rules, and are added to the
+//! [`registry`](crate::registry) when the plugin is loaded. Their group is the name
+//! used to enable all of the rules of the plugin. Rules use the Rust ABI, so a plugin must be built
+//! with the same compiler, version, features and dependencies of `rslint_core` as the linter
+//! loading it, this is checked when loading the plugin.
+
+use crate::registry::{self, RegistryError};
+use crate::CstRule;
+use dyn_clone::clone_box;
+use libloading::Library;
+use once_cell::sync::Lazy;
+use rslint_errors::Diagnostic;
+use rslint_parser::SyntaxNode;
+use serde::de::DeserializeOwned;
+use std::any::TypeId;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+/// The version of the plugin ABI, bumped whenever [`PluginDeclaration`] or [`PluginRegistrar`] change.
+pub const PLUGIN_ABI_VERSION: u32 = 2;
+/// The version of `rslint_core` plugins are built against.
+pub const CORE_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The version of the compiler `rslint_core` was built with.
+pub const RUSTC_VERSION: &str = env!("RSLINT_RUSTC_VERSION");
+
+/// The name of the static every plugin exports, see [`declare_plugin`].
+const DECLARATION_SYMBOL: &[u8] = b"RSLINT_PLUGIN_DECLARATION\0";
+
+/// Identify the build of `rslint_core`.
+///
+/// Two builds of the same version of `rslint_core` may still lay out rules differently, if they enable other
+/// features or resolve other versions of its dependencies. The id of a type depends on the metadata Cargo
+/// passes to the compiler, which hashes the features and the dependencies of the crate, so the id only
+/// matches if the plugin and the linter share the same build of `rslint_core`.
+pub fn build_id() -> TypeId {
+    TypeId::of::<(Box<dyn CstRule>, SyntaxNode, Diagnostic)>()
+}
+
+/// The declaration exported by a plugin, which is checked for compatibility before registering its rules.
+#[derive(Debug, Clone, Copy)]
+pub struct PluginDeclaration {
+    pub abi_version: u32,
+    pub core_version: &'static str,
+    pub rustc_version: &'static str,
+    /// The [`build_id`] of the `rslint_core` the plugin is built with, only called once the versions match.
+    pub build_id: fn() -> TypeId,
+    /// The function registering the rules of the plugin.
+    pub register: fn(&mut PluginRegistrar),
+}
+
+/// Declare the function registering the rules of a plugin.
+///
+/// ```ignore
+/// declare_plugin!(register);
+/// ```
+#[macro_export]
+macro_rules! declare_plugin {
+    ($register:path) => {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub static RSLINT_PLUGIN_DECLARATION: $crate::plugin::PluginDeclaration =
+            $crate::plugin::PluginDeclaration {
+                abi_version: $crate::plugin::PLUGIN_ABI_VERSION,
+                core_version: $crate::plugin::CORE_VERSION,
+                rustc_version: $crate::plugin::RUSTC_VERSION,
+                build_id: $crate::plugin::build_id,
+                register: $register,
+            };
+    };
+}
+
+/// A rule registered by a plugin.
+#[derive(Debug, Clone)]
+pub struct PluginRule {
+    /// The rule with its default options.
+    pub rule: Box<dyn CstRule>,
+    /// The path of the plugin the rule comes from.
+    pub plugin: PathBuf,
+    // the typetag registry of the plugin is not the registry of the linter, so options
+    // have to be deserialized by the plugin itself
+    deserialize: fn(serde_json::Value) -> Result<Box<dyn CstRule>, String>,
+}
+
+impl PluginRule {
+    /// Make a rule with the options of a rule config.
+    pub fn deserialize(&self, options: serde_json::Value) -> Result<Box<dyn CstRule>, String> {
+        (self.deserialize)(options)
+    }
+}
+
+/// The registrar passed to the registering function of a plugin.
+#[derive(Debug)]
+pub struct PluginRegistrar {
+    plugin: PathBuf,
+    rules: Vec<PluginRule>,
+}
+
+impl PluginRegistrar {
+    /// Register a rule, the rule is created with its `Default` implementation unless it is configured.
+    pub fn register_rule<R>(&mut self)
+    where
+        R: CstRule + Default + DeserializeOwned + 'static,
+    {
+        self.rules.push(PluginRule {
+            rule: Box::new(R::default()),
+            plugin: self.plugin.clone(),
+            deserialize: |options| {
+                serde_json::from_value::<R>(options)
+                    .map(|rule| Box::new(rule) as Box<dyn CstRule>)
+                    .map_err(|err| err.to_string())
+            },
+        });
+    }
+}
+
+/// An error produced when loading a plugin.
+#[derive(Debug)]
+pub enum PluginError {
+    /// The library could not be loaded.
+    Load(libloading::Error),
+    /// The library does not declare a plugin.
+    MissingDeclaration,
+    /// The plugin was built for another version of the ABI, of `rslint_core`, or of the compiler.
+    Incompatible { expected: String, found: String },
+    /// The plugin is built with other features or dependencies of `rslint_core`.
+    DifferentBuild,
+    /// The library changed since the plugin was loaded, a loaded plugin cannot be replaced.
+    Changed,
+    /// The plugin registers a rule whose name is already taken.
+    DuplicateRule(String),
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PluginError::Load(err) => write!(f, "{}", err),
+            PluginError::MissingDeclaration => {
+                write!(
+                    f,
+                    "the library does not declare a plugin with `declare_plugin!`"
+                )
+            }
+            PluginError::Incompatible { expected, found } => write!(
+                f,
+                "the plugin is built for {} but the linter is built for {}, rebuild the plugin",
+                found, expected
+            ),
+            PluginError::DifferentBuild => write!(
+                f,
+                "the plugin is built with other features or dependencies of rslint_core than the linter, \
+                 rebuild the plugin with the same features and versions"
+            ),
+            PluginError::Changed => write!(
+                f,
+                "the library changed since the plugin was loaded, restart the linter to load the new plugin"
+            ),
+            PluginError::DuplicateRule(name) => {
+                write!(
+                    f,
+                    "the plugin declares a rule `{}` which already exists",
+                    name
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+/// A plugin which was loaded, the library is never unloaded because its rules live in it.
+struct LoadedPlugin {
+    path: PathBuf,
+    /// The modification time of the library when it was loaded.
+    modified: Option<SystemTime>,
+    _library: Library,
+    rules: Vec<PluginRule>,
+}
+
+static PLUGINS: Lazy<Mutex<Vec<LoadedPlugin>>> = Lazy::new(Default::default);
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// Load a plugin from a dynamic library and register its rules, returns the names of the rules.
+/// Loading a plugin which is already loaded does nothing, unless the library was rebuilt since,
+/// which is an error because the rules of the loaded plugin cannot be unloaded.
+///
+/// # Safety
+///
+/// The library is loaded into the process and runs arbitrary code, so the caller must trust it.
+/// Its declaration is only checked to guard against building it with another compiler or build of
+/// `rslint_core`, the checks cannot catch a library which lies about its declaration.
+pub unsafe fn load_plugin(path: &Path) -> Result<Vec<&'static str>, PluginError> {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_owned());
+    let mut plugins = PLUGINS.lock().unwrap();
+    if let Some(plugin) = plugins.iter().find(|plugin| plugin.path == path) {
+        if plugin.modified != modified(&path) {
+            return Err(PluginError::Changed);
+        }
+        return Ok(plugin.rules.iter().map(|rule| rule.rule.name()).collect());
+    }
+
+    let loaded_modified = modified(&path);
+    let library = Library::new(&path).map_err(PluginError::Load)?;
+    let declaration = **library
+        .get::<*const PluginDeclaration>(DECLARATION_SYMBOL)
+        .map_err(|_| PluginError::MissingDeclaration)?;
+
+    let found = (
+        declaration.abi_version,
+        declaration.core_version,
+        declaration.rustc_version,
+    );
+    if found != (PLUGIN_ABI_VERSION, CORE_VERSION, RUSTC_VERSION) {
+        let describe = |(abi, core, rustc): (u32, &str, &str)| {
+            format!("plugin ABI {} of rslint_core {} with {}", abi, core, rustc)
+        };
+        return Err(PluginError::Incompatible {
+            expected: describe((PLUGIN_ABI_VERSION, CORE_VERSION, RUSTC_VERSION)),
+            found: describe(found),
+        });
+    }
+    if (declaration.build_id)() != build_id() {
+        return Err(PluginError::DifferentBuild);
+    }
+
+    let mut registrar = PluginRegistrar {
+        plugin: path.clone(),
+        rules: vec![],
+    };
+    (declaration.register)(&mut registrar);
+
+    let rules = registrar
+        .rules
+        .iter()
+        .map(|plugin_rule| clone_box(&*plugin_rule.rule))
+        .collect();
+    registry::register_rules(rules).map_err(|err| match err {
+        RegistryError::DuplicateRule(name) => PluginError::DuplicateRule(name.to_string()),
+        err => unreachable!("{}", err),
+    })?;
+
+    let names = registrar
+        .rules
+        .iter()
+        .map(|rule| rule.rule.name())
+        .collect();
+    plugins.push(LoadedPlugin {
+        modified: loaded_modified,
+        path,
+        _library: library,
+        rules: registrar.rules,
+    });
+    Ok(names)
+}
+
+/// Get a rule of a loaded plugin by its name.
+pub fn get_plugin_rule(name: &str) -> Option<PluginRule> {
+    PLUGINS
+        .lock()
+        .unwrap()
+        .iter()
+        .flat_map(|plugin| &plugin.rules)
+        .find(|plugin_rule| plugin_rule.rule.name() == name)
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_library() {
+        let path = Path::new("missing/librslint_missing.so");
+        let err = unsafe { load_plugin(path) }.unwrap_err();
+        assert!(matches!(err, PluginError::Load(_)), "{}", err);
+        assert!(get_plugin_rule("missing").is_none());
+    }
+
+    #[test]
+    fn invalid_library() {
+        let path = std::env::temp_dir().join(format!("rslint-plugin-{}.so", std::process::id()));
+        fs::write(&path, "not a library").unwrap();
+        let err = unsafe { load_plugin(&path) }.unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(err, PluginError::Load(_)), "{}", err);
+    }
+}
@@ -0,0 +1,377 @@
+//! The registry of every rule and group known to the linter.
+//!
+//! The registry is built lazily from the built in groups the first time it is used. Other crates
+//! and plugins can add rules to it with [`register_rule`] and [`register_group`], after which the rules
+//! can be configured, used in directives, and listed like built in rules.
+
+use crate::{groups, util, Category, CstRule, Deprecation, Tag};
+use dyn_clone::clone_box;
+use once_cell::sync::Lazy;
+use std::fmt;
+use std::sync::{RwLock, RwLockReadGuard};
+
+static REGISTRY: Lazy<RwLock<RuleRegistry>> = Lazy::new(|| RwLock::new(RuleRegistry::builtins()));
+
+/// The metadata of a registered rule.
+#[derive(Debug, Clone)]
+pub struct RuleMeta {
+    pub name: &'static str,
+    pub group: &'static str,
+    pub tags: &'static [Tag],
+    pub docs: &'static str,
+    /// Whether the rule can automatically fix some of the issues it reports.
+    pub fixable: bool,
+    /// Whether the rule suggests changes which have to be applied manually.
+    pub suggestions: bool,
+    pub category: Option<Category>,
+    /// The version of rslint the rule was added in.
+    pub since: Option<&'static str>,
+    pub deprecated: Option<Deprecation>,
+    /// The rule with its default options.
+    pub rule: Box<dyn CstRule>,
+}
+
+impl RuleMeta {
+    fn new(rule: Box<dyn CstRule>) -> Self {
+        Self {
+            name: rule.name(),
+            group: rule.group(),
+            tags: rule.tags(),
+            docs: rule.docs(),
+            fixable: rule.fixable(),
+            suggestions: rule.has_suggestions(),
+            category: rule.category(),
+            since: rule.since(),
+            deprecated: rule.deprecated(),
+            rule,
+        }
+    }
+
+    /// Whether the rule runs by default if no configuration is provided.
+    pub fn recommended(&self) -> bool {
+        self.tags.contains(&Tag::Recommended)
+    }
+
+    /// How the issues reported by the rule can be fixed.
+    pub fn fix_kind(&self) -> &'static str {
+        match (self.fixable, self.suggestions) {
+            (true, true) => "autofix, suggestions",
+            (true, false) => "autofix",
+            (false, true) => "suggestions",
+            (false, false) => "-",
+        }
+    }
+
+    /// A message telling users to stop using the rule if it is deprecated.
+    pub fn deprecation_message(&self) -> Option<String> {
+        let deprecation = self.deprecated?;
+        Some(match deprecation.replacement {
+            Some(replacement) => format!(
+                "the rule '{}' is deprecated, use '{}' instead",
+                self.name, replacement
+            ),
+            None => format!("the rule '{}' is deprecated", self.name),
+        })
+    }
+
+    /// The JSON schema of the options of the rule.
+    #[cfg(feature = "schema")]
+    pub fn schema(&self) -> Option<schemars::schema::RootSchema> {
+        self.rule.schema()
+    }
+}
+
+/// An error produced when registering rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    /// A rule with the same name is already registered.
+    DuplicateRule(&'static str),
+    /// A rule registered as part of a group declares another group.
+    WrongGroup {
+        rule: &'static str,
+        expected: &'static str,
+        found: &'static str,
+    },
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::DuplicateRule(name) => {
+                write!(f, "a rule named `{}` is already registered", name)
+            }
+            RegistryError::WrongGroup {
+                rule,
+                expected,
+                found,
+            } => write!(
+                f,
+                "the rule `{}` belongs to the group `{}`, not `{}`",
+                rule, found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// Rules and groups by their names, in the order they were registered.
+#[derive(Debug, Clone, Default)]
+pub struct RuleRegistry {
+    rules: Vec<RuleMeta>,
+    groups: Vec<&'static str>,
+}
+
+impl RuleRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry of the built in groups.
+    pub fn builtins() -> Self {
+        let mut registry = Self::new();
+        for (name, rules) in [
+            ("errors", groups::errors()),
+            ("style", groups::style()),
+            ("regex", groups::regex()),
+            ("typescript", groups::typescript()),
+        ] {
+            registry
+                .register_group(name, rules)
+                .expect("built in rules must have unique names");
+        }
+        registry
+    }
+
+    /// Register a rule, its group is registered too if it is new.
+    pub fn register_rule(&mut self, rule: Box<dyn CstRule>) -> Result<(), RegistryError> {
+        self.register_rules(vec![rule])
+    }
+
+    /// Register a group and its rules, every rule must belong to the group.
+    pub fn register_group(
+        &mut self,
+        group: &'static str,
+        rules: Vec<Box<dyn CstRule>>,
+    ) -> Result<(), RegistryError> {
+        if let Some(rule) = rules.iter().find(|rule| rule.group() != group) {
+            return Err(RegistryError::WrongGroup {
+                rule: rule.name(),
+                expected: group,
+                found: rule.group(),
+            });
+        }
+        self.register_rules(rules)?;
+        if !self.groups.contains(&group) {
+            self.groups.push(group);
+        }
+        Ok(())
+    }
+
+    /// Register several rules, none of them are registered if one of their names is taken.
+    pub fn register_rules(&mut self, rules: Vec<Box<dyn CstRule>>) -> Result<(), RegistryError> {
+        for (idx, rule) in rules.iter().enumerate() {
+            let name = rule.name();
+            if self.rule(name).is_some() || rules[..idx].iter().any(|prev| prev.name() == name) {
+                return Err(RegistryError::DuplicateRule(name));
+            }
+        }
+
+        for rule in rules {
+            if !self.groups.contains(&rule.group()) {
+                self.groups.push(rule.group());
+            }
+            self.rules.push(RuleMeta::new(rule));
+        }
+        Ok(())
+    }
+
+    /// Get the metadata of a rule by its name.
+    pub fn rule(&self, name: &str) -> Option<&RuleMeta> {
+        self.rules.iter().find(|meta| meta.name == name)
+    }
+
+    /// All of the registered rules.
+    pub fn rules(&self) -> &[RuleMeta] {
+        &self.rules
+    }
+
+    /// The names of all of the registered groups.
+    pub fn groups(&self) -> &[&'static str] {
+        &self.groups
+    }
+
+    /// The rules of a group, `None` if the group does not exist.
+    pub fn group(&self, name: &str) -> Option<impl Iterator<Item = &RuleMeta> + Clone> {
+        let group = *self.groups.iter().find(|group| **group == name)?;
+        Some(self.rules.iter().filter(move |meta| meta.group == group))
+    }
+
+    /// Make a new instance of every rule of a group with their default options.
+    pub fn group_rules(&self, name: &str) -> Option<Vec<Box<dyn CstRule>>> {
+        self.group(name)
+            .map(|rules| rules.map(|meta| clone_box(&*meta.rule)).collect())
+    }
+
+    /// The name of the registered rule closest to a misspelled name.
+    pub fn suggestion(&self, incorrect_rule_name: &str) -> Option<&'static str> {
+        let names = self.rules.iter().map(|meta| meta.name);
+        util::find_best_match_for_name(names, incorrect_rule_name, None)
+    }
+}
+
+/// The global registry.
+pub fn registry() -> RwLockReadGuard<'static, RuleRegistry> {
+    REGISTRY.read().unwrap()
+}
+
+/// Register a rule in the global registry.
+pub fn register_rule(rule: Box<dyn CstRule>) -> Result<(), RegistryError> {
+    REGISTRY.write().unwrap().register_rule(rule)
+}
+
+/// Register several rules in the global registry, none of them are registered if one of their names is taken.
+pub fn register_rules(rules: Vec<Box<dyn CstRule>>) -> Result<(), RegistryError> {
+    REGISTRY.write().unwrap().register_rules(rules)
+}
+
+/// Register a group and its rules in the global registry.
+pub fn register_group(
+    group: &'static str,
+    rules: Vec<Box<dyn CstRule>>,
+) -> Result<(), RegistryError> {
+    REGISTRY.write().unwrap().register_group(group, rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::{errors::NoEmpty, style::BlockSpacing};
+    use crate::rule_prelude::*;
+
+    declare_lint! {
+        /// A rule replaced by `no-empty`.
+        #[derive(Default)]
+        OldEmpty,
+        errors,
+        category(Suspicious),
+        since("0.1.0"),
+        deprecated("no-empty"),
+        "old-empty"
+    }
+
+    #[typetag::serde]
+    impl CstRule for OldEmpty {}
+
+    mod obsolete {
+        use crate::rule_prelude::*;
+
+        declare_lint! {
+            /// A rule without a replacement.
+            #[derive(Default)]
+            Obsolete,
+            errors,
+            deprecated(),
+            "obsolete"
+        }
+
+        #[typetag::serde]
+        impl CstRule for Obsolete {}
+    }
+    use obsolete::Obsolete;
+
+    #[test]
+    fn builtins() {
+        let registry = RuleRegistry::builtins();
+        assert_eq!(
+            registry.groups(),
+            ["errors", "style", "regex", "typescript"]
+        );
+
+        let meta = registry.rule("block-spacing").unwrap();
+        assert_eq!(meta.group, "style");
+        assert!(meta.fixable);
+        assert!(!meta.recommended());
+        assert!(registry.rule("no-empty").unwrap().recommended());
+
+        assert!(registry.group("nope").is_none());
+        assert!(registry
+            .group("regex")
+            .unwrap()
+            .all(|meta| meta.group == "regex"));
+        assert_eq!(registry.suggestion("no-emty"), Some("no-empty"));
+    }
+
+    #[test]
+    fn metadata() {
+        let registry = RuleRegistry::builtins();
+        let meta = registry.rule("no-new-symbol").unwrap();
+        assert!(meta.fixable && meta.suggestions);
+        assert_eq!(meta.category, Some(Category::Correctness));
+        assert_eq!(meta.deprecation_message(), None);
+        assert_eq!(
+            registry.rule("no-this-before-super").unwrap().since,
+            Some("0.3.0")
+        );
+        assert!(registry.rules().iter().all(|meta| meta.since.is_some()));
+
+        let mut registry = RuleRegistry::new();
+        registry
+            .register_rules(vec![Box::new(OldEmpty::new()), Box::new(Obsolete::new())])
+            .unwrap();
+        let old = registry.rule("old-empty").unwrap();
+        assert_eq!(old.category, Some(Category::Suspicious));
+        assert_eq!(old.since, Some("0.1.0"));
+        assert_eq!(
+            old.deprecated,
+            Some(Deprecation {
+                replacement: Some("no-empty")
+            })
+        );
+        assert_eq!(
+            old.deprecation_message().unwrap(),
+            "the rule 'old-empty' is deprecated, use 'no-empty' instead"
+        );
+
+        let obsolete = registry.rule("obsolete").unwrap();
+        assert_eq!(obsolete.category, None);
+        assert_eq!(obsolete.since, None);
+        assert_eq!(obsolete.deprecated, Some(Deprecation { replacement: None }));
+        assert_eq!(
+            obsolete.deprecation_message().unwrap(),
+            "the rule 'obsolete' is deprecated"
+        );
+    }
+
+    #[test]
+    fn duplicates_are_rejected() {
+        let mut registry = RuleRegistry::builtins();
+        let len = registry.rules().len();
+        assert_eq!(
+            registry.register_rule(Box::new(NoEmpty::default())),
+            Err(RegistryError::DuplicateRule("no-empty"))
+        );
+        assert_eq!(
+            registry.register_group("style", vec![Box::new(NoEmpty::default())]),
+            Err(RegistryError::WrongGroup {
+                rule: "no-empty",
+                expected: "style",
+                found: "errors",
+            })
+        );
+
+        let mut empty = RuleRegistry::new();
+        assert_eq!(
+            empty.register_rules(vec![
+                Box::new(BlockSpacing::default()),
+                Box::new(BlockSpacing::default()),
+            ]),
+            Err(RegistryError::DuplicateRule("block-spacing"))
+        );
+        assert!(empty.rules().is_empty());
+        assert!(empty.register_rule(Box::new(NoEmpty::default())).is_ok());
+        assert_eq!(empty.groups(), ["errors"]);
+        assert_eq!(registry.rules().len(), len);
+    }
+}
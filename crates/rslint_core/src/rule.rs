@@ -7,7 +7,7 @@ use crate::autofix::Fixer;
 use crate::Diagnostic;
 use dyn_clone::DynClone;
 use rslint_errors::Severity;
-use rslint_parser::{SyntaxNode, SyntaxNodeExt, SyntaxToken};
+use rslint_parser::{EcmaVersion, FileKind, SyntaxNode, SyntaxNodeExt, SyntaxToken};
 use rslint_text_edit::apply_indels;
 use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
@@ -26,6 +26,51 @@ pub enum Tag {
     OnlyJS,
     /// This rule should only run on TypeScript files.
     OnlyTS,
+    /// This rule can automatically fix some of the issues it reports.
+    Fixable,
+    /// This rule suggests changes for some of the issues it reports, which have to be applied manually.
+    Suggestions,
+}
+
+/// The kind of issues a rule reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Category {
+    /// Code which is wrong or does nothing useful.
+    Correctness,
+    /// Code which is most likely wrong or confusing.
+    Suspicious,
+    /// Code which can be written in a simpler way.
+    Complexity,
+    /// Code which does not follow a consistent style.
+    Style,
+    /// Syntax or APIs which a project decided not to use.
+    Restriction,
+}
+
+impl Category {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Correctness => "correctness",
+            Category::Suspicious => "suspicious",
+            Category::Complexity => "complexity",
+            Category::Style => "style",
+            Category::Restriction => "restriction",
+        }
+    }
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Marks a rule as deprecated. Deprecated rules still work, but they should not be used anymore.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Deprecation {
+    /// The rule which should be used instead, if there is one.
+    pub replacement: Option<&'static str>,
 }
 
 /// The main type of rule run by the runner. The rule takes individual
@@ -105,6 +150,36 @@ pub trait Rule: Debug + DynClone + Send + Sync {
     fn recommended(&self) -> bool {
         self.tags().iter().any(|x| x == &Tag::Recommended)
     }
+    /// Whether this rule can automatically fix some of the issues it reports, this is a simple helper around [`Self::tags`].
+    fn fixable(&self) -> bool {
+        self.tags().iter().any(|x| x == &Tag::Fixable)
+    }
+    /// Whether this rule suggests changes for some of the issues it reports, this is a simple helper around [`Self::tags`].
+    fn has_suggestions(&self) -> bool {
+        self.tags().iter().any(|x| x == &Tag::Suggestions)
+    }
+    /// Whether this rule runs on files of a kind, rules tagged with [`Tag::OnlyJS`] do not run on TypeScript files
+    /// and rules tagged with [`Tag::OnlyTS`] only run on TypeScript files.
+    fn runs_on(&self, kind: FileKind) -> bool {
+        let only = if kind == FileKind::TypeScript {
+            Tag::OnlyJS
+        } else {
+            Tag::OnlyTS
+        };
+        !self.tags().contains(&only)
+    }
+    /// The kind of issues this rule reports, if it is known.
+    fn category(&self) -> Option<Category> {
+        None
+    }
+    /// The version of rslint this rule was added in, if it is known.
+    fn since(&self) -> Option<&'static str> {
+        None
+    }
+    /// Whether this rule is deprecated, and which rule replaces it.
+    fn deprecated(&self) -> Option<Deprecation> {
+        None
+    }
 
     #[cfg(feature = "schema")]
     fn schema(&self) -> Option<schemars::schema::RootSchema> {
@@ -119,17 +194,65 @@ dyn_clone::clone_trait_object!(CstRule);
 /// parsed syntax trees
 #[typetag::serde]
 pub trait Inferable: CstRule {
-    /// Infer the options for the rule from multiple nodes (which may be from different trees) and change them
-    fn infer(&mut self, nodes: &[SyntaxNode]);
+    /// Infer the options for the rule from multiple nodes (which may be from different trees) and change them.
+    /// Returns the options which were inferred, options which could not be inferred are left untouched.
+    fn infer(&mut self, nodes: &[SyntaxNode]) -> Vec<InferredOption>;
+}
+
+/// An option of a rule which was inferred by [`Inferable::infer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InferredOption {
+    /// The name of the option in configs.
+    pub name: &'static str,
+    /// The share of the inspected code which agrees with the inferred value, from `0.0` to `1.0`.
+    pub confidence: f32,
 }
 
-/// The level configured for a rule.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// The level configured for a rule, which decides the severity of its diagnostics.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum RuleLevel {
+    /// The rule does not run.
+    Off,
+    /// Diagnostics are hints, which editors show as faded code.
+    Hint,
+    /// Diagnostics are informational notes.
+    Info,
+    #[serde(rename = "warn", alias = "warning")]
     Warning,
     Error,
 }
 
+impl RuleLevel {
+    /// The severity of the diagnostics of a rule with this level, `None` if the rule is off.
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            RuleLevel::Off => None,
+            RuleLevel::Hint => Some(Severity::Help),
+            RuleLevel::Info => Some(Severity::Note),
+            RuleLevel::Warning => Some(Severity::Warning),
+            RuleLevel::Error => Some(Severity::Error),
+        }
+    }
+
+    /// Lower the severity of diagnostics which are more severe than the level.
+    ///
+    /// Less severe diagnostics are left untouched because rules should be able to emit warnings
+    /// and notes for context without them being remapped to errors.
+    pub fn remap(self, diagnostics: &mut [Diagnostic]) {
+        let severity = match self.severity() {
+            Some(severity) => severity,
+            None => return,
+        };
+        for diagnostic in diagnostics {
+            if diagnostic.severity != Severity::Bug && diagnostic.severity > severity {
+                diagnostic.severity = severity;
+            }
+        }
+    }
+}
+
 /// Context given to a rule when running it.
 // This is passed by reference and not by Arc, which is very important,
 // Arcs are very expensive to copy, and for 50 rules running on 50 files we will have a total of
@@ -145,6 +268,8 @@ pub struct RuleCtx {
     pub diagnostics: Vec<Diagnostic>,
     pub fixer: Option<Fixer>,
     pub src: Arc<str>,
+    /// The ECMAScript version targeted by the file being linted.
+    pub ecma_version: EcmaVersion,
 }
 
 impl RuleCtx {
@@ -173,6 +298,7 @@ impl RuleCtx {
             diagnostics: vec![],
             fixer: None,
             src: Arc::from(String::new()),
+            ecma_version: EcmaVersion::LATEST,
         }
     }
 }
@@ -333,6 +459,17 @@ macro_rules! __declare_lint_inner {
         $(
             tags($($tag:ident),* $(,)?),
         )?
+        $(
+            category($category:ident),
+        )?
+        // The version of rslint the rule was added in
+        $(
+            since($since:literal),
+        )?
+        // The rule which replaces the rule, if it is deprecated
+        $(
+            deprecated($($replacement:literal)?),
+        )?
         // A unique kebab-case name for the rule
         $code:literal
         $(,
@@ -350,7 +487,7 @@ macro_rules! __declare_lint_inner {
         #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
         #[derive(Debug, Clone, Deserialize, Serialize)]
         $(#[$outer])*
-        #[serde(rename_all = "camelCase")]
+        #[serde(rename_all = "camelCase", deny_unknown_fields)]
         pub struct $name {
             $(
                 $(
@@ -385,6 +522,25 @@ macro_rules! __declare_lint_inner {
                 }
             )?
 
+            $(
+                fn category(&self) -> Option<$crate::Category> {
+                    Some($crate::Category::$category)
+                }
+            )?
+
+            $(
+                fn since(&self) -> Option<&'static str> {
+                    Some($since)
+                }
+            )?
+
+            $(
+                fn deprecated(&self) -> Option<$crate::Deprecation> {
+                    let replacement = None $(.or(Some($replacement)))?;
+                    Some($crate::Deprecation { replacement })
+                }
+            )?
+
             #[cfg(feature = "schema")]
             fn schema(&self) -> Option<schemars::schema::RootSchema> {
                 Some(schemars::schema_for!($name))
@@ -422,13 +578,29 @@ macro_rules! __declare_lint_inner {
 /// include a `Correct Code Examples`. Do not include a `Config` header, it is autogenerated
 /// from config field docs.
 ///
+/// # Metadata
+///
+/// Between the group name and the rule code, the macro accepts these optional items, in this order:
+///
+/// ```ignore
+/// // Tags of the rule, see [`Tag`].
+/// tags(Recommended, Fixable),
+/// // The kind of issues the rule reports, see [`Category`].
+/// category(Correctness),
+/// // The version of rslint the rule was added in.
+/// since("0.3.0"),
+/// // Marks the rule as deprecated, optionally with the name of the rule which replaces it.
+/// deprecated("other-rule"),
+/// ```
+///
 /// # Config
 ///
 /// After the rule code, the macro accepts fields for the struct. Any field which is
 /// public will be used for config, you can however disable this by using `#[serde(skip)]`.
 /// Every public (config) field should have a doc comment, the doc comments will be used for
 /// user facing documentation. Therefore try to be non technical and non rust specific with the doc comments.
-/// **All config fields will be renamed to camelCase**
+/// **All config fields will be renamed to camelCase**, and configs with fields which are not
+/// config fields fail to deserialize.
 ///
 ///
 /// This will generate a rule struct with `RuleName`,
@@ -442,3 +614,40 @@ macro_rules! declare_lint {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remapped(level: RuleLevel) -> Vec<Severity> {
+        let mut diagnostics = [
+            Severity::Bug,
+            Severity::Error,
+            Severity::Warning,
+            Severity::Note,
+            Severity::Help,
+        ]
+        .iter()
+        .map(|severity| Diagnostic::new(0, *severity, ""))
+        .collect::<Vec<_>>();
+        level.remap(&mut diagnostics);
+        diagnostics.into_iter().map(|d| d.severity).collect()
+    }
+
+    #[test]
+    fn remap_lowers_more_severe_diagnostics() {
+        use Severity::*;
+
+        assert_eq!(
+            remapped(RuleLevel::Error),
+            [Bug, Error, Warning, Note, Help]
+        );
+        assert_eq!(
+            remapped(RuleLevel::Warning),
+            [Bug, Warning, Warning, Note, Help]
+        );
+        assert_eq!(remapped(RuleLevel::Info), [Bug, Note, Note, Note, Help]);
+        assert_eq!(remapped(RuleLevel::Hint), [Bug, Help, Help, Help, Help]);
+        assert_eq!(remapped(RuleLevel::Off), [Bug, Error, Warning, Note, Help]);
+    }
+}
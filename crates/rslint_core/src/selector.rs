@@ -0,0 +1,594 @@
+//! A query language for matching syntax nodes, used by rules such as `no-restricted-syntax`.
+//!
+//! A selector is made of compound selectors which match a single node, joined by combinators:
+//!
+//! | Syntax             | Matches                                                                  |
+//! | ------------------ | ------------------------------------------------------------------------ |
+//! | `CALL_EXPR`        | a node of the `SyntaxKind`                                               |
+//! | `*`                | a node of any kind                                                       |
+//! | `NAME_REF("foo")`  | a node of the kind whose text is `foo`, string literals match their value |
+//! | `X[field]`         | an `X` node which has the field                                          |
+//! | `X[field=value]`   | an `X` node whose field matches a string or a compound selector          |
+//! | `X[field!=value]`  | an `X` node whose field is missing or does not match the value           |
+//! | `A B`              | a `B` node which is a descendant of an `A` node                          |
+//! | `A > B`            | a `B` node which is a direct child of an `A` node                        |
+//! | `A, B`             | a node matching either `A` or `B`                                        |
+//!
+//! Fields are the AST accessors of a node, for instance `callee` and `arguments` of a `CALL_EXPR`,
+//! so `CALL_EXPR[callee=DOT_EXPR[object=NAME_REF("moment")]]` matches `moment.utc()`.
+
+use crate::rule_prelude::*;
+use rslint_parser::NodeOrToken;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use SyntaxKind::*;
+
+/// A function getting a field of a node, the node is guaranteed to be of the kind of the field.
+type Accessor = fn(&SyntaxNode) -> Option<SyntaxElement>;
+
+macro_rules! fields {
+    ($($kind:ident: $ast:ident { $($field:ident),* $(; $($token:ident = $method:ident),*)? }),* $(,)?) => {
+        /// The fields of every node kind which can be used in attribute selectors.
+        static FIELDS: &[(SyntaxKind, &str, Accessor)] = &[
+            $(
+                $((
+                    $kind,
+                    stringify!($field),
+                    |node| node.to::<ast::$ast>().$field().map(|it| it.syntax().clone().into()),
+                ),)*
+                $($((
+                    $kind,
+                    stringify!($token),
+                    |node| node.to::<ast::$ast>().$method().map(Into::into),
+                ),)*)?
+            )*
+        ];
+    };
+}
+
+fields! {
+    CALL_EXPR: CallExpr { callee, arguments },
+    NEW_EXPR: NewExpr { object, arguments },
+    DOT_EXPR: DotExpr { object, prop },
+    BRACKET_EXPR: BracketExpr { object, prop },
+    BIN_EXPR: BinExpr { lhs, rhs; op = op_token },
+    ASSIGN_EXPR: AssignExpr { lhs, rhs; op = op_token },
+    UNARY_EXPR: UnaryExpr { expr; op = op_token },
+    COND_EXPR: CondExpr { test, cons, alt },
+    AWAIT_EXPR: AwaitExpr { expr },
+    SPREAD_ELEMENT: SpreadElement { element },
+    IMPORT_CALL: ImportCall { argument },
+    TEMPLATE: Template { tag },
+    ARROW_EXPR: ArrowExpr { params, body },
+    FN_EXPR: FnExpr { name, parameters, body },
+    FN_DECL: FnDecl { name, parameters, body },
+    CLASS_DECL: ClassDecl { name, parent, body },
+    DECLARATOR: Declarator { pattern, value },
+    LITERAL_PROP: LiteralProp { key, value },
+    EXPR_STMT: ExprStmt { expr },
+    IF_STMT: IfStmt { condition, cons, alt },
+    WHILE_STMT: WhileStmt { condition, cons },
+    RETURN_STMT: ReturnStmt { value },
+    THROW_STMT: ThrowStmt { exception },
+    IMPORT_DECL: ImportDecl { source },
+    EXPORT_DECL: ExportDecl { source, decl },
+    EXPORT_DEFAULT_DECL: ExportDefaultDecl { decl },
+    EXPORT_DEFAULT_EXPR: ExportDefaultExpr { expr },
+}
+
+/// Get a field of a node by its name.
+fn field(node: &SyntaxNode, name: &str) -> Option<SyntaxElement> {
+    let (_, _, accessor) = FIELDS
+        .iter()
+        .find(|(kind, field, _)| *kind == node.kind() && *field == name)?;
+    accessor(node)
+}
+
+/// Get a syntax kind by its name, such as `CALL_EXPR`.
+fn kind_by_name(name: &str) -> Option<SyntaxKind> {
+    (0..SyntaxKind::__LAST as u16)
+        .map(SyntaxKind::from)
+        .find(|kind| format!("{:?}", kind) == name)
+}
+
+/// The text of an element without surrounding trivia.
+fn element_text(element: &SyntaxElement) -> String {
+    match element {
+        NodeOrToken::Node(node) => node.trimmed_text().to_string(),
+        NodeOrToken::Token(token) => token.text().to_string(),
+    }
+}
+
+/// An error produced when a selector is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError {
+    pub message: String,
+    /// The byte offset in the selector the error occurred at.
+    pub offset: usize,
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+/// A compiled selector which matches syntax nodes.
+#[derive(Debug, Clone)]
+pub struct Selector {
+    source: String,
+    alternatives: Vec<Complex>,
+}
+
+impl Selector {
+    /// Compile a selector from its source.
+    pub fn new(source: &str) -> Result<Self, SelectorError> {
+        let mut parser = SelectorParser { source, pos: 0 };
+        let alternatives = parser.parse_selector()?;
+        Ok(Self {
+            source: source.to_string(),
+            alternatives,
+        })
+    }
+
+    /// The source the selector was compiled from.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether the node matches the selector.
+    pub fn matches(&self, node: &SyntaxNode) -> bool {
+        self.alternatives
+            .iter()
+            .any(|complex| complex.matches(complex.compounds.len() - 1, node))
+    }
+}
+
+impl FromStr for Selector {
+    type Err = SelectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl Serialize for Selector {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for Selector {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        Selector::new(&source)
+            .map_err(|err| D::Error::custom(format!("invalid selector `{}`: {}", source, err)))
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Selector {
+    fn schema_name() -> String {
+        "Selector".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+/// Compound selectors joined by combinators, `combinators[i]` is between `compounds[i]` and `compounds[i + 1]`.
+#[derive(Debug, Clone)]
+struct Complex {
+    compounds: Vec<Compound>,
+    combinators: Vec<Combinator>,
+}
+
+impl Complex {
+    /// Whether the node matches the compounds up to `idx`, the node is matched against the last
+    /// compound and its ancestors against the rest.
+    fn matches(&self, idx: usize, node: &SyntaxNode) -> bool {
+        if !self.compounds[idx].matches(&node.clone().into()) {
+            return false;
+        }
+        if idx == 0 {
+            return true;
+        }
+        match self.combinators[idx - 1] {
+            Combinator::Child => {
+                matches!(node.parent(), Some(parent) if self.matches(idx - 1, &parent))
+            }
+            Combinator::Descendant => node
+                .ancestors()
+                .skip(1)
+                .any(|ancestor| self.matches(idx - 1, &ancestor)),
+        }
+    }
+}
+
+/// A selector matching a single element.
+#[derive(Debug, Clone)]
+struct Compound {
+    /// The kind of the element, `None` for `*`.
+    kind: Option<SyntaxKind>,
+    text: Option<String>,
+    attributes: Vec<Attribute>,
+}
+
+impl Compound {
+    fn matches(&self, element: &SyntaxElement) -> bool {
+        if matches!(self.kind, Some(kind) if kind != element.kind()) {
+            return false;
+        }
+        if matches!(&self.text, Some(text) if !text_matches(text, element)) {
+            return false;
+        }
+        self.attributes.iter().all(|attr| match element {
+            NodeOrToken::Node(node) => attr.matches(node),
+            NodeOrToken::Token(_) => false,
+        })
+    }
+}
+
+/// Whether the text of an element is the text, string literals also match their value.
+fn text_matches(text: &str, element: &SyntaxElement) -> bool {
+    if element_text(element) == text {
+        return true;
+    }
+    match element {
+        NodeOrToken::Node(node) if node.kind() == LITERAL => {
+            matches!(node.to::<ast::Literal>().inner_string_text(), Some(inner) if inner == text)
+        }
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Attribute {
+    field: String,
+    /// The value the field is compared to and whether the comparison is negated,
+    /// `None` if the attribute only checks for the field.
+    value: Option<(Value, bool)>,
+}
+
+impl Attribute {
+    fn matches(&self, node: &SyntaxNode) -> bool {
+        let field = field(node, &self.field);
+        match &self.value {
+            None => field.is_some(),
+            Some((value, negated)) => {
+                let matches = matches!(&field, Some(field) if value.matches(field));
+                matches != *negated
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Value {
+    Text(String),
+    Compound(Compound),
+}
+
+impl Value {
+    fn matches(&self, element: &SyntaxElement) -> bool {
+        match self {
+            Value::Text(text) => text_matches(text, element),
+            Value::Compound(compound) => compound.matches(element),
+        }
+    }
+}
+
+struct SelectorParser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl SelectorParser<'_> {
+    fn err<T>(&self, message: impl Into<String>) -> Result<T, SelectorError> {
+        Err(SelectorError {
+            message: message.into(),
+            offset: self.pos,
+        })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        self.pos += self.peek().map_or(0, char::len_utf8);
+    }
+
+    /// Skip whitespace and return whether any was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.bump();
+        }
+        self.pos != start
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), SelectorError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.bump();
+                Ok(())
+            }
+            Some(c) => self.err(format!("expected `{}` but found `{}`", expected, c)),
+            None => self.err(format!("expected `{}` but the selector ended", expected)),
+        }
+    }
+
+    fn parse_selector(&mut self) -> Result<Vec<Complex>, SelectorError> {
+        let mut alternatives = vec![self.parse_complex()?];
+        while self.peek() == Some(',') {
+            self.bump();
+            alternatives.push(self.parse_complex()?);
+        }
+        if let Some(c) = self.peek() {
+            return self.err(format!("unexpected `{}`", c));
+        }
+        Ok(alternatives)
+    }
+
+    fn parse_complex(&mut self) -> Result<Complex, SelectorError> {
+        self.skip_whitespace();
+        let mut compounds = vec![self.parse_compound()?];
+        let mut combinators = vec![];
+
+        loop {
+            let whitespace = self.skip_whitespace();
+            let combinator = match self.peek() {
+                Some('>') => {
+                    self.bump();
+                    self.skip_whitespace();
+                    Combinator::Child
+                }
+                None | Some(',') => break,
+                Some(_) if whitespace => Combinator::Descendant,
+                Some(c) => return self.err(format!("unexpected `{}`", c)),
+            };
+            combinators.push(combinator);
+            compounds.push(self.parse_compound()?);
+        }
+        Ok(Complex {
+            compounds,
+            combinators,
+        })
+    }
+
+    fn parse_compound(&mut self) -> Result<Compound, SelectorError> {
+        let kind = if self.peek() == Some('*') {
+            self.bump();
+            None
+        } else {
+            let start = self.pos;
+            while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_') {
+                self.bump();
+            }
+            let name = &self.source[start..self.pos];
+            if name.is_empty() {
+                return match self.peek() {
+                    Some(c) => self.err(format!("expected a syntax kind or `*` but found `{}`", c)),
+                    None => self.err("expected a syntax kind or `*` but the selector ended"),
+                };
+            }
+            match kind_by_name(name) {
+                Some(kind) => Some(kind),
+                None => {
+                    self.pos = start;
+                    return self.err(format!("unknown syntax kind `{}`", name));
+                }
+            }
+        };
+
+        let text = if self.peek() == Some('(') {
+            self.bump();
+            self.skip_whitespace();
+            let text = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(')')?;
+            Some(text)
+        } else {
+            None
+        };
+
+        let mut attributes = vec![];
+        while self.peek() == Some('[') {
+            self.bump();
+            attributes.push(self.parse_attribute(kind)?);
+        }
+        Ok(Compound {
+            kind,
+            text,
+            attributes,
+        })
+    }
+
+    fn parse_attribute(&mut self, kind: Option<SyntaxKind>) -> Result<Attribute, SelectorError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_') {
+            self.bump();
+        }
+        let name = &self.source[start..self.pos];
+        let known = FIELDS.iter().any(|(field_kind, field, _)| {
+            *field == name && (kind.is_none() || kind == Some(*field_kind))
+        });
+        if !known {
+            self.pos = start;
+            return match kind {
+                _ if name.is_empty() => self.err("expected a field name"),
+                Some(kind) => self.err(format!("unknown field `{}` for `{:?}`", name, kind)),
+                None => self.err(format!("unknown field `{}`", name)),
+            };
+        }
+        self.skip_whitespace();
+
+        let negated = match self.peek() {
+            Some(']') => {
+                self.bump();
+                return Ok(Attribute {
+                    field: name.to_string(),
+                    value: None,
+                });
+            }
+            Some('!') => {
+                self.bump();
+                true
+            }
+            _ => false,
+        };
+        self.expect('=')?;
+        self.skip_whitespace();
+        let value = if matches!(self.peek(), Some('"') | Some('\'')) {
+            Value::Text(self.parse_string()?)
+        } else {
+            Value::Compound(self.parse_compound()?)
+        };
+        self.skip_whitespace();
+        self.expect(']')?;
+
+        Ok(Attribute {
+            field: name.to_string(),
+            value: Some((value, negated)),
+        })
+    }
+
+    fn parse_string(&mut self) -> Result<String, SelectorError> {
+        let quote = match self.peek() {
+            Some(c @ '"') | Some(c @ '\'') => c,
+            _ => return self.err("expected a string"),
+        };
+        let start = self.pos;
+        self.bump();
+
+        let mut string = String::new();
+        loop {
+            match self.peek() {
+                Some(c) if c == quote => {
+                    self.bump();
+                    return Ok(string);
+                }
+                Some('\\') => {
+                    self.bump();
+                    match self.peek() {
+                        Some(c) => string.push(c),
+                        None => break,
+                    }
+                    self.bump();
+                }
+                Some(c) => {
+                    string.push(c);
+                    self.bump();
+                }
+                None => break,
+            }
+        }
+        self.pos = start;
+        self.err("unterminated string")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rslint_parser::parse_module;
+
+    fn matches(selector: &str, code: &str) -> Vec<String> {
+        let selector = Selector::new(selector).unwrap();
+        parse_module(code, 0)
+            .syntax()
+            .descendants()
+            .filter(|node| selector.matches(node))
+            .map(|node| node.trimmed_text().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn kinds_and_text() {
+        assert_eq!(matches("NAME_REF", "a + b"), ["a", "b"]);
+        assert_eq!(matches("NAME_REF(\"b\")", "a + b"), ["b"]);
+        assert_eq!(
+            matches("LITERAL('moment')", "'moment'; 'other'"),
+            ["'moment'"]
+        );
+        assert_eq!(matches("NAME_REF, LITERAL", "a + 1"), ["a", "1"]);
+    }
+
+    #[test]
+    fn attributes() {
+        let code = "moment(); moment.utc(); foo(); new moment()";
+        assert_eq!(
+            matches(r#"CALL_EXPR[callee=NAME_REF("moment")]"#, code),
+            ["moment()"]
+        );
+        assert_eq!(
+            matches(
+                r#"CALL_EXPR[callee=DOT_EXPR[object=NAME_REF("moment")]]"#,
+                code
+            ),
+            ["moment.utc()"]
+        );
+        assert_eq!(
+            matches(r#"CALL_EXPR[callee!="moment"]"#, code),
+            ["moment.utc()", "foo()"]
+        );
+        assert_eq!(matches("BIN_EXPR[op='==']", "a == b; a === b"), ["a == b"]);
+        assert_eq!(
+            matches("IF_STMT[alt]", "if (a) {} if (b) {} else {}"),
+            ["if (b) {} else {}"]
+        );
+    }
+
+    #[test]
+    fn combinators() {
+        let code = "function f() { a(); if (b) { c() } }";
+        assert_eq!(matches("FN_DECL CALL_EXPR", code), ["a()", "c()"]);
+        assert_eq!(
+            matches("BLOCK_STMT > EXPR_STMT > CALL_EXPR", code),
+            ["a()", "c()"]
+        );
+        assert_eq!(
+            matches("FN_DECL > BLOCK_STMT > EXPR_STMT > CALL_EXPR", code),
+            ["a()"]
+        );
+        assert_eq!(matches("IF_STMT *[callee]", code), ["c()"]);
+    }
+
+    #[test]
+    fn errors() {
+        let err = |selector: &str| Selector::new(selector).unwrap_err().to_string();
+        assert_eq!(err("CALL"), "unknown syntax kind `CALL` at offset 0");
+        assert_eq!(
+            err("CALL_EXPR[calee]"),
+            "unknown field `calee` for `CALL_EXPR` at offset 10"
+        );
+        assert_eq!(
+            err("CALL_EXPR[callee=]"),
+            "expected a syntax kind or `*` but found `]` at offset 17"
+        );
+        assert_eq!(err("NAME_REF(\"a)"), "unterminated string at offset 9");
+        assert_eq!(
+            err("CALL_EXPR >"),
+            "expected a syntax kind or `*` but the selector ended at offset 11"
+        );
+        assert_eq!(err("CALL_EXPR)"), "unexpected `)` at offset 9");
+    }
+}
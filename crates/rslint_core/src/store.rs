@@ -19,6 +19,19 @@ impl CstRuleStore {
         self.rules.extend(errors());
         self.rules.extend(style());
         self.rules.extend(regex());
+        self.rules.extend(typescript());
+        self
+    }
+
+    /// All rules of the [`registry`](crate::registry::registry), which includes the built in rules,
+    /// and the rules registered by other crates or plugins.
+    pub fn registered(mut self) -> Self {
+        self.rules.extend(
+            crate::registry::registry()
+                .rules()
+                .iter()
+                .map(|meta| meta.rule.clone()),
+        );
         self
     }
 
@@ -30,6 +43,8 @@ impl CstRuleStore {
             .extend(style().into_iter().filter(|x| x.recommended()));
         self.rules
             .extend(regex().into_iter().filter(|x| x.recommended()));
+        self.rules
+            .extend(typescript().into_iter().filter(|x| x.recommended()));
         self
     }
 
@@ -1,4 +1,17 @@
-//! Macros for easily making rule tests which also generate documentation examples.
+//! Macros for easily making rule tests which also generate documentation examples,
+//! and [`RuleTest`] for testing the diagnostics and the fixes of rules.
+//!
+//! The diagnostics of the failing cases of [`rule_tests!`](crate::rule_tests) are rendered
+//! like the long formatter does, and compared to a `.snap` snapshot next to the rule. Snapshots
+//! are written instead of compared (blessed) when the tests are run with `UPDATE_EXPECT=1`.
+
+use crate::{autofix::recursively_apply_fixes, lint_file, CstRule, CstRuleStore, File};
+use rslint_errors::{file::SimpleFile, termcolor::NoColor, Diagnostic, Formatter, LongFormatter};
+use rslint_parser::{parse_with_syntax, FileKind};
+use std::{env, fs, ops::Range, path::Path};
+
+/// The environment variable which makes snapshot tests bless their snapshots.
+pub const BLESS_VAR: &str = "UPDATE_EXPECT";
 
 /// A macro for generating linter rule tests.
 ///
@@ -6,6 +19,9 @@
 /// in user facing docs. You can use a `/// ignore` doc
 /// on a code expr to make docgen ignore it for user facing docs.
 ///
+/// The diagnostics of the `err` cases are compared to a snapshot next to the file of the rule,
+/// named after the file, and after the name of the test if it is not `invalid`.
+///
 /// test code is run as modules, not scripts.
 #[macro_export]
 macro_rules! rule_tests {
@@ -50,20 +66,24 @@ macro_rules! rule_tests {
     } $(,)?) => {
         #[test]
         fn $err_name() {
+            #[allow(unused_mut)]
+            let mut snapshot = std::string::String::new();
             $(
                 let res = rslint_parser::parse_module($code, 0);
-                let errs = $crate::run_rule(&$rule, 0, res.syntax(), true, &[], std::sync::Arc::from($code.to_string()));
+                let errs = $crate::run_rule(&$rule, &$crate::LintCtx { file_id: 0, root: res.syntax(), src: std::sync::Arc::from($code.to_string()), directives: &[], ecma_version: Default::default(), verbose: true });
                 if errs.diagnostics.is_empty() {
                     panic!("\nExpected:\n```\n{}\n```\nto fail linting, but instead it passed (with {} parsing errors)", $code, res.errors().len());
                 }
+                snapshot.push_str(&$crate::testing::render_case("test.js", $code, &errs.diagnostics));
             )*
+            $crate::testing::assert_snapshot(env!("CARGO_MANIFEST_DIR"), file!(), stringify!($err_name), &snapshot);
         }
 
         #[test]
         fn $ok_name() {
             $(
                 let res = rslint_parser::parse_module($ok_code, 0);
-                let errs = $crate::run_rule(&$rule, 0, res.syntax(), true, &[], std::sync::Arc::from($ok_code.to_string()));
+                let errs = $crate::run_rule(&$rule, &$crate::LintCtx { file_id: 0, root: res.syntax(), src: std::sync::Arc::from($ok_code.to_string()), directives: &[], ecma_version: Default::default(), verbose: true });
 
                 if !errs.diagnostics.is_empty() {
                     panic!("\nExpected:\n```\n{}\n```\nto pass linting, but instead it threw errors (along with {} parsing errors):\n\n", $ok_code, res.errors().len());
@@ -117,20 +137,24 @@ macro_rules! ts_rule_tests {
     } $(,)?) => {
         #[test]
         fn $err_name() {
+            #[allow(unused_mut)]
+            let mut snapshot = std::string::String::new();
             $(
                 let res = rslint_parser::parse_typescript($code, 0);
-                let errs = $crate::run_rule(&$rule, 0, res.syntax(), true, &[], std::sync::Arc::from($code.to_string()));
+                let errs = $crate::run_rule(&$rule, &$crate::LintCtx { file_id: 0, root: res.syntax(), src: std::sync::Arc::from($code.to_string()), directives: &[], ecma_version: Default::default(), verbose: true });
                 if errs.diagnostics.is_empty() {
                     panic!("\nExpected:\n```\n{}\n```\nto fail linting, but instead it passed (with {} parsing errors)", $code, res.errors().len());
                 }
+                snapshot.push_str(&$crate::testing::render_case("test.ts", $code, &errs.diagnostics));
             )*
+            $crate::testing::assert_snapshot(env!("CARGO_MANIFEST_DIR"), file!(), stringify!($err_name), &snapshot);
         }
 
         #[test]
         fn $ok_name() {
             $(
                 let res = rslint_parser::parse_typescript($ok_code, 0);
-                let errs = $crate::run_rule(&$rule, 0, res.syntax(), true, &[], std::sync::Arc::from($ok_code.to_string()));
+                let errs = $crate::run_rule(&$rule, &$crate::LintCtx { file_id: 0, root: res.syntax(), src: std::sync::Arc::from($ok_code.to_string()), directives: &[], ecma_version: Default::default(), verbose: true });
 
                 if !errs.diagnostics.is_empty() {
                     panic!("\nExpected:\n```\n{}\n```\nto pass linting, but instead it threw errors (along with {} parsing errors):\n\n", $ok_code, res.errors().len());
@@ -139,3 +163,312 @@ macro_rules! ts_rule_tests {
         }
     };
 }
+
+/// Render the diagnostics of a failing case of a rule for its snapshot, without colors.
+#[doc(hidden)]
+pub fn render_case(file_name: &str, code: &str, diagnostics: &[Diagnostic]) -> String {
+    let file = SimpleFile::new(file_name.to_string(), code.to_string());
+    let mut writer = NoColor::new(Vec::new());
+    LongFormatter
+        .emit_with_writer(diagnostics, &file, &mut writer)
+        .expect("rendering diagnostics to a buffer should not fail");
+    let rendered = String::from_utf8(writer.into_inner()).expect("diagnostics should be utf8");
+    let rendered = rendered
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "=== {}\n{}\n---\n{}\n\n",
+        file_name,
+        code.trim(),
+        rendered.trim()
+    )
+}
+
+/// Compare the snapshot of a test with its `.snap` file, or write it if the tests are blessed.
+///
+/// The snapshot is next to `source_file`, which is relative to the directory of the manifest or one
+/// of its ancestors, like the paths given by `file!()`.
+#[doc(hidden)]
+pub fn assert_snapshot(manifest_dir: &str, source_file: &str, test_name: &str, actual: &str) {
+    let source = Path::new(manifest_dir)
+        .ancestors()
+        .map(|dir| dir.join(source_file))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| panic!("could not find the source file `{}`", source_file));
+    let stem = source.file_stem().unwrap().to_string_lossy();
+    let snap_name = if test_name == "invalid" {
+        format!("{}.snap", stem)
+    } else {
+        format!("{}.{}.snap", stem, test_name)
+    };
+    let path = source.with_file_name(snap_name);
+
+    if env::var_os(BLESS_VAR).is_some() {
+        fs::write(&path, actual).expect("failed to write the snapshot");
+        return;
+    }
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_default()
+        .replace("\r\n", "\n");
+    // snapshots may be checked out with CRLF line endings
+    if expected != actual.replace("\r\n", "\n") {
+        panic!(
+            "\nThe diagnostics do not match the snapshot `{}`, run the tests with `{}=1` to update it.\n\nExpected:\n{}\nActual:\n{}",
+            path.display(),
+            BLESS_VAR,
+            expected,
+            actual
+        );
+    }
+}
+
+/// A macro for generating tests of the fixes of a linter rule.
+///
+/// Each case is a pair of code and the code the fixes of the rule turn it into, a case can
+/// use other options for the rule by giving the rule in parentheses before the code.
+/// Every case is run with [`RuleTest`](crate::testing::RuleTest), which also checks that the
+/// fixed code parses and does not fail linting anymore.
+///
+/// ```ignore
+/// rule_fix_tests! {
+///     BlockSpacing::default(),
+///     "{foo}" => "{ foo }",
+///     (BlockSpacing { style: Style::Never }) "{ foo }" => "{foo}",
+/// }
+/// ```
+#[macro_export]
+macro_rules! rule_fix_tests {
+    ($rule:expr, $($(($case_rule:expr))? $code:literal => $fixed:literal),* $(,)?) => {
+        rule_fix_tests!(fixes, $rule, $($(($case_rule))? $code => $fixed),*);
+    };
+    ($name:ident, $rule:expr, $($(($case_rule:expr))? $code:literal => $fixed:literal),* $(,)?) => {
+        #[test]
+        fn $name() {
+            $(
+                #[allow(unused_variables)]
+                let rule = $rule;
+                $(let rule = $case_rule;)?
+                $crate::testing::RuleTest::new(rule, $code).fixed($fixed).run();
+            )*
+        }
+    };
+}
+
+/// A macro for generating tests of the fixes of a linter rule on TypeScript code, which works
+/// like [`rule_fix_tests`] but parses the code as TypeScript.
+#[macro_export]
+macro_rules! ts_rule_fix_tests {
+    ($rule:expr, $($(($case_rule:expr))? $code:literal => $fixed:literal),* $(,)?) => {
+        ts_rule_fix_tests!(typescript_fixes, $rule, $($(($case_rule))? $code => $fixed),*);
+    };
+    ($name:ident, $rule:expr, $($(($case_rule:expr))? $code:literal => $fixed:literal),* $(,)?) => {
+        #[test]
+        fn $name() {
+            $(
+                #[allow(unused_variables)]
+                let rule = $rule;
+                $(let rule = $case_rule;)?
+                $crate::testing::RuleTest::new(rule, $code).typescript().fixed($fixed).run();
+            )*
+        }
+    };
+}
+
+/// A test of a rule on a single piece of code, which checks the diagnostics of the rule
+/// and the code its fixes produce.
+///
+/// ```ignore
+/// RuleTest::new(NoNewSymbol::default(), "let foo = new Symbol()")
+///     .diagnostic(10..22, "`Symbol` cannot be called as a constructor.")
+///     .fixed("let foo = Symbol()")
+///     .run();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleTest {
+    rule: Box<dyn CstRule>,
+    code: String,
+    kind: FileKind,
+    diagnostics: Option<Vec<(Range<usize>, String)>>,
+    fixed: Option<String>,
+}
+
+impl RuleTest {
+    /// A test of a rule on some code, which is parsed as a module.
+    pub fn new(rule: impl CstRule + 'static, code: impl ToString) -> Self {
+        Self {
+            rule: Box::new(rule),
+            code: code.to_string(),
+            kind: FileKind::Module,
+            diagnostics: None,
+            fixed: None,
+        }
+    }
+
+    /// Parse the code as TypeScript.
+    pub fn typescript(mut self) -> Self {
+        self.kind = FileKind::TypeScript;
+        self
+    }
+
+    /// Expect a diagnostic with a primary label at `range` and a message, once a diagnostic is expected
+    /// the diagnostics of the rule must be exactly the expected ones, in order.
+    pub fn diagnostic(mut self, range: Range<usize>, message: impl ToString) -> Self {
+        self.diagnostics
+            .get_or_insert_with(Vec::new)
+            .push((range, message.to_string()));
+        self
+    }
+
+    /// Expect the fixes of the rule to turn the code into `fixed`.
+    pub fn fixed(mut self, fixed: impl ToString) -> Self {
+        self.fixed = Some(fixed.to_string());
+        self
+    }
+
+    /// Run the test, the code must always fail linting, and the test panics if any expectation is not met.
+    pub fn run(self) {
+        let name = self.rule.name();
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![self.rule.clone()]);
+
+        let file = File::from_string(&self.code, self.kind, "test");
+        let mut result = lint_file(&file, &store, true);
+        let diagnostics = result
+            .rule_results
+            .get(name)
+            .map(|result| result.diagnostics.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|d| {
+                let range = d.primary.as_ref().map(|primary| primary.span.range.clone());
+                (range.unwrap_or_default(), d.title.clone())
+            })
+            .collect::<Vec<_>>();
+
+        match &self.diagnostics {
+            Some(expected) if *expected != diagnostics => panic!(
+                "\nExpected:\n```\n{}\n```\nto produce the diagnostics:\n{:#?}\nbut instead it produced:\n{:#?}",
+                self.code, expected, diagnostics
+            ),
+            None if diagnostics.is_empty() => panic!(
+                "\nExpected:\n```\n{}\n```\nto fail linting, but instead it passed",
+                self.code
+            ),
+            _ => {}
+        }
+
+        let expected = match &self.fixed {
+            Some(fixed) => fixed,
+            None => return,
+        };
+        let fixed = recursively_apply_fixes(&mut result, &file);
+        if fixed != *expected {
+            panic!(
+                "\nExpected the fixes of `{}` to turn:\n```\n{}\n```\ninto:\n```\n{}\n```\nbut instead they produced:\n```\n{}\n```",
+                name, self.code, expected, fixed
+            );
+        }
+
+        let errors = parse_with_syntax(&fixed, 0, file.syntax).errors().len();
+        if errors != 0 {
+            panic!(
+                "\nExpected the fixed code:\n```\n{}\n```\nto parse, but instead it has {} parsing errors",
+                fixed, errors
+            );
+        }
+
+        let fixed_file = File::from_string(&fixed, self.kind, "fixed");
+        let relinted = lint_file(&fixed_file, &store, true);
+        let remaining = relinted
+            .rule_results
+            .get(name)
+            .map_or(0, |result| result.diagnostics.len());
+        if remaining != 0 {
+            panic!(
+                "\nExpected the fixed code:\n```\n{}\n```\nto pass linting, but instead it produced {} diagnostics",
+                fixed, remaining
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RuleTest;
+    use crate::groups::errors::{NoCompareNegZero, NoNewSymbol};
+    use only_ts::OnlyTs;
+
+    mod only_ts {
+        use crate::rule_prelude::*;
+
+        declare_lint! {
+            /// A rule which reports every TypeScript file.
+            #[derive(Default)]
+            OnlyTs,
+            errors,
+            tags(OnlyTS),
+            "only-ts"
+        }
+
+        #[typetag::serde]
+        impl CstRule for OnlyTs {
+            fn check_root(&self, root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+                let err = ctx.err(self.name(), "a TypeScript file").primary(root, "");
+                ctx.add_err(err);
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn diagnostics_and_fixes() {
+        RuleTest::new(NoNewSymbol::default(), "let foo = new Symbol();")
+            .diagnostic(10..22, "`Symbol` cannot be called as a constructor.")
+            .fixed("let foo = Symbol();")
+            .run();
+
+        RuleTest::new(NoCompareNegZero::default(), "let a: number = x == -0")
+            .typescript()
+            .diagnostic(
+                18..20,
+                "comparison against `-0` with `x` yields unexpected behavior",
+            )
+            .fixed("let a: number = Object.is(x, -0)")
+            .run();
+    }
+
+    #[test]
+    #[should_panic(expected = "to produce the diagnostics")]
+    fn wrong_diagnostics() {
+        RuleTest::new(NoNewSymbol::default(), "new Symbol()")
+            .diagnostic(0..3, "`Symbol` cannot be called as a constructor.")
+            .run();
+    }
+
+    #[test]
+    #[should_panic(expected = "but instead they produced")]
+    fn wrong_fix() {
+        RuleTest::new(NoNewSymbol::default(), "new Symbol()")
+            .fixed("Symbol")
+            .run();
+    }
+
+    #[test]
+    #[should_panic(expected = "to fail linting")]
+    fn passing_code() {
+        RuleTest::new(NoNewSymbol::default(), "Symbol()").run();
+    }
+
+    #[test]
+    fn typescript_rules_run_on_typescript() {
+        RuleTest::new(OnlyTs::new(), "foo").typescript().run();
+    }
+
+    #[test]
+    #[should_panic(expected = "to fail linting")]
+    fn typescript_rules_do_not_run_on_javascript() {
+        RuleTest::new(OnlyTs::new(), "foo").run();
+    }
+}
@@ -16,9 +16,17 @@ use std::collections::{BinaryHeap, HashMap};
 use std::hash::Hash;
 use SyntaxKind::*;
 
+pub fn most_frequent<T>(items: Vec<T>) -> T
+where
+    T: Hash + Eq + Ord + Clone,
+{
+    most_frequent_with_count(items).0
+}
+
+/// The most frequent item and how many times it occurs.
 // rustfmt panics on this function for me
 #[rustfmt::skip]
-pub fn most_frequent<T>(items: Vec<T>) -> T
+pub fn most_frequent_with_count<T>(items: Vec<T>) -> (T, usize)
 where
     T: Hash + Eq + Ord + Clone,
 {
@@ -35,7 +43,8 @@ where
         }
     }
     // TODO: remove this clone
-    heap.into_sorted_vec()[0].0.1.to_owned()
+    let (count, item) = &heap.into_sorted_vec()[0].0;
+    (item.to_owned(), *count)
 }
 
 /// Expands an assignment to the returned value, e.g. `foo += 5` -> `foo + 5`, `foo = 6` -> `6`
@@ -202,7 +202,7 @@ pub fn column_index(source: &str, line_range: Range<usize>, byte_index: usize) -
 /// [`Files`]: Files
 /// [`Files::line_index`]: Files::line_index
 pub fn line_starts<'source>(source: &'source str) -> impl 'source + Iterator<Item = usize> {
-    std::iter::once(0).chain(source.match_indices('\n').map(|(i, _)| i + 1))
+    crate::file::line_starts(source)
 }
 
 /// A file database that contains a single source file.
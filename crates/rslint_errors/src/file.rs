@@ -160,6 +160,18 @@ pub trait Files {
 
     /// The byte range of line in the source of the file.
     fn line_range(&self, id: FileId, line_index: usize) -> Option<Range<usize>>;
+
+    /// The index of the line at the byte index, with lines counted like the language server protocol does.
+    ///
+    /// The default implementation computes the [`lsp_line_starts`] of the whole source on every call.
+    fn lsp_line_index(&self, id: FileId, byte_index: usize) -> Option<usize> {
+        Some(LineIndex::lsp(self.source(id)?).line_index(byte_index))
+    }
+
+    /// The byte range of a line, with lines counted like the language server protocol does.
+    fn lsp_line_range(&self, id: FileId, line_index: usize) -> Option<Range<usize>> {
+        LineIndex::lsp(self.source(id)?).line_range(line_index)
+    }
 }
 
 /// A file database that contains only one file.
@@ -167,14 +179,16 @@ pub trait Files {
 pub struct SimpleFile {
     name: String,
     source: String,
-    line_starts: Vec<usize>,
+    lines: LineIndex,
+    lsp_lines: LineIndex,
 }
 
 impl SimpleFile {
     /// Create a new file with the name and source.
     pub fn new(name: String, source: String) -> Self {
         Self {
-            line_starts: line_starts(&source).collect(),
+            lines: LineIndex::new(&source),
+            lsp_lines: LineIndex::lsp(&source),
             name,
             source,
         }
@@ -184,16 +198,6 @@ impl SimpleFile {
     pub fn empty() -> SimpleFile {
         SimpleFile::new(String::new(), String::new())
     }
-
-    fn line_start(&self, line_index: usize) -> Option<usize> {
-        use std::cmp::Ordering;
-
-        match line_index.cmp(&self.line_starts.len()) {
-            Ordering::Less => self.line_starts.get(line_index).cloned(),
-            Ordering::Equal => Some(self.source.len()),
-            Ordering::Greater => None,
-        }
-    }
 }
 
 impl Files for SimpleFile {
@@ -206,18 +210,19 @@ impl Files for SimpleFile {
     }
 
     fn line_index(&self, _file_id: FileId, byte_index: usize) -> Option<usize> {
-        Some(
-            self.line_starts
-                .binary_search(&byte_index)
-                .unwrap_or_else(|next_line| next_line - 1),
-        )
+        Some(self.lines.line_index(byte_index))
     }
 
     fn line_range(&self, _: FileId, line_index: usize) -> Option<Range<usize>> {
-        let line_start = self.line_start(line_index)?;
-        let next_line_start = self.line_start(line_index + 1)?;
+        self.lines.line_range(line_index)
+    }
 
-        Some(line_start..next_line_start)
+    fn lsp_line_index(&self, _: FileId, byte_index: usize) -> Option<usize> {
+        Some(self.lsp_lines.line_index(byte_index))
+    }
+
+    fn lsp_line_range(&self, _: FileId, line_index: usize) -> Option<Range<usize>> {
+        self.lsp_lines.line_range(line_index)
     }
 }
 
@@ -262,13 +267,125 @@ impl Files for SimpleFiles {
     fn line_range(&self, file_id: FileId, line_index: usize) -> Option<Range<usize>> {
         self.files.get(&file_id)?.line_range(file_id, line_index)
     }
+
+    fn lsp_line_index(&self, id: FileId, byte_index: usize) -> Option<usize> {
+        self.files.get(&id)?.lsp_line_index(id, byte_index)
+    }
+
+    fn lsp_line_range(&self, file_id: FileId, line_index: usize) -> Option<Range<usize>> {
+        self.files
+            .get(&file_id)?
+            .lsp_line_range(file_id, line_index)
+    }
 }
 
-/// Computes the byte indicies of every line start.
+/// Computes the byte indices of every line start.
+///
+/// Lines are terminated by `\n`, `\r\n`, a lone `\r`, `\u{2028}` (line separator),
+/// or `\u{2029}` (paragraph separator), like in ECMAScript.
 pub fn line_starts(source: &str) -> impl '_ + Iterator<Item = usize> {
+    starts_of_lines(source, &['\n', '\r', '\u{2028}', '\u{2029}'])
+}
+
+/// Computes the byte indices of every line start like the language server protocol does.
+///
+/// Lines are only terminated by `\n`, `\r\n` or a lone `\r`, so unlike [`line_starts`]
+/// `\u{2028}` and `\u{2029}` stay on their line.
+pub fn lsp_line_starts(source: &str) -> impl '_ + Iterator<Item = usize> {
+    starts_of_lines(source, &['\n', '\r'])
+}
+
+fn starts_of_lines<'a>(
+    source: &'a str,
+    terminators: &'static [char],
+) -> impl 'a + Iterator<Item = usize> {
+    let bytes = source.as_bytes();
     std::iter::once(0).chain(
         source
-            .match_indices(&['\n', '\r', '\u{2028}', '\u{2029}'][..])
-            .map(|(i, _)| i + 1),
+            .match_indices(terminators)
+            // the `\r` of a `\r\n` does not end the line by itself
+            .filter(move |(i, text)| *text != "\r" || bytes.get(i + 1) != Some(&b'\n'))
+            .map(|(i, text)| i + text.len()),
     )
 }
+
+/// The cached line starts of a source, used to convert between byte indices and lines or columns.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+    len: usize,
+}
+
+impl LineIndex {
+    pub fn new(source: &str) -> Self {
+        Self {
+            line_starts: line_starts(source).collect(),
+            len: source.len(),
+        }
+    }
+
+    /// An index of the lines as the language server protocol counts them, see [`lsp_line_starts`].
+    pub fn lsp(source: &str) -> Self {
+        Self {
+            line_starts: lsp_line_starts(source).collect(),
+            len: source.len(),
+        }
+    }
+
+    /// The byte indices of every line start.
+    pub fn line_starts(&self) -> &[usize] {
+        &self.line_starts
+    }
+
+    /// The number of lines in the source, a source always has at least one line.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// The byte index of the start of a line, the line after the last line starts at the end of the source.
+    pub fn line_start(&self, line_index: usize) -> Option<usize> {
+        use std::cmp::Ordering;
+
+        match line_index.cmp(&self.line_starts.len()) {
+            Ordering::Less => self.line_starts.get(line_index).cloned(),
+            Ordering::Equal => Some(self.len),
+            Ordering::Greater => None,
+        }
+    }
+
+    /// The index of the line at the byte index.
+    pub fn line_index(&self, byte_index: usize) -> usize {
+        self.line_starts
+            .binary_search(&byte_index)
+            .unwrap_or_else(|next_line| next_line - 1)
+    }
+
+    /// The byte range of a line, including its line terminator.
+    pub fn line_range(&self, line_index: usize) -> Option<Range<usize>> {
+        let line_start = self.line_start(line_index)?;
+        let next_line_start = self.line_start(line_index + 1)?;
+
+        Some(line_start..next_line_start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_terminators() {
+        let source = "a\nb\r\nc\rd\u{2028}e\u{2029}f";
+        let lines = LineIndex::new(source);
+
+        assert_eq!(lines.line_starts(), [0, 2, 5, 7, 11, 15]);
+        let line_sources = (0..lines.line_count())
+            .map(|line| &source[lines.line_range(line).unwrap()])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            line_sources,
+            ["a\n", "b\r\n", "c\r", "d\u{2028}", "e\u{2029}", "f"]
+        );
+        assert_eq!(LineIndex::lsp(source).line_starts(), [0, 2, 5, 7]);
+    }
+}
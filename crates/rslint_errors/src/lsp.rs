@@ -1,6 +1,6 @@
 //! functions to convert diagnostics to LSP (language server protocol) Diagnostics.
 
-use crate::file::Files;
+use crate::file::{lsp_line_starts, Files};
 use crate::*;
 
 use lsp_types::{
@@ -58,28 +58,30 @@ pub fn convert_to_lsp_diagnostic(
     }
 
     let tags = diagnostic.tag.map(|tag| match tag {
-        DiagnosticTag::Deprecated => vec![LspTag::Deprecated],
-        DiagnosticTag::Unnecessary => vec![LspTag::Unnecessary],
-        DiagnosticTag::Both => vec![LspTag::Deprecated, LspTag::Unnecessary],
+        DiagnosticTag::Deprecated => vec![LspTag::DEPRECATED],
+        DiagnosticTag::Unnecessary => vec![LspTag::UNNECESSARY],
+        DiagnosticTag::Both => vec![LspTag::DEPRECATED, LspTag::UNNECESSARY],
     });
 
     Some(lsp_types::Diagnostic {
         range: primary_label?,
         severity: Some(severity_to_lsp_severity(diagnostic.severity)),
         code: diagnostic.code.map(NumberOrString::String),
+        code_description: None,
         source,
         message,
         related_information: Some(related_information),
         tags,
+        data: None,
     })
 }
 
 pub fn severity_to_lsp_severity(severity: Severity) -> DiagnosticSeverity {
     match severity {
-        Severity::Error | Severity::Bug => DiagnosticSeverity::Error,
-        Severity::Warning => DiagnosticSeverity::Warning,
-        Severity::Help => DiagnosticSeverity::Hint,
-        Severity::Note => DiagnosticSeverity::Information,
+        Severity::Error | Severity::Bug => DiagnosticSeverity::ERROR,
+        Severity::Warning => DiagnosticSeverity::WARNING,
+        Severity::Help => DiagnosticSeverity::HINT,
+        Severity::Note => DiagnosticSeverity::INFORMATION,
     }
 }
 
@@ -216,8 +218,8 @@ fn location_to_position(
         Err(LocationError::InvalidCharBoundary { given }.into())
     } else {
         let line_utf16 = line_str[..column].encode_utf16();
-        let character = line_utf16.count() as u64;
-        let line = line as u64;
+        let character = line_utf16.count() as u32;
+        let line = line as u32;
 
         Ok(LspPosition { line, character })
     }
@@ -235,12 +237,12 @@ where
 
     let line_index =
         files
-            .line_index(file_id, byte_index)
+            .lsp_line_index(file_id, byte_index)
             .ok_or_else(|| LineIndexOutOfBoundsError {
                 given: byte_index,
-                max: source.lines().count(),
+                max: lsp_line_starts(source).count(),
             })?;
-    let line_span = files.line_range(file_id, line_index).unwrap();
+    let line_span = files.lsp_line_range(file_id, line_index).unwrap();
 
     let line_str = source
         .get(line_span.clone())
@@ -267,7 +269,7 @@ where
     })
 }
 
-pub fn character_to_line_offset(line: &str, character: u64) -> Result<usize, Error> {
+pub fn character_to_line_offset(line: &str, character: u32) -> Result<usize, Error> {
     let line_len = line.len();
     let mut character_offset = 0;
 
@@ -280,7 +282,7 @@ pub fn character_to_line_offset(line: &str, character: u64) -> Result<usize, Err
             return Ok(line_len - chars_off - ch_off);
         }
 
-        character_offset += ch.len_utf16() as u64;
+        character_offset += ch.len_utf16() as u32;
     }
 
     // Handle positions after the last character on the line
@@ -304,8 +306,23 @@ where
 {
     let source = files.source(file_id).ok_or(Error::MissingFile)?;
 
-    let line_span = files.line_range(file_id, position.line as usize).unwrap();
-    let line_str = source.get(line_span.clone()).unwrap();
+    let line_span = files
+        .lsp_line_range(file_id, position.line as usize)
+        .ok_or_else(|| LineIndexOutOfBoundsError {
+            given: position.line as usize,
+            max: lsp_line_starts(source).count(),
+        })?;
+    let line_str = source
+        .get(line_span.clone())
+        .ok_or_else(|| SpanOutOfBoundsError {
+            given: line_span.clone(),
+            span: 0..source.len(),
+        })?;
+    // positions can not point inside of the line terminator
+    let line_str = line_str
+        .strip_suffix("\r\n")
+        .or_else(|| line_str.strip_suffix(&['\n', '\r'][..]))
+        .unwrap_or(line_str);
 
     let byte_offset = character_to_line_offset(line_str, position.character)?;
 
@@ -323,3 +340,60 @@ where
     Ok(position_to_byte_index(files, file_id, &range.start)?
         ..position_to_byte_index(files, file_id, &range.end)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::SimpleFile;
+
+    #[test]
+    fn utf16_positions() {
+        let source = "let 🦀 = 'é';\r\nfoo";
+        let file = SimpleFile::new("a.js".into(), source.into());
+        let after_crab = source.find(" =").unwrap();
+        let position = |line, character| LspPosition { line, character };
+
+        assert_eq!(
+            byte_index_to_position(&file, 0, after_crab),
+            Ok(position(0, 6))
+        );
+        assert_eq!(
+            position_to_byte_index(&file, 0, &position(0, 6)),
+            Ok(after_crab)
+        );
+        assert_eq!(
+            byte_index_to_position(&file, 0, after_crab - 1),
+            Err(LocationError::InvalidCharBoundary {
+                given: after_crab - 1
+            }
+            .into())
+        );
+        assert_eq!(
+            byte_index_to_position(&file, 0, source.len()),
+            Ok(position(1, 3))
+        );
+        // positions can not point into the `\r\n`
+        assert_eq!(
+            position_to_byte_index(&file, 0, &position(0, 13)),
+            Ok(source.find('\r').unwrap())
+        );
+        assert!(position_to_byte_index(&file, 0, &position(0, 14)).is_err());
+    }
+
+    #[test]
+    fn line_separators_do_not_end_lsp_lines() {
+        let source = "let a = '\u{2028}\u{2029}';\nfoo";
+        let file = SimpleFile::new("a.js".into(), source.into());
+        let quote = source.rfind('\'').unwrap();
+        let foo = source.find("foo").unwrap();
+        let position = |line, character| LspPosition { line, character };
+
+        assert_eq!(byte_index_to_position(&file, 0, quote), Ok(position(0, 11)));
+        assert_eq!(
+            position_to_byte_index(&file, 0, &position(0, 11)),
+            Ok(quote)
+        );
+        assert_eq!(byte_index_to_position(&file, 0, foo), Ok(position(1, 0)));
+        assert_eq!(position_to_byte_index(&file, 0, &position(1, 0)), Ok(foo));
+    }
+}
@@ -0,0 +1,367 @@
+//! Extraction of the scripts embedded in HTML, Vue, Svelte, and Markdown files.
+//!
+//! Each `<script>` block or fenced code block of a host file is linted as its own virtual file,
+//! the diagnostics and fixes of the virtual file are then remapped to the host file using its offset.
+
+use rslint_errors::Diagnostic;
+use rslint_parser::Syntax;
+use rslint_text_edit::{Indel, TextSize};
+use std::ops::Range;
+use std::path::Path;
+
+/// The extensions of the files scripts are extracted from.
+pub const HOST_FILES: [&str; 6] = ["html", "htm", "vue", "svelte", "md", "markdown"];
+
+/// Whether scripts should be extracted from a file instead of linting it directly.
+pub fn is_host_file(path: &Path) -> bool {
+    matches!(path.extension().and_then(|ext| ext.to_str()), Some(ext) if HOST_FILES.contains(&ext))
+}
+
+/// Whether a host file is a Markdown document, whose code blocks are linted instead of its scripts.
+pub fn is_markdown_file(path: &Path) -> bool {
+    matches!(path.extension(), Some(ext) if ext == "md" || ext == "markdown")
+}
+
+/// A `<script>` block of a host file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptBlock {
+    /// The range of the content of the block in the host file.
+    pub range: Range<usize>,
+    /// The syntax the content is parsed with.
+    pub syntax: Syntax,
+}
+
+/// Where a virtual file extracted from a host file lives in the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Embedded {
+    /// The id of the host file.
+    pub host: usize,
+    /// The offset of the content of the script in the host file.
+    pub offset: usize,
+}
+
+impl Embedded {
+    /// Remap a range in the virtual file to the host file.
+    pub fn remap_range(&self, range: Range<usize>) -> Range<usize> {
+        range.start + self.offset..range.end + self.offset
+    }
+
+    /// Remap all of the spans of a diagnostic which point to the virtual file `file_id` to
+    /// the host file.
+    pub fn remap_diagnostic(&self, file_id: usize, diagnostic: &mut Diagnostic) {
+        if diagnostic.file_id == file_id {
+            diagnostic.file_id = self.host;
+        }
+        let spans = diagnostic
+            .primary
+            .iter_mut()
+            .chain(diagnostic.children.iter_mut())
+            .map(|sub| &mut sub.span);
+
+        for span in spans.filter(|span| span.file == file_id) {
+            span.file = self.host;
+            span.range = self.remap_range(span.range.clone());
+        }
+        for suggestion in diagnostic
+            .suggestions
+            .iter_mut()
+            .filter(|sug| sug.span.file == file_id)
+        {
+            suggestion.span.file = self.host;
+            suggestion.span.range = self.remap_range(suggestion.span.range.clone());
+            // labels point into the file like spans, not into the suggestion
+            for label in suggestion.labels.iter_mut() {
+                *label = self.remap_range(label.clone());
+            }
+            if let rslint_errors::SuggestionChange::Indels(indels) = &mut suggestion.substitution {
+                for indel in indels {
+                    *indel = self.remap_indel(indel.clone());
+                }
+            }
+        }
+    }
+
+    /// Remap an indel on the virtual file to the host file.
+    pub fn remap_indel(&self, indel: Indel) -> Indel {
+        let offset = TextSize::from(self.offset as u32);
+        Indel::replace(indel.delete + offset, indel.insert)
+    }
+}
+
+/// Find the `<script>` blocks of an HTML-like file. Scripts which are not JavaScript or TypeScript,
+/// such as JSON or templates, are skipped. Scripts are modules if `module` is true (for Vue and Svelte
+/// components) or if they have `type="module"`, and TypeScript if they have `lang="ts"`.
+pub fn extract_scripts(source: &str, module: bool) -> Vec<ScriptBlock> {
+    let lower = source.to_ascii_lowercase();
+    let bytes = lower.as_bytes();
+    let mut blocks = vec![];
+    let mut pos = 0;
+
+    while let Some(idx) = lower[pos..].find('<').map(|idx| idx + pos) {
+        if lower[idx..].starts_with("<!--") {
+            pos = lower[idx..]
+                .find("-->")
+                .map_or(source.len(), |end| idx + end + 3);
+            continue;
+        }
+        let after_name = idx + "<script".len();
+        if !lower[idx..].starts_with("<script")
+            || !matches!(bytes.get(after_name), Some(b) if b.is_ascii_whitespace() || *b == b'>' || *b == b'/')
+        {
+            pos = idx + 1;
+            continue;
+        }
+
+        let (attributes, tag_end, self_closing) = script_attributes(source, after_name);
+        if self_closing {
+            pos = tag_end;
+            continue;
+        }
+        let end = lower[tag_end..]
+            .find("</script")
+            .map_or(source.len(), |end| tag_end + end);
+        pos = end;
+
+        if let Some(syntax) = script_syntax(&attributes, module) {
+            blocks.push(ScriptBlock {
+                range: tag_end..end,
+                syntax,
+            });
+        }
+    }
+    blocks
+}
+
+/// Parse the attributes of a script tag starting after its name, returns the attributes with
+/// lowercased names, the index after the end of the tag, and whether the tag is self closing.
+fn script_attributes(source: &str, start: usize) -> (Vec<(String, String)>, usize, bool) {
+    let bytes = source.as_bytes();
+    let mut attributes = vec![];
+    let mut pos = start;
+
+    loop {
+        while matches!(bytes.get(pos), Some(b) if b.is_ascii_whitespace()) {
+            pos += 1;
+        }
+        match bytes.get(pos) {
+            None => return (attributes, source.len(), false),
+            Some(b'>') => return (attributes, pos + 1, false),
+            Some(b'/') if bytes.get(pos + 1) == Some(&b'>') => return (attributes, pos + 2, true),
+            _ => {}
+        }
+
+        let name_start = pos;
+        while matches!(bytes.get(pos), Some(b) if !b.is_ascii_whitespace() && !b"=>/".contains(b)) {
+            pos += 1;
+        }
+        // a stray `/`
+        if pos == name_start {
+            pos += 1;
+            continue;
+        }
+        let name = source[name_start..pos].to_ascii_lowercase();
+
+        let mut value = String::new();
+        if bytes.get(pos) == Some(&b'=') {
+            pos += 1;
+            match bytes.get(pos) {
+                Some(&quote) if quote == b'"' || quote == b'\'' => {
+                    let value_end = source[pos + 1..]
+                        .find(quote as char)
+                        .map_or(source.len(), |end| pos + 1 + end);
+                    value = source[pos + 1..value_end].to_string();
+                    pos = (value_end + 1).min(source.len());
+                }
+                _ => {
+                    let value_start = pos;
+                    while matches!(bytes.get(pos), Some(b) if !b.is_ascii_whitespace() && *b != b'>')
+                    {
+                        pos += 1;
+                    }
+                    value = source[value_start..pos].to_string();
+                }
+            }
+        }
+        attributes.push((name, value));
+    }
+}
+
+/// The syntax of a script from its attributes, or `None` if it is not a JavaScript or TypeScript script.
+fn script_syntax(attributes: &[(String, String)], module: bool) -> Option<Syntax> {
+    let attribute = |name: &str| {
+        attributes
+            .iter()
+            .find(|(attr, _)| attr == name)
+            .map(|(_, value)| value.trim().to_ascii_lowercase())
+    };
+
+    let module = match attribute("type").as_deref() {
+        None | Some("") | Some("text/javascript") | Some("application/javascript") => module,
+        Some("module") => true,
+        _ => return None,
+    };
+    match attribute("lang").as_deref() {
+        None | Some("js") | Some("javascript") if module => Some(Syntax::default().module()),
+        None | Some("js") | Some("javascript") => Some(Syntax::default().script()),
+        Some("ts") | Some("typescript") => Some(Syntax::default().typescript()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rslint_errors::{Applicability, SuggestionChange};
+    use rslint_parser::FileKind;
+    use rslint_text_edit::TextRange;
+
+    /// The sources of the scripts extracted from a host file.
+    fn scripts(source: &str, module: bool) -> Vec<(&str, FileKind)> {
+        extract_scripts(source, module)
+            .into_iter()
+            .map(|block| (&source[block.range], block.syntax.file_kind))
+            .collect()
+    }
+
+    #[test]
+    fn extracts_scripts_after_the_start() {
+        let source = "<p>hello</p>\n<script>\nlet a = 5;\n</script>\n";
+        let blocks = extract_scripts(source, false);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].range, 21..33);
+        assert_eq!(&source[blocks[0].range.clone()], "\nlet a = 5;\n");
+    }
+
+    #[test]
+    fn extracts_multiple_scripts() {
+        let source = r#"<SCRIPT>a();</SCRIPT>
+<!-- <script>commented();</script> -->
+<script type="application/json">{}</script>
+<script src="lib.js" />
+<script type="module">b();</script>
+<script lang="ts">let c: number;</script>"#;
+        assert_eq!(
+            scripts(source, false),
+            vec![
+                ("a();", FileKind::Script),
+                ("b();", FileKind::Module),
+                ("let c: number;", FileKind::TypeScript),
+            ]
+        );
+    }
+
+    #[test]
+    fn components_are_modules() {
+        let source = "<template><div /></template>\n<script>export default {};</script>";
+        assert_eq!(
+            scripts(source, true),
+            vec![("export default {};", FileKind::Module)]
+        );
+    }
+
+    #[test]
+    fn extracts_scripts_with_crlf() {
+        let source =
+            "<div>\r\n</div>\r\n<script\r\n  type=\"module\">\r\nlet a = 5;\r\n</script>\r\n";
+        assert_eq!(
+            scripts(source, false),
+            vec![("\r\nlet a = 5;\r\n", FileKind::Module)]
+        );
+    }
+
+    #[test]
+    fn unclosed_script_runs_to_the_end() {
+        let source = "<script>let a = 5;";
+        assert_eq!(
+            scripts(source, false),
+            vec![("let a = 5;", FileKind::Script)]
+        );
+    }
+
+    #[test]
+    fn parses_script_attributes() {
+        let source = r#"<script TYPE='module' defer lang=ts data-x="a>b">"#;
+        let (attributes, end, self_closing) = script_attributes(source, "<script".len());
+        assert_eq!(
+            attributes,
+            vec![
+                ("type".to_string(), "module".to_string()),
+                ("defer".to_string(), String::new()),
+                ("lang".to_string(), "ts".to_string()),
+                ("data-x".to_string(), "a>b".to_string()),
+            ]
+        );
+        assert_eq!(end, source.len());
+        assert!(!self_closing);
+
+        let source = "<script src=a.js/>rest";
+        let (attributes, end, self_closing) = script_attributes(source, "<script".len());
+        assert_eq!(attributes, vec![("src".to_string(), "a.js/".to_string())]);
+        assert_eq!(&source[end..], "rest");
+        assert!(!self_closing);
+
+        let source = "<script src=\"a.js\" />rest";
+        let (_, end, self_closing) = script_attributes(source, "<script".len());
+        assert_eq!(&source[end..], "rest");
+        assert!(self_closing);
+    }
+
+    #[test]
+    fn remaps_diagnostics_to_the_host() {
+        let embedded = Embedded {
+            host: 1,
+            offset: 10,
+        };
+        let mut diagnostic = Diagnostic::error(2, "", "error")
+            .primary(0usize..3, "")
+            .secondary(4usize..5, "")
+            .label_in_file(
+                rslint_errors::Severity::Note,
+                rslint_errors::file::FileSpan::new(3, 0usize..1),
+                String::new(),
+            )
+            .indel_suggestion(
+                vec![Indel::insert(2.into(), "x".to_string())],
+                2usize..3,
+                "insert",
+                Applicability::Always,
+            );
+        embedded.remap_diagnostic(2, &mut diagnostic);
+
+        assert_eq!(diagnostic.file_id, 1);
+        let primary = diagnostic.primary.unwrap().span;
+        assert_eq!((primary.file, primary.range), (1, 10..13));
+        let spans = diagnostic
+            .children
+            .iter()
+            .map(|child| (child.span.file, child.span.range.clone()))
+            .collect::<Vec<_>>();
+        // spans into other files are left alone
+        assert_eq!(spans, vec![(1, 14..15), (3, 0..1)]);
+
+        let suggestion = &diagnostic.suggestions[0];
+        assert_eq!(
+            (suggestion.span.file, suggestion.span.range.clone()),
+            (1, 12..13)
+        );
+        assert_eq!(suggestion.labels, vec![12..13]);
+        match &suggestion.substitution {
+            SuggestionChange::Indels(indels) => {
+                assert_eq!(indels[0].delete, TextRange::empty(12.into()));
+            }
+            SuggestionChange::String(_) => panic!("expected indels"),
+        }
+    }
+
+    #[test]
+    fn remaps_indels_to_the_host() {
+        let embedded = Embedded { host: 0, offset: 7 };
+        let indel = embedded.remap_indel(Indel::replace(
+            TextRange::new(1.into(), 4.into()),
+            "b".to_string(),
+        ));
+        assert_eq!(indel.delete, TextRange::new(8.into(), 11.into()));
+        assert_eq!(indel.insert, "b");
+        assert_eq!(embedded.remap_range(0..2), 7..9);
+    }
+}
@@ -0,0 +1,354 @@
+//! The structure responsible for managing IO and the files implementation for codespan.
+
+use crate::embedded::{extract_scripts, is_host_file, is_markdown_file, Embedded};
+use crate::{lint_note, lint_warn};
+use ignore::{WalkBuilder, WalkState};
+use rslint_config::Config;
+use rslint_core::{markdown, File};
+use rslint_errors::file::{FileId, Files};
+use rslint_errors::Diagnostic;
+use rslint_parser::{FileKind, Syntax};
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::ops::Range;
+use std::path::PathBuf;
+
+/// The filename of the ignore file for RSLint
+const RSLINT_IGNORE_FILE: &str = ".rslintignore";
+
+/// The structure for managing IO to and from the core runner.
+/// The walker uses multithreaded IO, spawning a thread for every file being loaded.
+// TODO: use IO_Uring for linux
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FileWalker {
+    /// The files which are linted, including the scripts extracted from host files.
+    pub files: HashMap<usize, File>,
+    /// The files scripts were extracted from, such as HTML or Vue files.
+    pub hosts: HashMap<usize, File>,
+    /// The location in its host of each script extracted from a host file, by the id of the script.
+    pub embedded: HashMap<usize, Embedded>,
+}
+
+/// Options for how a walker finds the files it loads.
+#[derive(Debug, Clone, Default)]
+pub struct LoadOptions {
+    /// The amount of threads used to walk directories, `0` lets the walker choose it.
+    pub num_threads: usize,
+    /// Do not skip the files ignored by `.rslintignore` or the ignore file.
+    pub no_ignore: bool,
+    /// A file to use instead of `.rslintignore` files.
+    pub ignore_file: Option<PathBuf>,
+    /// Skip the files ignored by git.
+    pub use_gitignore: bool,
+    /// Parse every loaded file as this kind of file, instead of the kind inferred for it.
+    pub overwrite_filetype: Option<FileKind>,
+}
+
+/// A file loaded by a walker thread.
+enum LoadedFile {
+    File(File),
+    /// A host file and the scripts extracted from it with their offset in the host.
+    Host(File, Vec<(File, usize)>),
+}
+
+impl Files for FileWalker {
+    fn name(&self, id: FileId) -> Option<&str> {
+        let entry = self.file(id)?;
+        let name = entry
+            .path
+            .as_ref()
+            .and_then(|path| path.to_str())
+            .unwrap_or(entry.name.as_str());
+        Some(name)
+    }
+
+    fn source(&self, id: FileId) -> Option<&str> {
+        let entry = self.file(id)?;
+        Some(&entry.source)
+    }
+
+    fn line_index(&self, id: FileId, byte_index: usize) -> Option<usize> {
+        Some(self.file(id)?.line_index(byte_index))
+    }
+
+    fn line_range(&self, file_id: FileId, line_index: usize) -> Option<Range<usize>> {
+        self.file(file_id)?.line_range(line_index)
+    }
+}
+
+impl FileWalker {
+    pub fn empty() -> Self {
+        Self {
+            files: HashMap::new(),
+            hosts: HashMap::new(),
+            embedded: HashMap::new(),
+        }
+    }
+
+    /// Get a linted file or a host file by its id.
+    fn file(&self, id: FileId) -> Option<&File> {
+        self.files.get(&id).or_else(|| self.hosts.get(&id))
+    }
+
+    /// Remap a diagnostic of a script extracted from a host file to the host file, so it
+    /// can be emitted. Diagnostics of other files are returned unchanged.
+    pub fn remap_diagnostic(&self, mut diagnostic: Diagnostic) -> Diagnostic {
+        if let Some(embedded) = self.embedded.get(&diagnostic.file_id) {
+            embedded.remap_diagnostic(diagnostic.file_id, &mut diagnostic);
+        }
+        diagnostic
+    }
+
+    /// Make a new file walker from a compiled glob pattern. This also
+    /// skips any unreadable files/dirs
+    pub fn from_glob_parallel(paths: Vec<PathBuf>, num_threads: usize) -> Self {
+        let mut base = Self::default();
+        let options = LoadOptions {
+            num_threads,
+            ..LoadOptions::default()
+        };
+        base.load_files_parallel(paths.into_iter(), &options, &Config::default());
+        base
+    }
+
+    /// Load the files at the paths and in the directories of `paths`, files are parsed with the
+    /// syntax the `file-types` table of the config or their extension gives them. The scripts
+    /// of HTML, Vue, and Svelte files are extracted into their own files. Files of an unknown
+    /// type are skipped.
+    pub fn load_files_parallel(
+        &mut self,
+        paths: impl Iterator<Item = PathBuf>,
+        options: &LoadOptions,
+        config: &Config,
+    ) {
+        let LoadOptions {
+            num_threads,
+            no_ignore,
+            ref ignore_file,
+            use_gitignore,
+            overwrite_filetype,
+        } = *options;
+        let build_walker = |path: &PathBuf| {
+            let mut builder = WalkBuilder::new(path);
+            builder.standard_filters(false);
+
+            if !no_ignore {
+                match ignore_file.as_ref() {
+                    Some(file) => {
+                        if let Some(err) = builder.add_ignore(file) {
+                            crate::lint_warn!("invalid gitignore file: {}", err);
+                        }
+                    }
+                    None => {
+                        builder.add_custom_ignore_filename(RSLINT_IGNORE_FILE);
+                    }
+                }
+
+                builder
+                    .parents(true)
+                    .hidden(true)
+                    .git_global(use_gitignore)
+                    .git_ignore(use_gitignore)
+                    .git_exclude(use_gitignore);
+            }
+
+            builder.threads(num_threads).build_parallel()
+        };
+
+        for path in paths {
+            let (tx, rx) = std::sync::mpsc::channel();
+            build_walker(&path).run(|| {
+                let tx = tx.clone();
+                Box::new(move |entry| {
+                    let (path, explicit) = match entry {
+                        Ok(entry) => match entry.file_type() {
+                            Some(typ) if !typ.is_dir() => {
+                                let explicit = entry.depth() == 0;
+                                (entry.into_path(), explicit)
+                            }
+                            _ => return WalkState::Continue,
+                        },
+                        Err(err) => {
+                            crate::lint_warn!("invalid gitignore file: {}", err);
+                            return WalkState::Continue;
+                        }
+                    };
+
+                    // check if this is a js/ts file or a file with scripts, only files which were
+                    // given explicitly are worth a note, not every file of a directory
+                    let syntax = config.file_syntax(&path);
+                    if syntax.is_none() && !is_host_file(&path) {
+                        if explicit {
+                            lint_note!(
+                                "skipping `{}` because its file type is unknown, map it to a source type in the `file-types` table of the config to lint it",
+                                path.display()
+                            );
+                        }
+                        return WalkState::Continue;
+                    }
+
+                    // read the content of the file
+                    let content = match std::fs::read_to_string(&path) {
+                        Ok(c) => c,
+                        Err(err) => {
+                            crate::lint_err!("failed to read file {}: {}", path.display(), err);
+                            return WalkState::Continue;
+                        }
+                    };
+
+                    let loaded = match syntax {
+                        Some(syntax) => {
+                            let mut file = File::with_syntax(content, path, syntax);
+                            if let Some(kind) = overwrite_filetype {
+                                file.overwrite_kind(kind);
+                            }
+                            LoadedFile::File(file)
+                        }
+                        // code blocks keep the path of their document, so the overrides of the config apply to them
+                        None if is_markdown_file(&path) => {
+                            // the ranges of the blocks are relative to the source without the byte order mark
+                            let host = File::with_syntax(content, path.clone(), Syntax::default());
+                            let blocks = markdown::code_blocks(&host.source)
+                                .into_iter()
+                                .map(|block| {
+                                    let source = host.source[block.range.clone()].to_string();
+                                    let file = File::with_syntax(source, path.clone(), block.syntax);
+                                    (file, block.range.start)
+                                })
+                                .collect();
+                            LoadedFile::Host(host, blocks)
+                        }
+                        None => {
+                            // vue and svelte components are always modules
+                            let module = !matches!(path.extension(), Some(ext) if ext == "html" || ext == "htm");
+                            let host = File::with_syntax(content, path.clone(), Syntax::default());
+                            let scripts = extract_scripts(&host.source, module)
+                                .into_iter()
+                                .map(|block| {
+                                    let source = host.source[block.range.clone()].to_string();
+                                    let file = File::with_syntax(source, path.clone(), block.syntax);
+                                    (file, block.range.start)
+                                })
+                                .collect();
+                            LoadedFile::Host(host, scripts)
+                        }
+                    };
+
+                    tx.send(loaded)
+                        .expect("failed to send files to receiver thread");
+                    WalkState::Continue
+                })
+            });
+
+            drop(tx);
+
+            for loaded in rx {
+                match loaded {
+                    LoadedFile::File(file) => {
+                        self.files.insert(file.id, file);
+                    }
+                    LoadedFile::Host(host, scripts) => {
+                        for (file, offset) in scripts {
+                            let embedded = Embedded {
+                                host: host.id,
+                                offset,
+                            };
+                            self.embedded.insert(file.id, embedded);
+                            self.files.insert(file.id, file);
+                        }
+                        self.hosts.insert(host.id, host);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn line_start(&self, id: usize, line_index: usize) -> Option<usize> {
+        self.files.get(&id)?.line_start(line_index)
+    }
+
+    /// try loading a file's source code and updating the correspoding file in the walker
+    pub fn maybe_update_file_src(&mut self, path: PathBuf) {
+        let embedded = &self.embedded;
+        if let Some((_, file)) = self.files.iter_mut().find(|(id, f)| {
+            !embedded.contains_key(id) && f.path.as_deref().map_or(false, |x| x == path)
+        }) {
+            let src = if let Ok(src) = read_to_string(&path) {
+                src
+            } else {
+                return lint_warn!(
+                    "failed to reload the source code at `{}`",
+                    path.to_string_lossy()
+                );
+            };
+            file.reload_src(src);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn code_blocks_keep_the_path_of_their_document() {
+        let dir = std::env::temp_dir().join(format!("rslint-markdown-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("README.md");
+        fs::write(&path, "# Example\n\n```ts\nlet a: number = 5;\n```\n").unwrap();
+
+        let walker = FileWalker::from_glob_parallel(vec![path.clone()], 1);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(walker.hosts.len(), 1);
+        let (id, file) = walker.files.iter().next().unwrap();
+        assert_eq!(file.path.as_deref(), Some(path.as_path()));
+        assert_eq!(file.source, "let a: number = 5;\n");
+        assert_eq!(file.syntax, Syntax::default().typescript());
+        assert_eq!(walker.embedded[id].offset, "# Example\n\n```ts\n".len());
+    }
+
+    #[test]
+    fn reloading_matches_the_full_path() {
+        let dir = std::env::temp_dir().join(format!("rslint-reload-{}", std::process::id()));
+        fs::create_dir_all(dir.join("a")).unwrap();
+        fs::create_dir_all(dir.join("b")).unwrap();
+        let a = dir.join("a").join("index.js");
+        let b = dir.join("b").join("index.js");
+        fs::write(&a, "let a = 1;").unwrap();
+        fs::write(&b, "let b = 1;").unwrap();
+
+        let mut walker = FileWalker::from_glob_parallel(vec![a.clone(), b.clone()], 1);
+        fs::write(&b, "let b = 2;").unwrap();
+        walker.maybe_update_file_src(b.clone());
+        fs::remove_dir_all(&dir).unwrap();
+
+        let source = |path: &Path| {
+            walker
+                .files
+                .values()
+                .find(|f| f.path.as_deref() == Some(path))
+                .unwrap()
+                .source
+                .clone()
+        };
+        assert_eq!(source(&a), "let a = 1;");
+        assert_eq!(source(&b), "let b = 2;");
+    }
+
+    #[test]
+    fn reloading_a_host_leaves_its_code_blocks_alone() {
+        let dir = std::env::temp_dir().join(format!("rslint-reload-host-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("README.md");
+        fs::write(&path, "```js\nlet a = 1;\n```\n").unwrap();
+
+        let mut walker = FileWalker::from_glob_parallel(vec![path.clone()], 1);
+        walker.maybe_update_file_src(path.clone());
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(walker.files.values().next().unwrap().source, "let a = 1;\n");
+    }
+}
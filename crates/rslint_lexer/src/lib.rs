@@ -856,27 +856,29 @@ impl<'src> Lexer<'src> {
     fn read_shebang(&mut self) -> LexerReturn {
         let start = self.cur;
         self.next();
-        if start != 0 {
+        // a shebang may only follow a byte order mark
+        let bom = '\u{FEFF}'.len_utf8();
+        if start != 0 && (start != bom || !self.bytes.starts_with("\u{FEFF}".as_bytes())) {
             return (Token::new(T![#], 1), None);
         }
 
-        if let Some(b'!') = self.bytes.get(1) {
+        if let Some(b'!') = self.bytes.get(start + 1) {
             while self.next().is_some() {
                 let chr = self.get_unicode_char();
 
                 if is_linebreak(chr) {
-                    return tok!(SHEBANG, self.cur);
+                    return tok!(SHEBANG, self.cur - start);
                 }
                 self.cur += chr.len_utf8() - 1;
             }
-            tok!(SHEBANG, self.cur)
+            tok!(SHEBANG, self.cur - start)
         } else {
             let err = Diagnostic::error(
                 self.file_id,
                 "",
                 "expected `!` following a `#`, but found none",
             )
-            .primary(0usize..1usize, "");
+            .primary(start..start + 1, "");
 
             (Token::new(SyntaxKind::ERROR_TOKEN, 1), Some(err))
         }
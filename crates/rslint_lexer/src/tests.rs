@@ -824,6 +824,21 @@ fn shebang() {
         NUMBER:1
     }
 
+    assert_lex! {
+        "\u{FEFF}#!/bin/node\n",
+        WHITESPACE:3,
+        SHEBANG:11,
+        WHITESPACE:1
+    }
+
+    assert_lex! {
+        "\u{FEFF} #!/bin/node",
+        WHITESPACE:4,
+        HASH:1,
+        BANG:1,
+        REGEX:9
+    }
+
     assert_lex! {
         "0#!/bin/deno",
         NUMBER:1,
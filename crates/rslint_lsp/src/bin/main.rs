@@ -1,17 +1,15 @@
+use rslint_lsp::lsp::server;
 use tower_lsp::{LspService, Server};
 
-#[tokio::main]
+#[cfg_attr(feature = "threaded", tokio::main)]
+#[cfg_attr(not(feature = "threaded"), tokio::main(flavor = "current_thread"))]
 async fn main() -> anyhow::Result<()> {
     env_logger::try_init()?;
 
-    let (service, messages) =
-        LspService::new(|client| rslint_lsp::lsp::server::Server::new(client).unwrap());
+    let (service, socket) = LspService::new(|client| server::Server::new(client).unwrap());
     let stdin = tokio::io::stdin();
     let stdout = tokio::io::stdout();
-    Server::new(stdin, stdout)
-        .interleave(messages)
-        .serve(service)
-        .await;
+    Server::new(stdin, stdout, socket).serve(service).await;
 
     Ok(())
 }
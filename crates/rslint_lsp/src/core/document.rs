@@ -1,9 +1,10 @@
 //! Core definitions related to documents.
 
 use crate::core::language::{Language, LanguageId};
+use rslint_config::Config;
 use rslint_core::{autofix::Fixer, Directive, DirectiveError, DirectiveParser, File};
 use rslint_errors::file::SimpleFiles;
-use rslint_parser::{FileKind, SyntaxNode};
+use rslint_parser::SyntaxNode;
 use std::convert::TryFrom;
 use tower_lsp::lsp_types::*;
 
@@ -31,14 +32,28 @@ pub struct Document {
     /// The result of running rules on the document
     pub rule_results: Vec<RuleResult>,
     pub root: SyntaxNode,
+    /// The semantic tokens last sent to the client, used to compute token deltas.
+    pub semantic_tokens: Option<SemanticTokens>,
+    /// The version of the document given by the client.
+    pub version: Option<i32>,
+    /// The result id of the diagnostics last pulled by the client, cleared when the document changes.
+    pub diagnostics_result_id: Option<String>,
 }
 
 impl Document {
-    /// Create a new Document.
-    pub fn new(uri: Url, language_id: String, text: String) -> anyhow::Result<Self> {
+    /// Create a new Document, parsed with the syntax the config sets for it.
+    pub fn new(
+        uri: Url,
+        language_id: String,
+        text: String,
+        config: &Config,
+    ) -> anyhow::Result<Self> {
         let language = {
             if let Ok(path) = uri.to_file_path() {
-                Language::try_from(path.as_path())?
+                match config.file_type(&path) {
+                    Some(kind) => kind.into(),
+                    None => Language::try_from(path.as_path())?,
+                }
             } else {
                 Language::try_from(LanguageId(language_id.clone()))?
             }
@@ -46,13 +61,10 @@ impl Document {
 
         let mut files = SimpleFiles::new();
         let file_id = files.add(uri.to_string(), text.clone());
-        let kind = match language {
-            Language::JavaScriptModule => FileKind::Module,
-            Language::JavaScriptScript => FileKind::Script,
-            Language::TypeScript => FileKind::TypeScript,
-        };
-        let mut file = File::from_string(text, kind, uri.path());
+        let mut file = File::from_string(text, language.into(), uri.path());
         file.id = file_id;
+        let path = uri.to_file_path().ok();
+        file.overwrite_syntax(config.syntax(path.as_deref(), language.into()));
 
         let (parsing_errors, root) = file.parse_with_errors();
 
@@ -68,8 +80,41 @@ impl Document {
             directive_errors: res.diagnostics,
             rule_results: vec![],
             root,
+            semantic_tokens: None,
+            version: None,
+            diagnostics_result_id: None,
         };
 
         Ok(document)
     }
+
+    /// Set the syntax of the document from a config, parsing the document again if the syntax
+    /// changed.
+    pub fn configure(&mut self, uri: &Url, config: &Config) {
+        let path = uri.to_file_path().ok();
+        if let Some(path) = path.as_deref() {
+            if let Some(kind) = config.file_type(path) {
+                self.language = kind.into();
+            } else if let Ok(language) = Language::try_from(path) {
+                self.language = language;
+            }
+        }
+        let syntax = config.syntax(path.as_deref(), self.language.into());
+        if syntax != self.file.syntax {
+            self.file.overwrite_syntax(syntax);
+            self.reparse();
+        }
+    }
+
+    /// Parse the source of the document again, updating its syntax tree and directives.
+    pub fn reparse(&mut self) {
+        let (parsing_errors, root) = self.file.parse_with_errors();
+        let res = DirectiveParser::new(root.clone(), &self.file).get_file_directives();
+
+        self.root = root;
+        self.directives = res.directives;
+        self.directive_errors = res.diagnostics;
+        self.parsing_errors = parsing_errors;
+        self.diagnostics_result_id = None;
+    }
 }
@@ -19,6 +19,16 @@ pub(crate) enum Error {
     ToStrFailed,
     #[error("failed to get file extension for PathBuf: {0}")]
     PathExtensionFailed(PathBuf),
+    #[error("unknown command: {0}")]
+    UnknownCommand(String),
+    #[error("invalid arguments for command {0}")]
+    InvalidCommandArguments(String),
+    #[error("{0}")]
+    UnknownRule(String),
+    #[error("no workspace folder is open")]
+    NoWorkspaceRoot,
+    #[error("the config file could not be edited: {0}")]
+    ConfigEditFailed(String),
 }
 
 pub(crate) struct IntoJsonRpcError(pub(crate) anyhow::Error);
@@ -1,6 +1,7 @@
 //! Core definitions related to language types for documents.
 
 use crate::core::error::Error;
+use rslint_parser::{FileKind, Syntax};
 use std::{convert::TryFrom, path::Path};
 
 /// A language type for a document (e.g., JavaScript (script) or JavaScript (module) or TypeScript).
@@ -10,8 +11,16 @@ pub enum Language {
     JavaScriptScript,
     /// JavaScript (module)
     JavaScriptModule,
+    /// JavaScript (CommonJS module, `.cjs`)
+    JavaScriptCommonJs,
     /// TypeScript
     TypeScript,
+    /// TypeScript (ES module, `.mts`)
+    TypeScriptModule,
+    /// TypeScript (CommonJS module, `.cts`)
+    TypeScriptCommonJs,
+    /// TypeScript (declaration file, `.d.ts`, `.d.mts` or `.d.cts`)
+    TypeScriptDeclaration,
 }
 
 /// A language id for a document (e.g., JavaScript (script) or JavaScript (module)).
@@ -21,13 +30,56 @@ pub struct LanguageId(pub String);
 impl From<Language> for LanguageId {
     fn from(language: Language) -> Self {
         match language {
-            Language::JavaScriptScript => LanguageId("javascript".into()),
-            Language::JavaScriptModule => LanguageId("javascript".into()),
-            Language::TypeScript => LanguageId("typescript".into()),
+            Language::JavaScriptScript
+            | Language::JavaScriptModule
+            | Language::JavaScriptCommonJs => LanguageId("javascript".into()),
+            Language::TypeScript
+            | Language::TypeScriptModule
+            | Language::TypeScriptCommonJs
+            | Language::TypeScriptDeclaration => LanguageId("typescript".into()),
         }
     }
 }
 
+impl From<Language> for FileKind {
+    fn from(language: Language) -> Self {
+        Syntax::from(language).file_kind
+    }
+}
+
+impl From<FileKind> for Language {
+    fn from(kind: FileKind) -> Self {
+        match kind {
+            FileKind::Script => Language::JavaScriptScript,
+            FileKind::Module => Language::JavaScriptModule,
+            FileKind::TypeScript => Language::TypeScript,
+        }
+    }
+}
+
+impl Language {
+    /// The file extension of the language, used to infer its syntax like for files on disk.
+    fn extension(self) -> &'static str {
+        match self {
+            Language::JavaScriptScript => "js",
+            Language::JavaScriptModule => "mjs",
+            Language::JavaScriptCommonJs => "cjs",
+            Language::TypeScript => "ts",
+            Language::TypeScriptModule => "mts",
+            Language::TypeScriptCommonJs => "cts",
+            Language::TypeScriptDeclaration => "d.ts",
+        }
+    }
+}
+
+impl From<Language> for Syntax {
+    fn from(language: Language) -> Self {
+        let path = format!("document.{}", language.extension());
+        Syntax::from_path(Path::new(&path))
+            .expect("language extensions are JavaScript or TypeScript extensions")
+    }
+}
+
 impl TryFrom<&Path> for Language {
     type Error = anyhow::Error;
 
@@ -36,10 +88,18 @@ impl TryFrom<&Path> for Language {
             .extension()
             .ok_or_else(|| Error::PathExtensionFailed(path.into()))?;
         let file_ext = file_ext.to_str().ok_or(Error::ToStrFailed)?;
+        let declaration = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .map_or(false, |stem| stem.ends_with(".d"));
         match file_ext {
+            "ts" | "mts" | "cts" if declaration => Ok(Language::TypeScriptDeclaration),
             "mjs" => Ok(Language::JavaScriptModule),
             "js" => Ok(Language::JavaScriptScript),
+            "cjs" => Ok(Language::JavaScriptCommonJs),
             "ts" => Ok(Language::TypeScript),
+            "mts" => Ok(Language::TypeScriptModule),
+            "cts" => Ok(Language::TypeScriptCommonJs),
             _ => Err(Error::InvalidLanguageExtension(file_ext.into()).into()),
         }
     }
@@ -57,3 +117,30 @@ impl TryFrom<LanguageId> for Language {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_syntax_matches_path_syntax() {
+        // `.d.mts` and `.d.cts` files share the declaration language, which does not keep the module system
+        for name in ["a.js", "a.mjs", "a.cjs", "a.ts", "a.mts", "a.cts", "a.d.ts"] {
+            let path = Path::new(name);
+            let language = Language::try_from(path).unwrap();
+            assert_eq!(
+                Syntax::from(language),
+                Syntax::from_path(path).unwrap(),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn mts_documents_are_modules() {
+        assert!(Syntax::from(Language::TypeScriptModule).is_module());
+        assert!(!Syntax::from(Language::TypeScript).is_module());
+        assert!(!Syntax::from(Language::TypeScriptCommonJs).is_module());
+    }
+}
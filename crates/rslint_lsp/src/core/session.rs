@@ -5,19 +5,31 @@ use dashmap::{
     mapref::one::{Ref, RefMut},
     DashMap,
 };
-use futures::executor::block_on;
+use futures::{executor::block_on, future::AbortHandle};
 use rslint_core::CstRuleStore;
 use serde::Deserialize;
 use serde_json::Value;
-use std::sync::RwLock;
-use taplo::{parser::Parse, util::coords::Mapper};
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+};
+use taplo::{
+    parser::Parse,
+    rowan::{TextRange, TextSize},
+    util::coords::{self, Mapper},
+};
 use tower_lsp::lsp_types::ConfigurationItem;
 use tower_lsp::{lsp_types::*, Client};
 
 #[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct Config {
     pub incorrect_file_autofixes: bool,
+    /// Load the plugins listed in the rslint config of the workspace when the server starts.
+    pub load_plugins: bool,
 }
 
 impl Config {
@@ -30,19 +42,61 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             incorrect_file_autofixes: true,
+            load_plugins: false,
         }
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct TomlDocument {
+    pub uri: Url,
+    pub version: Option<i32>,
     pub parse: Parse,
     pub mapper: Mapper,
 }
 
 impl TomlDocument {
-    pub fn new(parse: Parse, mapper: Mapper) -> Self {
-        Self { parse, mapper }
+    pub fn new(uri: Url, version: Option<i32>, parse: Parse, mapper: Mapper) -> Self {
+        Self {
+            uri,
+            version,
+            parse,
+            mapper,
+        }
+    }
+
+    /// Map a text range of the config to an LSP range.
+    ///
+    /// The taplo mapper speaks an older lsp-types, so its positions are converted here.
+    pub fn range(&self, range: TextRange) -> Option<Range> {
+        let range = self.mapper.range(range)?;
+        Some(Range::new(
+            Position::new(range.start.line as u32, range.start.character as u32),
+            Position::new(range.end.line as u32, range.end.character as u32),
+        ))
+    }
+
+    /// Map an LSP position to a text offset of the config.
+    pub fn offset(&self, position: Position) -> Option<TextSize> {
+        self.mapper.offset(coords::Position::new(
+            position.line.into(),
+            position.character.into(),
+        ))
+    }
+}
+
+/// A pending lint of a document.
+pub(crate) struct LintJob {
+    pub(crate) id: usize,
+    pub(crate) handle: AbortHandle,
+    /// Set when the job is replaced, aborting the handle does not stop a lint which is running.
+    pub(crate) cancelled: Arc<AtomicBool>,
+}
+
+impl LintJob {
+    pub(crate) fn cancel(&self) {
+        self.handle.abort();
+        self.cancelled.store(true, Ordering::SeqCst);
     }
 }
 
@@ -50,16 +104,27 @@ impl TomlDocument {
 pub struct Session {
     client: Option<Client>,
     documents: DashMap<Url, Document>,
-    pub(crate) store: CstRuleStore,
+    /// All registered rules, replaced once the plugins of the workspace are loaded.
+    pub(crate) store: RwLock<CstRuleStore>,
     pub(crate) config: RwLock<Config>,
     pub(crate) config_doc: RwLock<Option<TomlDocument>>,
+    /// The rslint config of the workspace, which decides the syntax documents are parsed with.
+    pub(crate) lint_config: RwLock<rslint_config::Config>,
+    /// The root directory of the workspace opened by the client.
+    pub(crate) root: RwLock<Option<PathBuf>>,
+    /// The pending lint jobs of documents, aborted when the document changes again.
+    pub(crate) lint_jobs: DashMap<Url, LintJob>,
+    /// Whether the client pulls diagnostics, in which case they are not published on changes.
+    pub(crate) pull_diagnostics: AtomicBool,
+    /// Whether the client accepts requests to pull the diagnostics of all documents again.
+    pub(crate) diagnostic_refresh: AtomicBool,
 }
 
 impl Session {
     /// Create a new session.
     pub fn new(client: Option<Client>) -> anyhow::Result<Self> {
         let documents = DashMap::new();
-        let store = CstRuleStore::new().builtins();
+        let store = CstRuleStore::new().registered();
         let config = RwLock::new(
             client
                 .as_ref()
@@ -78,9 +143,14 @@ impl Session {
         Ok(Session {
             client,
             documents,
-            store,
+            store: RwLock::new(store),
             config,
             config_doc: RwLock::new(None),
+            lint_config: RwLock::new(Default::default()),
+            root: RwLock::new(None),
+            lint_jobs: DashMap::new(),
+            pull_diagnostics: AtomicBool::new(false),
+            diagnostic_refresh: AtomicBool::new(false),
         })
     }
 
@@ -106,6 +176,14 @@ impl Session {
         Ok(result)
     }
 
+    /// The uris of all the open documents.
+    pub(crate) fn document_uris(&self) -> Vec<Url> {
+        self.documents
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect()
+    }
+
     /// Get a reference to a document associated with the session, if possible.
     pub async fn get_document(&self, uri: &Url) -> anyhow::Result<Ref<'_, Url, Document>> {
         self.documents
@@ -1,20 +1,52 @@
 //! Definitions for the LSP server instance.
 
 use crate::{
-    core::session::{Config, TomlDocument},
+    core::{
+        error::IntoJsonRpcError,
+        session::{Config, TomlDocument},
+    },
     lsp::server::Server,
     provider,
     service::synchronizer,
 };
+use serde_json::{json, Value};
+use std::sync::atomic::Ordering;
 use tower_lsp::{
     jsonrpc::{self, Result},
-    lsp_types::*,
+    lsp_types::{
+        request::{DocumentDiagnosticRequest, Request},
+        *,
+    },
     LanguageServer,
 };
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Server {
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        *self.session.root.write().unwrap() =
+            params.root_uri.and_then(|uri| uri.to_file_path().ok());
+        if let Some(Ok(config)) = params.initialization_options.map(Config::from_value) {
+            *self.session.config.write().unwrap() = config;
+        }
+        self.load_lint_config();
+
+        let refresh = params
+            .capabilities
+            .workspace
+            .and_then(|caps| caps.diagnostic)
+            .and_then(|caps| caps.refresh_support)
+            .unwrap_or_default();
+        self.session
+            .diagnostic_refresh
+            .store(refresh, Ordering::SeqCst);
+        let pull = params
+            .capabilities
+            .text_document
+            .and_then(|caps| caps.diagnostic)
+            .and_then(|caps| caps.dynamic_registration)
+            .unwrap_or_default();
+        self.session.pull_diagnostics.store(pull, Ordering::SeqCst);
+
         let capabilities = crate::lsp::server::capabilities();
         Ok(InitializeResult {
             capabilities,
@@ -62,10 +94,116 @@ impl LanguageServer for Server {
             .map_err(|_| jsonrpc::Error::internal_error())
     }
 
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        if uri_is_toml(&params.text_document.uri) {
+            return Ok(None);
+        }
+        provider::document_symbols::document_symbols(&*self.session, params)
+            .await
+            .map_err(|_| jsonrpc::Error::internal_error())
+    }
+
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        if uri_is_toml(&params.text_document.uri) {
+            return Ok(None);
+        }
+        provider::folding_ranges::folding_ranges(&*self.session, params)
+            .await
+            .map_err(|_| jsonrpc::Error::internal_error())
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<Option<Vec<SelectionRange>>> {
+        if uri_is_toml(&params.text_document.uri) {
+            return Ok(None);
+        }
+        provider::selection_ranges::selection_ranges(&*self.session, params)
+            .await
+            .map_err(|_| jsonrpc::Error::internal_error())
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<Option<SemanticTokensResult>> {
+        if uri_is_toml(&params.text_document.uri) {
+            return Ok(None);
+        }
+        provider::semantic_tokens::full(&*self.session, params)
+            .await
+            .map_err(|_| jsonrpc::Error::internal_error())
+    }
+
+    async fn semantic_tokens_full_delta(
+        &self,
+        params: SemanticTokensDeltaParams,
+    ) -> Result<Option<SemanticTokensFullDeltaResult>> {
+        if uri_is_toml(&params.text_document.uri) {
+            return Ok(None);
+        }
+        provider::semantic_tokens::full_delta(&*self.session, params)
+            .await
+            .map_err(|_| jsonrpc::Error::internal_error())
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> Result<Option<SemanticTokensRangeResult>> {
+        if uri_is_toml(&params.text_document.uri) {
+            return Ok(None);
+        }
+        provider::semantic_tokens::range(&*self.session, params)
+            .await
+            .map_err(|_| jsonrpc::Error::internal_error())
+    }
+
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> Result<DocumentDiagnosticReportResult> {
+        provider::diagnostics::pull_diagnostics(&*self.session, params)
+            .await
+            .map_err(|_| jsonrpc::Error::internal_error())
+    }
+
+    async fn execute_command(&self, params: ExecuteCommandParams) -> Result<Option<Value>> {
+        provider::commands::execute_command(&*self.session, params)
+            .await
+            .map_err(|err| IntoJsonRpcError(err).into())
+    }
+
     async fn initialized(&self, _: InitializedParams) {
-        let typ = MessageType::Info;
+        let typ = MessageType::INFO;
         let message = "RSLint Language Server initialized!";
         self.client.log_message(typ, message).await;
+
+        if self.session.pull_diagnostics.load(Ordering::SeqCst) {
+            let registration = Registration {
+                id: "rslint-pull-diagnostics".to_string(),
+                method: DocumentDiagnosticRequest::METHOD.to_string(),
+                register_options: Some(json!({
+                    "documentSelector": null,
+                    "identifier": "rslint",
+                    "interFileDependencies": false,
+                    "workspaceDiagnostics": false,
+                })),
+            };
+            if self
+                .client
+                .register_capability(vec![registration])
+                .await
+                .is_err()
+            {
+                // fall back to publishing diagnostics if the client did not accept the registration
+                self.session.pull_diagnostics.store(false, Ordering::SeqCst);
+            }
+        }
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -78,7 +216,10 @@ impl LanguageServer for Server {
             let parse = taplo::parser::parse(&doc.text);
             let mapper = taplo::util::coords::Mapper::new_utf16(&doc.text, false);
 
-            *self.session.config_doc.write().unwrap() = Some(TomlDocument::new(parse, mapper));
+            self.reload_lint_config(&doc.uri, &doc.text);
+            *self.session.config_doc.write().unwrap() =
+                Some(TomlDocument::new(doc.uri, Some(doc.version), parse, mapper));
+            self.publish_config_diagnostics().await;
         } else {
             if params.text_document.language_id == "toml" {
                 return;
@@ -94,15 +235,19 @@ impl LanguageServer for Server {
         if is_config_doc(&params.text_document.uri) {
             if let Some(ref mut doc) = *self.session.config_doc.write().unwrap() {
                 let TextDocumentContentChangeEvent { text, .. } = params.content_changes[0].clone();
+                doc.version = Some(params.text_document.version);
                 doc.parse = taplo::parser::parse(&text);
                 doc.mapper = taplo::util::coords::Mapper::new_utf16(&text, false);
             }
+            let TextDocumentContentChangeEvent { text, .. } = &params.content_changes[0];
+            self.reload_lint_config(&params.text_document.uri, text);
+            self.publish_config_diagnostics().await;
         } else {
             if uri_is_toml(&params.text_document.uri) {
                 return;
             }
 
-            synchronizer::document::change(&*self.session, params)
+            synchronizer::document::change(self.session.clone(), params)
                 .await
                 .unwrap()
         }
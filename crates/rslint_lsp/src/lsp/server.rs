@@ -1,7 +1,9 @@
 //! Definitions for the LSP server instance.
 
-use crate::core::session::Session;
-use std::sync::Arc;
+use crate::{core::session::Session, provider, service::synchronizer};
+use rslint_config::Config;
+use rslint_core::CstRuleStore;
+use std::sync::{atomic::Ordering, Arc};
 use tower_lsp::{lsp_types::*, Client};
 
 /// The RSLint Language Server instance.
@@ -18,6 +20,69 @@ impl Server {
         let session = Arc::new(Session::new(Some(client.clone()))?);
         Ok(Server { client, session })
     }
+
+    /// Load the rslint config of the workspace root, and its plugins if the user opted in.
+    #[allow(unsafe_code)]
+    pub(crate) fn load_lint_config(&self) {
+        let root = match self.session.root.read().unwrap().clone() {
+            Some(root) => root,
+            None => return,
+        };
+        if self.session.config.read().unwrap().load_plugins {
+            // SAFETY: the user opted into loading plugins through the initialization options of
+            // their client, which the vscode extension only takes from the user settings so a
+            // workspace cannot enable it by itself. Plugins are only loaded from the config on
+            // disk, never from the text of an open config.
+            match unsafe { Config::load_plugins_in(&root, false) } {
+                Ok(()) => *self.session.store.write().unwrap() = CstRuleStore::new().registered(),
+                Err(err) => {
+                    log::error!("failed to load the plugins of the config: {}", err.1.title)
+                }
+            }
+        }
+        // errors in the config are reported once it is opened
+        if let Ok(config) = Config::new_in(&root, false) {
+            *self.session.lint_config.write().unwrap() = config;
+        }
+    }
+
+    /// Reload the rslint config of the workspace from the text of an open config document if it
+    /// is the config which applies to the workspace, and parse the open documents again.
+    pub(crate) fn reload_lint_config(&self, uri: &Url, text: &str) {
+        let path = match uri.to_file_path() {
+            Ok(path) => path,
+            Err(_) => return,
+        };
+        let root = self.session.root.read().unwrap().clone();
+        let dir = match (path.parent(), root) {
+            (Some(dir), Some(root)) if root.starts_with(dir) => dir,
+            _ => return,
+        };
+        // a config closer to the workspace root takes precedence
+        if let Some(current) = self.session.lint_config.read().unwrap().dir() {
+            if current != dir && current.starts_with(dir) {
+                return;
+            }
+        }
+
+        // invalid configs are reported through the config diagnostics
+        if let Ok(config) = Config::from_source(text.to_string(), path.clone()) {
+            *self.session.lint_config.write().unwrap() = config;
+            synchronizer::document::reconfigure(self.session.clone());
+        }
+    }
+
+    /// Publish the diagnostics of the open rslint config, unless the client pulls diagnostics.
+    pub(crate) async fn publish_config_diagnostics(&self) {
+        // clients pulling diagnostics request them again by themselves
+        if self.session.pull_diagnostics.load(Ordering::SeqCst) {
+            return;
+        }
+        if let Err(err) = provider::toml_diagnostics::publish_toml_diagnostics(&self.session).await
+        {
+            log::error!("failed to publish config diagnostics: {}", err);
+        }
+    }
 }
 
 /// Compute the server capabilities.
@@ -25,7 +90,7 @@ pub fn capabilities() -> ServerCapabilities {
     let text_document_sync = Some(TextDocumentSyncCapability::Options(
         TextDocumentSyncOptions {
             open_close: Some(true),
-            change: Some(TextDocumentSyncKind::Full),
+            change: Some(TextDocumentSyncKind::FULL),
             ..Default::default()
         },
     ));
@@ -43,6 +108,25 @@ pub fn capabilities() -> ServerCapabilities {
             ..Default::default()
         }),
         code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+        selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: provider::commands::COMMANDS
+                .iter()
+                .map(|command| command.to_string())
+                .collect(),
+            ..Default::default()
+        }),
+        semantic_tokens_provider: Some(
+            SemanticTokensOptions {
+                legend: provider::semantic_tokens::legend(),
+                range: Some(true),
+                full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+                ..Default::default()
+            }
+            .into(),
+        ),
         text_document_sync,
         ..Default::default()
     }
@@ -106,7 +106,7 @@ fn ignore_file_action(document: &Document, uri: &Url, diagnostics: Vec<Diagnosti
     let line = document
         .root
         .token_with_kind(T![shebang])
-        .map_or(0u64, |_| 1);
+        .map_or(0u32, |_| 1);
 
     let first_edit = TextEdit {
         range: LspRange::new(Position::new(line, 0), Position::new(line, 0)),
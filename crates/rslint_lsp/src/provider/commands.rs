@@ -0,0 +1,498 @@
+//! Provider for the commands run through `workspace/executeCommand`.
+
+use crate::{
+    core::{document::Document, error::Error, session::Session},
+    provider::diagnostics,
+};
+use anyhow::Result;
+use rslint_config::{configures_rule, find_config, key_path};
+use rslint_core::{explain, get_rule_by_name, get_rule_suggestion};
+use rslint_errors::file::SimpleFile;
+use rslint_files::{FileWalker, LoadOptions};
+use serde_json::Value;
+use std::{collections::HashMap, ops::Range};
+use taplo::syntax::{SyntaxKind, SyntaxNode};
+use tower_lsp::lsp_types::*;
+
+/// Render the documentation of a rule as markdown.
+pub const EXPLAIN_RULE: &str = "rslint.explainRule";
+/// Lint every file of the workspace, including the ones which are not open.
+pub const LINT_WORKSPACE: &str = "rslint.lintWorkspace";
+/// Move a rule to the `allowed` rules of the workspace config.
+pub const DISABLE_RULE_IN_CONFIG: &str = "rslint.disableRuleInConfig";
+
+/// All the commands supported by the server.
+pub const COMMANDS: [&str; 3] = [EXPLAIN_RULE, LINT_WORKSPACE, DISABLE_RULE_IN_CONFIG];
+
+const CONFIG_NAME: &str = "rslintrc.toml";
+
+pub async fn execute_command(
+    session: &Session,
+    params: ExecuteCommandParams,
+) -> Result<Option<Value>> {
+    let ExecuteCommandParams {
+        command, arguments, ..
+    } = params;
+
+    match command.as_str() {
+        EXPLAIN_RULE => {
+            let rule = rule_argument(&command, &arguments)?;
+            Ok(Some(Value::String(explain_rule(&rule)?)))
+        }
+        LINT_WORKSPACE => {
+            let linted = lint_workspace(session).await?;
+            Ok(Some(Value::from(linted)))
+        }
+        DISABLE_RULE_IN_CONFIG => {
+            let rule = rule_argument(&command, &arguments)?;
+            let applied = disable_rule_in_config(session, &rule).await?;
+            Ok(Some(Value::Bool(applied)))
+        }
+        _ => Err(Error::UnknownCommand(command).into()),
+    }
+}
+
+/// Get the rule name given as the first argument of a command, checking that the rule exists.
+fn rule_argument(command: &str, arguments: &[Value]) -> Result<String> {
+    let rule = arguments
+        .first()
+        .and_then(|arg| arg.as_str())
+        .ok_or_else(|| Error::InvalidCommandArguments(command.to_string()))?;
+
+    if get_rule_by_name(rule).is_none() {
+        let message = match get_rule_suggestion(rule) {
+            Some(suggestion) => format!("unknown rule '{}', did you mean '{}'?", rule, suggestion),
+            None => format!("unknown rule '{}'", rule),
+        };
+        return Err(Error::UnknownRule(message).into());
+    }
+    Ok(rule.to_string())
+}
+
+fn explain_rule(rule: &str) -> Result<String> {
+    explain::markdown(rule)
+        .ok_or_else(|| Error::UnknownRule(format!("no documentation for rule '{}'", rule)).into())
+}
+
+/// Lint all files of the workspace and publish their diagnostics, returning the amount of linted files.
+async fn lint_workspace(session: &Session) -> Result<usize> {
+    let root = session
+        .root
+        .read()
+        .unwrap()
+        .clone()
+        .ok_or(Error::NoWorkspaceRoot)?;
+
+    let mut walker = FileWalker::empty();
+    {
+        let config = session.lint_config.read().unwrap();
+        let options = LoadOptions {
+            use_gitignore: true,
+            ..LoadOptions::default()
+        };
+        walker.load_files_parallel(std::iter::once(root), &options, &config);
+    }
+
+    // scripts extracted from html, vue, and svelte files are not supported by documents
+    let embedded = walker.embedded;
+    let linted = walker.files.len() - embedded.len();
+    for file in walker
+        .files
+        .into_values()
+        .filter(|file| !embedded.contains_key(&file.id))
+    {
+        let uri = match file
+            .path
+            .as_ref()
+            .and_then(|path| Url::from_file_path(path).ok())
+        {
+            Some(uri) => uri,
+            None => continue,
+        };
+
+        // open documents may have unsaved changes, so they are linted as they are in the editor
+        if session.get_document(&uri).await.is_ok() {
+            diagnostics::publish_diagnostics(session, uri).await?;
+            continue;
+        }
+
+        let mut document = {
+            let config = session.lint_config.read().unwrap();
+            Document::new(uri.clone(), String::new(), file.source, &config)?
+        };
+        let diagnostics = diagnostics::lint_document(session, &mut document, &uri);
+        session
+            .client()?
+            .publish_diagnostics(uri, diagnostics, None)
+            .await;
+    }
+
+    Ok(linted)
+}
+
+/// Edit the config of the workspace to move a rule to the `allowed` rules, creating the config
+/// if there is none. Returns whether the client applied the edit.
+async fn disable_rule_in_config(session: &Session, rule: &str) -> Result<bool> {
+    let root = session
+        .root
+        .read()
+        .unwrap()
+        .clone()
+        .ok_or(Error::NoWorkspaceRoot)?;
+    let path = find_config(&root).unwrap_or_else(|| root.join(CONFIG_NAME));
+    if path.extension() != Some("toml".as_ref()) {
+        return Err(Error::ConfigEditFailed(format!(
+            "only an `{}` can be edited, but the config is `{}`",
+            CONFIG_NAME,
+            path.display()
+        ))
+        .into());
+    }
+    let uri = Url::from_file_path(&path)
+        .map_err(|_| Error::ConfigEditFailed(path.display().to_string()))?;
+
+    let open_text = session
+        .config_doc
+        .read()
+        .unwrap()
+        .as_ref()
+        .filter(|doc| doc.uri == uri)
+        .map(|doc| doc.parse.clone().into_syntax().text().to_string());
+    let exists = open_text.is_some() || path.exists();
+    let text = match open_text {
+        Some(text) => text,
+        None if exists => std::fs::read_to_string(&path)?,
+        None => String::new(),
+    };
+
+    let file = SimpleFile::new(uri.to_string(), text.clone());
+    let edits = disable_rule_edits(&text, rule)?
+        .into_iter()
+        .map(|(range, new_text)| {
+            Ok(TextEdit {
+                range: rslint_errors::lsp::byte_span_to_range(&file, 0, range)?,
+                new_text,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let edit = if exists {
+        let mut changes = HashMap::new();
+        changes.insert(uri, edits);
+        WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }
+    } else {
+        WorkspaceEdit {
+            document_changes: Some(DocumentChanges::Operations(vec![
+                DocumentChangeOperation::Op(ResourceOp::Create(CreateFile {
+                    uri: uri.clone(),
+                    options: None,
+                    annotation_id: None,
+                })),
+                DocumentChangeOperation::Edit(TextDocumentEdit {
+                    text_document: OptionalVersionedTextDocumentIdentifier { uri, version: None },
+                    edits: edits.into_iter().map(OneOf::Left).collect(),
+                }),
+            ])),
+            ..Default::default()
+        }
+    };
+
+    let response = session.client()?.apply_edit(edit).await?;
+    Ok(response.applied)
+}
+
+/// Compute the edits to a config which remove the configuration of a rule from the `errors` and
+/// `warnings` rules, and add it to the `allowed` rules.
+fn disable_rule_edits(text: &str, rule: &str) -> Result<Vec<(Range<usize>, String)>> {
+    let root = taplo::parser::parse(text).into_syntax();
+    let mut edits = vec![];
+
+    let mut table: Vec<String> = vec![];
+    let mut in_rule_table = false;
+    let mut rules_header = None;
+    let mut allowed = None;
+    let mut dotted_rules_at_root = false;
+
+    for node in root.children() {
+        let key = node
+            .children()
+            .find(|child| child.kind() == SyntaxKind::KEY)
+            .map(|key| key_path(&key.text().to_string()))
+            .unwrap_or_default();
+
+        match node.kind() {
+            SyntaxKind::TABLE_HEADER | SyntaxKind::TABLE_ARRAY_HEADER => {
+                table = key;
+                in_rule_table = configures_rule(&table, rule);
+                if in_rule_table {
+                    edits.push((line_range(&node), String::new()));
+                }
+                if table == ["rules"] {
+                    rules_header = Some(node);
+                }
+            }
+            SyntaxKind::ENTRY => {
+                let path = table.iter().cloned().chain(key).collect::<Vec<_>>();
+                if in_rule_table || configures_rule(&path, rule) {
+                    edits.push((line_range(&node), String::new()));
+                } else if path == ["rules", "allowed"] {
+                    allowed = Some(node);
+                } else {
+                    inline_rule_edits(&node, &path, rule, &mut edits);
+                    if table.is_empty() && path.first().map(|s| s.as_str()) == Some("rules") {
+                        dotted_rules_at_root = true;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let quoted = format!("\"{}\"", rule);
+    if let Some(entry) = allowed {
+        let array = entry
+            .descendants()
+            .find(|node| node.kind() == SyntaxKind::ARRAY)
+            .ok_or_else(|| Error::ConfigEditFailed("`rules.allowed` is not an array".into()))?;
+
+        let already_allowed = array
+            .descendants_with_tokens()
+            .filter_map(|elem| elem.into_token())
+            .any(|tok| tok.kind() == SyntaxKind::STRING && tok.text().trim_matches('"') == rule);
+
+        if !already_allowed {
+            let last = array
+                .children_with_tokens()
+                .filter(|elem| {
+                    !matches!(
+                        elem.kind(),
+                        SyntaxKind::WHITESPACE
+                            | SyntaxKind::NEWLINE
+                            | SyntaxKind::COMMENT
+                            | SyntaxKind::BRACKET_END
+                    )
+                })
+                .last()
+                .ok_or_else(|| Error::ConfigEditFailed("`rules.allowed` is invalid".into()))?;
+
+            let offset = usize::from(last.text_range().end());
+            let new_text = match last.kind() {
+                SyntaxKind::BRACKET_START => quoted,
+                SyntaxKind::COMMA => format!(" {}", quoted),
+                _ => format!(", {}", quoted),
+            };
+            edits.push((offset..offset, new_text));
+        }
+    } else if let Some(header) = rules_header {
+        let offset = line_range(&header).end;
+        let newline = if text[..offset].ends_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+        let new_text = format!("{}allowed = [{}]\n", newline, quoted);
+        edits.push((offset..offset, new_text));
+    } else if dotted_rules_at_root {
+        edits.push((0..0, format!("rules.allowed = [{}]\n", quoted)));
+    } else {
+        let separator = match text {
+            "" => "",
+            _ if text.ends_with('\n') => "\n",
+            _ => "\n\n",
+        };
+        let new_text = format!("{}[rules]\nallowed = [{}]\n", separator, quoted);
+        edits.push((text.len()..text.len(), new_text));
+    }
+
+    Ok(edits)
+}
+
+/// Compute the edits which remove the configuration of a rule from the inline tables of an entry,
+/// such as `errors = { no-empty = {} }` under `[rules]`.
+fn inline_rule_edits(
+    entry: &SyntaxNode,
+    path: &[String],
+    rule: &str,
+    edits: &mut Vec<(Range<usize>, String)>,
+) {
+    let table = match entry
+        .children()
+        .find(|child| child.kind() == SyntaxKind::VALUE)
+        .and_then(|value| {
+            value
+                .children()
+                .find(|child| child.kind() == SyntaxKind::INLINE_TABLE)
+        }) {
+        Some(table) => table,
+        None => return,
+    };
+
+    for child in table
+        .children()
+        .filter(|child| child.kind() == SyntaxKind::ENTRY)
+    {
+        let key = child
+            .children()
+            .find(|child| child.kind() == SyntaxKind::KEY)
+            .map(|key| key_path(&key.text().to_string()))
+            .unwrap_or_default();
+        let path = path.iter().cloned().chain(key).collect::<Vec<_>>();
+
+        if configures_rule(&path, rule) {
+            edits.push((inline_entry_range(&child), String::new()));
+        } else {
+            inline_rule_edits(&child, &path, rule, edits);
+        }
+    }
+}
+
+/// The range of an entry of an inline table including the comma separating it from the next
+/// entry, or from the previous entry if it is the last one.
+fn inline_entry_range(entry: &SyntaxNode) -> Range<usize> {
+    let range = entry.text_range();
+    let (start, end) = (usize::from(range.start()), usize::from(range.end()));
+
+    let mut next = entry.next_sibling_or_token();
+    while let Some(elem) = next {
+        match elem.kind() {
+            SyntaxKind::WHITESPACE | SyntaxKind::NEWLINE => next = elem.next_sibling_or_token(),
+            SyntaxKind::COMMA => {
+                // take the whitespace after the comma as well so `{ a = 1, b = 2 }` stays tidy
+                let mut end = usize::from(elem.text_range().end());
+                if let Some(space) = elem
+                    .next_sibling_or_token()
+                    .filter(|elem| elem.kind() == SyntaxKind::WHITESPACE)
+                {
+                    end = usize::from(space.text_range().end());
+                }
+                return start..end;
+            }
+            _ => break,
+        }
+    }
+
+    let mut prev = entry.prev_sibling_or_token();
+    while let Some(elem) = prev {
+        match elem.kind() {
+            SyntaxKind::WHITESPACE | SyntaxKind::NEWLINE => prev = elem.prev_sibling_or_token(),
+            SyntaxKind::COMMA => {
+                // the entry owns the whitespace before the closing brace, which is kept
+                let end = entry
+                    .descendants_with_tokens()
+                    .filter_map(|elem| elem.into_token())
+                    .filter(|tok| {
+                        !matches!(tok.kind(), SyntaxKind::WHITESPACE | SyntaxKind::NEWLINE)
+                    })
+                    .last()
+                    .map_or(end, |tok| usize::from(tok.text_range().end()));
+                return usize::from(elem.text_range().start())..end;
+            }
+            _ => break,
+        }
+    }
+    start..end
+}
+
+/// The range of a node including its indentation and the newline after it.
+fn line_range(node: &SyntaxNode) -> Range<usize> {
+    let mut start = usize::from(node.text_range().start());
+    if let Some(indent) = node
+        .prev_sibling_or_token()
+        .filter(|elem| elem.kind() == SyntaxKind::WHITESPACE)
+    {
+        start = usize::from(indent.text_range().start());
+    }
+
+    let mut end = usize::from(node.text_range().end());
+    if let Some(newline) = node
+        .next_sibling_or_token()
+        .filter(|elem| elem.kind() == SyntaxKind::NEWLINE)
+        .and_then(|elem| elem.into_token())
+    {
+        end += if newline.text().starts_with("\r\n") {
+            2
+        } else {
+            1
+        };
+    }
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disable(text: &str, rule: &str) -> String {
+        let mut edits = disable_rule_edits(text, rule).unwrap();
+        edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+        let mut text = text.to_string();
+        for (range, new_text) in edits {
+            text.replace_range(range, &new_text);
+        }
+        text
+    }
+
+    #[test]
+    fn disable_rule_table() {
+        assert_eq!(
+            disable(
+                "[rules]\nallowed = []\n\n[rules.errors.no-empty]\nallowEmptyCatch = true\n",
+                "no-empty"
+            ),
+            "[rules]\nallowed = [\"no-empty\"]\n\n"
+        );
+    }
+
+    #[test]
+    fn disable_rule_dotted_key() {
+        assert_eq!(
+            disable("[rules]\nerrors.no-empty = {}\n", "no-empty"),
+            "[rules]\nallowed = [\"no-empty\"]\n"
+        );
+    }
+
+    #[test]
+    fn disable_rule_in_inline_table() {
+        assert_eq!(
+            disable("[rules]\nerrors = { no-empty = {} }\n", "no-empty"),
+            "[rules]\nallowed = [\"no-empty\"]\nerrors = { }\n"
+        );
+        assert_eq!(
+            disable(
+                "[rules]\nwarnings = { no-empty = {}, getter-return = {} }\n",
+                "no-empty"
+            ),
+            "[rules]\nallowed = [\"no-empty\"]\nwarnings = { getter-return = {} }\n"
+        );
+        assert_eq!(
+            disable(
+                "[rules]\nerrors = { getter-return = {}, no-empty = { allowEmptyCatch = true } }\n",
+                "no-empty"
+            ),
+            "[rules]\nallowed = [\"no-empty\"]\nerrors = { getter-return = {} }\n"
+        );
+    }
+
+    #[test]
+    fn disable_rule_appends_to_allowed() {
+        assert_eq!(
+            disable("[rules]\nallowed = [\"getter-return\"]\n", "no-empty"),
+            "[rules]\nallowed = [\"getter-return\", \"no-empty\"]\n"
+        );
+        assert_eq!(
+            disable("[rules]\nallowed = [\"no-empty\"]\n", "no-empty"),
+            "[rules]\nallowed = [\"no-empty\"]\n"
+        );
+    }
+
+    #[test]
+    fn disable_rule_creates_config() {
+        assert_eq!(
+            disable("", "no-empty"),
+            "[rules]\nallowed = [\"no-empty\"]\n"
+        );
+    }
+}
@@ -5,13 +5,14 @@ use anyhow::Result;
 use once_cell::sync::Lazy;
 use rslint_core::{
     directives::{get_command_descriptors, CommandDescriptor, Instruction},
+    registry::{registry, RuleMeta},
     util::levenshtein_distance,
-    CstRuleStore, DirectiveErrorKind,
+    DirectiveErrorKind,
 };
 use rslint_parser::{util::*, TextRange, TextSize};
 use tower_lsp::lsp_types::{
-    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, Documentation,
-    MarkupContent, MarkupKind,
+    CompletionItem, CompletionItemKind, CompletionItemTag, CompletionParams, CompletionResponse,
+    Documentation, MarkupContent, MarkupKind,
 };
 
 static DESCRIPTORS: Lazy<Box<[CommandDescriptor]>> = Lazy::new(get_command_descriptors);
@@ -27,7 +28,7 @@ fn command_name_completions() -> CompletionResponse {
                 CompletionItem {
                     detail: Some(x.docs.to_string()),
                     label,
-                    kind: Some(CompletionItemKind::Snippet),
+                    kind: Some(CompletionItemKind::SNIPPET),
                     ..Default::default()
                 }
             })
@@ -60,27 +61,18 @@ pub async fn complete(
             }
             DirectiveErrorKind::InvalidRule => {
                 let wrong_text = &document.file.source[err.range()];
-                let available_rules = CstRuleStore::new().builtins().rules.into_iter();
+                let registry = registry();
+                let mut list = registry.rules().iter().collect::<Vec<_>>();
 
-                let mut list = available_rules
-                    .map(|r| (r.name(), r.docs()))
-                    .collect::<Vec<_>>();
-
-                list.sort_by(|(l_name, _), (r_name, _)| {
-                    levenshtein_distance(wrong_text, l_name)
-                        .cmp(&levenshtein_distance(wrong_text, r_name))
+                list.sort_by(|l_meta, r_meta| {
+                    levenshtein_distance(wrong_text, l_meta.name)
+                        .cmp(&levenshtein_distance(wrong_text, r_meta.name))
                 });
-                completion_list(list, true)
+                completion_list(list)
+            }
+            DirectiveErrorKind::ExpectedNotFound(Instruction::RuleName) => {
+                completion_list(registry().rules().iter().collect())
             }
-            DirectiveErrorKind::ExpectedNotFound(Instruction::RuleName) => completion_list(
-                CstRuleStore::new()
-                    .builtins()
-                    .rules
-                    .into_iter()
-                    .map(|x| (x.name(), x.docs()))
-                    .collect(),
-                true,
-            ),
             _ => return Ok(None),
         }));
     }
@@ -102,39 +94,25 @@ pub async fn complete(
     Ok(None)
 }
 
-fn completion_list(items: Vec<(impl ToString, impl ToString)>, rules: bool) -> CompletionResponse {
-    CompletionResponse::Array(
-        items
-            .into_iter()
-            .map(|(label, detail)| {
-                string_to_completion_item(label.to_string(), detail.to_string(), rules)
-            })
-            .collect(),
-    )
+fn completion_list(rules: Vec<&RuleMeta>) -> CompletionResponse {
+    CompletionResponse::Array(rules.into_iter().map(rule_completion_item).collect())
 }
 
-fn string_to_completion_item(label: String, detail: String, rules: bool) -> CompletionItem {
-    if rules {
-        let mut split = detail.split('\n');
-        let header = split.next().unwrap_or("");
-        let body = split.next().unwrap_or("").to_string();
-        let documentation = Some(Documentation::MarkupContent(MarkupContent {
-            kind: MarkupKind::Markdown,
-            value: body,
-        }));
-        CompletionItem {
-            documentation,
-            detail: Some(header.to_string()),
-            kind: Some(CompletionItemKind::Field),
-            label,
-            ..Default::default()
-        }
-    } else {
-        CompletionItem {
-            label,
-            detail: Some(detail),
-            kind: Some(CompletionItemKind::Field),
-            ..Default::default()
-        }
+fn rule_completion_item(meta: &RuleMeta) -> CompletionItem {
+    let mut split = meta.docs.split('\n');
+    let header = split.next().unwrap_or("");
+    let body = split.next().unwrap_or("").to_string();
+    let documentation = Some(Documentation::MarkupContent(MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: body,
+    }));
+    let tags = meta.deprecated.map(|_| vec![CompletionItemTag::DEPRECATED]);
+    CompletionItem {
+        documentation,
+        detail: Some(header.to_string()),
+        kind: Some(CompletionItemKind::FIELD),
+        label: meta.name.to_string(),
+        tags,
+        ..Default::default()
     }
 }
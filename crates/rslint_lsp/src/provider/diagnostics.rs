@@ -1,29 +1,40 @@
 //! Provider for LSP diagnostics.
 
-use crate::core::{
-    document::{Document, RuleResult},
-    session::Session,
+use crate::{
+    core::{
+        document::{Document, RuleResult},
+        session::Session,
+    },
+    provider::toml_diagnostics,
 };
+use dashmap::mapref::one::RefMut;
 use rslint_core::{
-    apply_top_level_directives, directives::DirectiveResult, run_rule, DirectiveParser,
+    apply_top_level_directives, directives::DirectiveResult, run_rule, DirectiveParser, File,
+    LintCtx, RuleLevel,
+};
+use rslint_errors::{
+    file::SimpleFiles, lsp::convert_to_lsp_diagnostic, Diagnostic as RslintDiagnostic,
+};
+use rslint_parser::SyntaxNode;
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc,
 };
-use rslint_errors::{lsp::convert_to_lsp_diagnostic, Diagnostic as RslintDiagnostic};
-use std::{collections::HashMap, sync::Arc};
 use tower_lsp::lsp_types::*;
 
+static RESULT_ID: AtomicUsize = AtomicUsize::new(0);
+
 fn process_diagnostics(
-    document: &Document,
-    uri: Url,
+    files: &SimpleFiles,
+    file_id: usize,
+    uri: &Url,
     diagnostics: Vec<RslintDiagnostic>,
     out: &mut Vec<Diagnostic>,
 ) {
-    let files = document.files.clone();
-    let file_id = document.file.id;
-
     for diagnostic in diagnostics {
         if let Some(lsp_diag) = convert_to_lsp_diagnostic(
             diagnostic,
-            &files,
+            files,
             file_id,
             uri.clone(),
             Some("rslint".to_string()),
@@ -33,80 +44,196 @@ fn process_diagnostics(
     }
 }
 
-pub async fn publish_diagnostics(session: &Session, uri: Url) -> anyhow::Result<()> {
-    let diags = {
-        let mut document = session.get_mut_document(&uri)?;
-
-        let mut new_store = session.store.clone();
-        let DirectiveResult {
-            directives,
-            diagnostics: mut directive_diagnostics,
-        } = DirectiveParser::new_with_store(document.root.clone(), &document.file, &session.store)
-            .get_file_directives();
-
-        apply_top_level_directives(
-            directives.as_slice(),
-            &mut new_store,
-            &mut directive_diagnostics,
-            document.file.id,
-        );
-
-        let verbose = false;
-        let src = Arc::from(document.file.source.clone());
-        let rule_results: HashMap<&str, rslint_core::RuleResult> = new_store
+/// The parts of a document needed to lint it, cloned so the document is not locked while the
+/// rules run.
+pub(crate) struct Snapshot {
+    file: File,
+    files: SimpleFiles,
+    root: SyntaxNode,
+    parsing_errors: Vec<RslintDiagnostic>,
+    version: Option<i32>,
+}
+
+impl Snapshot {
+    pub(crate) fn new(document: &Document) -> Self {
+        Self {
+            file: document.file.clone(),
+            files: document.files.clone(),
+            root: document.root.clone(),
+            parsing_errors: document.parsing_errors.clone(),
+            version: document.version,
+        }
+    }
+
+    /// Whether the document still has the version and syntax tree the snapshot was taken of.
+    fn is_current(&self, document: &Document) -> bool {
+        document.version == self.version && document.root == self.root
+    }
+}
+
+/// Run the rules on a snapshot of a document, returning its diagnostics and the results of the
+/// rules for code actions, or `None` if linting was cancelled.
+pub(crate) fn lint_snapshot(
+    session: &Session,
+    snapshot: &Snapshot,
+    uri: &Url,
+    cancelled: &AtomicBool,
+) -> Option<(Vec<Diagnostic>, Vec<RuleResult>)> {
+    let mut new_store = session.store.read().unwrap().clone();
+    let DirectiveResult {
+        directives,
+        diagnostics: mut directive_diagnostics,
+    } = DirectiveParser::new_with_store(snapshot.root.clone(), &snapshot.file, &new_store)
+        .get_file_directives();
+
+    apply_top_level_directives(
+        directives.as_slice(),
+        &mut new_store,
+        &mut directive_diagnostics,
+        snapshot.file.id,
+    );
+
+    let ctx = LintCtx {
+        file_id: snapshot.file.id,
+        root: snapshot.root.clone(),
+        src: Arc::from(snapshot.file.source.clone()),
+        directives: &directives,
+        ecma_version: snapshot.file.syntax.ecma_version,
+        verbose: false,
+    };
+    let levels = {
+        let lint_config = session.lint_config.read().unwrap();
+        new_store
             .rules
             .iter()
-            .map(|rule| {
-                (
-                    rule.name(),
-                    run_rule(
-                        &**rule,
-                        document.file.id,
-                        document.root.clone(),
-                        verbose,
-                        &directives,
-                        Arc::clone(&src),
-                    ),
-                )
-            })
-            .collect();
-
-        let mut diags = vec![];
-
-        process_diagnostics(
-            &document,
-            uri.clone(),
-            directive_diagnostics
-                .into_iter()
-                .map(|x| x.diagnostic)
-                .collect(),
-            &mut diags,
-        );
-
-        process_diagnostics(
-            &document,
-            uri.clone(),
-            document.parsing_errors.to_owned(),
-            &mut diags,
-        );
+            .map(|rule| lint_config.rule_level_by_name(rule.name()))
+            .collect::<Vec<_>>()
+    };
 
-        for diagnostics in rule_results.clone().into_iter().map(|(_, r)| r.diagnostics) {
-            process_diagnostics(&document, uri.clone(), diagnostics, &mut diags);
+    let mut rule_results = vec![];
+    for (rule, level) in new_store.rules.iter().zip(levels) {
+        if level == RuleLevel::Off || !rule.runs_on(snapshot.file.kind) {
+            continue;
+        }
+        // a newer version of the document is waiting to be linted
+        if cancelled.load(Ordering::SeqCst) {
+            return None;
         }
+        let mut result = run_rule(&**rule, &ctx);
+        level.remap(&mut result.diagnostics);
+        // hints are shown as faded code by editors
+        if level == RuleLevel::Hint {
+            for diagnostic in result.diagnostics.iter_mut() {
+                *diagnostic = diagnostic.clone().unnecessary();
+            }
+        }
+        rule_results.push(result);
+    }
 
-        document.rule_results = rule_results
+    let (files, file_id) = (&snapshot.files, snapshot.file.id);
+    let mut diags = vec![];
+
+    process_diagnostics(
+        files,
+        file_id,
+        uri,
+        directive_diagnostics
             .into_iter()
-            .map(|(_, v)| v)
-            .map(|res| RuleResult {
-                diagnostics: diags.to_owned(),
+            .map(|x| x.diagnostic)
+            .collect(),
+        &mut diags,
+    );
+
+    process_diagnostics(
+        files,
+        file_id,
+        uri,
+        snapshot.parsing_errors.clone(),
+        &mut diags,
+    );
+
+    // each result keeps only the diagnostics of its own rule, so code actions only offer
+    // the fixes of the rule which reported a diagnostic
+    let rule_results = rule_results
+        .into_iter()
+        .map(|res| {
+            let mut diagnostics = vec![];
+            process_diagnostics(files, file_id, uri, res.diagnostics, &mut diagnostics);
+            diags.extend(diagnostics.iter().cloned());
+            RuleResult {
+                diagnostics,
                 fixer: res.fixer,
-            })
-            .collect();
+            }
+        })
+        .collect();
 
-        diags
+    Some((diags, rule_results))
+}
+
+/// Run the rules on a document which is not shared with the session, storing their results
+/// for code actions.
+pub(crate) fn lint_document(
+    session: &Session,
+    document: &mut Document,
+    uri: &Url,
+) -> Vec<Diagnostic> {
+    let snapshot = Snapshot::new(document);
+    match lint_snapshot(session, &snapshot, uri, &AtomicBool::new(false)) {
+        Some((diags, rule_results)) => {
+            document.rule_results = rule_results;
+            diags
+        }
+        None => vec![],
+    }
+}
+
+/// The diagnostics of a document with the document they were stored in.
+pub(crate) type Lint<'a> = (Vec<Diagnostic>, RefMut<'a, Url, Document>);
+
+/// Lint an open document without locking it while the rules run.
+///
+/// Returns the diagnostics together with the document, whose rule results were updated, or
+/// `None` if linting was cancelled or the document changed while it was linted.
+pub(crate) fn lint<'a>(
+    session: &'a Session,
+    uri: &Url,
+    cancelled: &AtomicBool,
+) -> anyhow::Result<Option<Lint<'a>>> {
+    let snapshot = Snapshot::new(&*session.get_mut_document(uri)?);
+    let (diags, rule_results) = match lint_snapshot(session, &snapshot, uri, cancelled) {
+        Some(lint) => lint,
+        None => return Ok(None),
     };
 
-    let version = Default::default();
+    let mut document = session.get_mut_document(uri)?;
+    // the results of an older version would offer fixes for text which is gone
+    if !snapshot.is_current(&document) {
+        return Ok(None);
+    }
+    document.rule_results = rule_results;
+    Ok(Some((diags, document)))
+}
+
+pub async fn publish_diagnostics(session: &Session, uri: Url) -> anyhow::Result<()> {
+    let (diags, version) = match lint(session, &uri, &AtomicBool::new(false))? {
+        Some((diags, document)) => (diags, document.version),
+        None => return Ok(()),
+    };
+    publish(session, uri, diags, version).await
+}
+
+/// Publish the diagnostics of a version of a document.
+pub(crate) async fn publish(
+    session: &Session,
+    uri: Url,
+    diags: Vec<Diagnostic>,
+    version: Option<i32>,
+) -> anyhow::Result<()> {
+    // the document changed while it was being linted, the lint job of the new version publishes instead
+    if session.get_document(&uri).await?.version != version {
+        return Ok(());
+    }
+
     session
         .client()?
         .publish_diagnostics(uri, diags, version)
@@ -114,3 +241,115 @@ pub async fn publish_diagnostics(session: &Session, uri: Url) -> anyhow::Result<
 
     Ok(())
 }
+
+/// Handle a `textDocument/diagnostic` request, relinting the document only if it changed since
+/// the diagnostics the client already has.
+pub async fn pull_diagnostics(
+    session: &Session,
+    params: DocumentDiagnosticParams,
+) -> anyhow::Result<DocumentDiagnosticReportResult> {
+    let uri = params.text_document.uri;
+    let document = match session.get_mut_document(&uri) {
+        Ok(document) => document,
+        // rslint config files are validated instead of linted, other documents are ignored
+        Err(_) => {
+            let config_doc = session.config_doc.read().unwrap().clone();
+            let items = config_doc
+                .filter(|doc| doc.uri == uri)
+                .map(|doc| toml_diagnostics::toml_diagnostics(&doc))
+                .unwrap_or_default();
+            return Ok(full_report(None, items));
+        }
+    };
+
+    if let Some(result_id) = document.diagnostics_result_id.clone() {
+        if params.previous_result_id.as_ref() == Some(&result_id) {
+            let report = RelatedUnchangedDocumentDiagnosticReport {
+                related_documents: None,
+                unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                    result_id,
+                },
+            };
+            return Ok(DocumentDiagnosticReport::Unchanged(report).into());
+        }
+    }
+    drop(document);
+
+    // the document may change while it is linted, in which case its newer version is linted
+    let cancelled = AtomicBool::new(false);
+    let (items, mut document) = loop {
+        if let Some(lint) = lint(session, &uri, &cancelled)? {
+            break lint;
+        }
+    };
+    let result_id = RESULT_ID.fetch_add(1, Ordering::SeqCst).to_string();
+    document.diagnostics_result_id = Some(result_id.clone());
+
+    Ok(full_report(Some(result_id), items))
+}
+
+fn full_report(
+    result_id: Option<String>,
+    items: Vec<Diagnostic>,
+) -> DocumentDiagnosticReportResult {
+    let report = RelatedFullDocumentDiagnosticReport {
+        related_documents: None,
+        full_document_diagnostic_report: FullDocumentDiagnosticReport { result_id, items },
+    };
+    DocumentDiagnosticReport::Full(report).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_with(text: &str) -> (Session, Url) {
+        let session = Session::new(None).unwrap();
+        let uri = Url::parse("file:///project/a.js").unwrap();
+        let mut document = {
+            let config = session.lint_config.read().unwrap();
+            Document::new(uri.clone(), "javascript".into(), text.into(), &config).unwrap()
+        };
+        document.version = Some(1);
+        session.insert_document(uri.clone(), document).unwrap();
+        (session, uri)
+    }
+
+    #[test]
+    fn lint_stores_rule_results() {
+        let (session, uri) = session_with("if (a) {}");
+        let (diags, document) = lint(&session, &uri, &AtomicBool::new(false))
+            .unwrap()
+            .expect("the lint was not cancelled");
+        assert!(diags.iter().any(|d| d.message.contains("empty")));
+        assert!(!document.rule_results.is_empty());
+    }
+
+    #[test]
+    fn cancelled_lint_keeps_the_document() {
+        let (session, uri) = session_with("if (a) {}");
+        assert!(lint(&session, &uri, &AtomicBool::new(true))
+            .unwrap()
+            .is_none());
+        assert!(session
+            .get_mut_document(&uri)
+            .unwrap()
+            .rule_results
+            .is_empty());
+    }
+
+    #[test]
+    fn snapshots_of_older_versions_are_outdated() {
+        let (session, uri) = session_with("if (a) {}");
+        let mut document = session.get_mut_document(&uri).unwrap();
+        let snapshot = Snapshot::new(&document);
+        assert!(snapshot.is_current(&document));
+
+        document.version = Some(2);
+        assert!(!snapshot.is_current(&document));
+
+        let snapshot = Snapshot::new(&document);
+        document.reparse();
+        assert!(!snapshot.is_current(&document));
+    }
+}
@@ -0,0 +1,380 @@
+//! Document symbol provider, building the outline of a document from its syntax tree.
+
+use crate::core::{document::Document, session::Session};
+use anyhow::Result;
+use rslint_parser::{
+    ast::{
+        ArrowExpr, ClassDecl, ClassProp, Constructor, Declarator, FnDecl, FnExpr, Getter, Method,
+        Pattern, PrivateProp, Setter, TsEnumMember, TsMethodSignature, VarDecl,
+    },
+    AstNode,
+    SyntaxKind::*,
+    SyntaxNode, SyntaxNodeExt, TextRange,
+};
+use tower_lsp::lsp_types::{
+    DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, Range, SymbolKind,
+};
+
+pub async fn document_symbols(
+    session: &Session,
+    params: DocumentSymbolParams,
+) -> Result<Option<DocumentSymbolResponse>> {
+    let document = session.get_document(&params.text_document.uri).await?;
+    let symbols = SymbolCollector {
+        document: &document,
+    }
+    .symbols(&document.root);
+
+    Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+}
+
+struct SymbolCollector<'a> {
+    document: &'a Document,
+}
+
+impl SymbolCollector<'_> {
+    /// Collect the symbols declared in the children of a node, searching through nodes which
+    /// do not declare anything themselves.
+    fn symbols(&self, node: &SyntaxNode) -> Vec<DocumentSymbol> {
+        let mut symbols = vec![];
+        for child in node.children() {
+            match self.symbol(&child) {
+                Some(symbol) => symbols.push(symbol),
+                None => symbols.extend(self.symbols(&child)),
+            }
+        }
+        symbols
+    }
+
+    /// Build the symbol for a node if it declares one.
+    fn symbol(&self, node: &SyntaxNode) -> Option<DocumentSymbol> {
+        match node.kind() {
+            FN_DECL => {
+                let decl = node.to::<FnDecl>();
+                self.build(
+                    node,
+                    decl.name()?.syntax(),
+                    SymbolKind::FUNCTION,
+                    decl.parameters().map(|params| params.syntax().clone()),
+                )
+            }
+            CLASS_DECL => self.build(
+                node,
+                node.to::<ClassDecl>().name()?.syntax(),
+                SymbolKind::CLASS,
+                None,
+            ),
+            METHOD => {
+                let method = node.to::<Method>();
+                self.build(
+                    node,
+                    method.name()?.syntax(),
+                    SymbolKind::METHOD,
+                    method.parameters().map(|params| params.syntax().clone()),
+                )
+            }
+            CONSTRUCTOR => {
+                let constructor = node.to::<Constructor>();
+                self.build(
+                    node,
+                    constructor.name()?.syntax(),
+                    SymbolKind::CONSTRUCTOR,
+                    constructor
+                        .parameters()
+                        .map(|params| params.syntax().clone()),
+                )
+            }
+            GETTER => self.build(
+                node,
+                node.to::<Getter>().key()?.syntax(),
+                SymbolKind::PROPERTY,
+                None,
+            ),
+            SETTER => self.build(
+                node,
+                node.to::<Setter>().key()?.syntax(),
+                SymbolKind::PROPERTY,
+                None,
+            ),
+            CLASS_PROP => self.build(
+                node,
+                node.to::<ClassProp>().key()?.syntax(),
+                SymbolKind::FIELD,
+                None,
+            ),
+            PRIVATE_PROP => self.build(
+                node,
+                node.to::<PrivateProp>().key()?.syntax(),
+                SymbolKind::FIELD,
+                None,
+            ),
+            TS_INTERFACE_DECL => {
+                self.build(node, &declared_name(node)?, SymbolKind::INTERFACE, None)
+            }
+            TS_PROPERTY_SIGNATURE => {
+                self.build(node, &node.first_child()?, SymbolKind::PROPERTY, None)
+            }
+            TS_METHOD_SIGNATURE => {
+                let signature = node.to::<TsMethodSignature>();
+                self.build(
+                    node,
+                    &node.first_child()?,
+                    SymbolKind::METHOD,
+                    signature.parameters().map(|params| params.syntax().clone()),
+                )
+            }
+            TS_ENUM => self.build(node, &declared_name(node)?, SymbolKind::ENUM, None),
+            TS_ENUM_MEMBER => {
+                let name = node.to::<TsEnumMember>().ident_token()?;
+                Some(self.symbol_with_range(
+                    node,
+                    name.text().to_string(),
+                    name.text_range(),
+                    SymbolKind::ENUM_MEMBER,
+                    None,
+                ))
+            }
+            TS_NAMESPACE_DECL => {
+                self.build(node, &declared_name(node)?, SymbolKind::NAMESPACE, None)
+            }
+            TS_MODULE_DECL => {
+                if let Some(name) = declared_name(node) {
+                    return self.build(node, &name, SymbolKind::MODULE, None);
+                }
+                // `declare module "foo" {}` and `declare global {}`
+                let name = node
+                    .children_with_tokens()
+                    .filter_map(|elem| elem.into_token())
+                    .find(|tok| tok.kind() == STRING || tok.text() == "global")?;
+                Some(self.symbol_with_range(
+                    node,
+                    name.text().to_string(),
+                    name.text_range(),
+                    SymbolKind::MODULE,
+                    None,
+                ))
+            }
+            DECLARATOR => self.declarator(node),
+            _ => None,
+        }
+    }
+
+    /// Build the symbol of a variable declarator.
+    ///
+    /// Declarators initialized with a function or a class are shown as such wherever they are,
+    /// other variables are only shown if they are declared at the top level of a module or namespace.
+    fn declarator(&self, node: &SyntaxNode) -> Option<DocumentSymbol> {
+        let declarator = node.to::<Declarator>();
+        let name = match declarator.pattern()? {
+            Pattern::SinglePattern(pat) => pat.name()?,
+            _ => return None,
+        };
+
+        let (kind, detail) = match declarator.value().as_ref().map(|value| value.syntax()) {
+            Some(value) if value.kind() == ARROW_EXPR => (
+                SymbolKind::FUNCTION,
+                value
+                    .to::<ArrowExpr>()
+                    .params()
+                    .map(|params| params.syntax().clone()),
+            ),
+            Some(value) if value.kind() == FN_EXPR => (
+                SymbolKind::FUNCTION,
+                value
+                    .to::<FnExpr>()
+                    .parameters()
+                    .map(|params| params.syntax().clone()),
+            ),
+            Some(value) if value.kind() == CLASS_EXPR => (SymbolKind::CLASS, None),
+            _ => {
+                let decl = node.parent()?.try_to::<VarDecl>()?;
+                let parent = decl
+                    .syntax()
+                    .ancestors()
+                    .skip(1)
+                    .find(|ancestor| ancestor.kind() != EXPORT_DECL)?;
+                if !matches!(parent.kind(), SCRIPT | MODULE | TS_MODULE_BLOCK) {
+                    return None;
+                }
+
+                let kind = if decl.is_const() {
+                    SymbolKind::CONSTANT
+                } else {
+                    SymbolKind::VARIABLE
+                };
+                (kind, None)
+            }
+        };
+
+        self.build(node, name.syntax(), kind, detail)
+    }
+
+    fn build(
+        &self,
+        node: &SyntaxNode,
+        name: &SyntaxNode,
+        kind: SymbolKind,
+        detail: Option<SyntaxNode>,
+    ) -> Option<DocumentSymbol> {
+        Some(self.symbol_with_range(
+            node,
+            name.trimmed_text().to_string(),
+            name.trimmed_range(),
+            kind,
+            detail,
+        ))
+    }
+
+    fn symbol_with_range(
+        &self,
+        node: &SyntaxNode,
+        name: String,
+        selection_range: TextRange,
+        kind: SymbolKind,
+        detail: Option<SyntaxNode>,
+    ) -> DocumentSymbol {
+        let children = self.symbols(node);
+
+        #[allow(deprecated)]
+        DocumentSymbol {
+            name,
+            detail: detail.map(|detail| detail.trimmed_text().to_string()),
+            kind,
+            tags: None,
+            deprecated: None,
+            range: self.range(node.trimmed_range()),
+            selection_range: self.range(selection_range),
+            children: if children.is_empty() {
+                None
+            } else {
+                Some(children)
+            },
+        }
+    }
+
+    fn range(&self, range: TextRange) -> Range {
+        rslint_errors::lsp::byte_span_to_range(
+            &self.document.files,
+            self.document.file.id,
+            range.into(),
+        )
+        .unwrap_or_default()
+    }
+}
+
+/// The name of a TypeScript declaration, which is not exposed through the typed AST.
+fn declared_name(node: &SyntaxNode) -> Option<SyntaxNode> {
+    node.children().find(|child| child.kind() == NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rslint_config::Config;
+    use tower_lsp::lsp_types::{Position, Url};
+
+    fn symbols(path: &str, text: &str) -> Vec<DocumentSymbol> {
+        let uri = Url::parse(&format!("file:///{}", path)).unwrap();
+        let language_id = if path.ends_with(".ts") {
+            "typescript"
+        } else {
+            "javascript"
+        };
+        let document =
+            Document::new(uri, language_id.into(), text.into(), &Config::default()).unwrap();
+        SymbolCollector {
+            document: &document,
+        }
+        .symbols(&document.root)
+    }
+
+    fn names(symbols: &[DocumentSymbol]) -> Vec<(&str, SymbolKind)> {
+        symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind))
+            .collect()
+    }
+
+    #[test]
+    fn names_exclude_trivia() {
+        let symbols = symbols(
+            "a.js",
+            "function /* a */ foo /* b */ (a, b) {}\nclass  Bar  {}",
+        );
+        assert_eq!(
+            names(&symbols),
+            vec![("foo", SymbolKind::FUNCTION), ("Bar", SymbolKind::CLASS)]
+        );
+        assert_eq!(symbols[0].detail.as_deref(), Some("(a, b)"));
+        assert_eq!(
+            symbols[0].selection_range,
+            Range::new(Position::new(0, 17), Position::new(0, 20))
+        );
+    }
+
+    #[test]
+    fn class_members_are_children() {
+        let symbols = symbols(
+            "a.js",
+            "class A {\n  constructor() {}\n  get b() {}\n  c = 1;\n  d() {}\n}",
+        );
+        let children = symbols[0].children.as_deref().unwrap();
+        assert_eq!(
+            names(children),
+            vec![
+                ("constructor", SymbolKind::CONSTRUCTOR),
+                ("b", SymbolKind::PROPERTY),
+                ("c", SymbolKind::FIELD),
+                ("d", SymbolKind::METHOD),
+            ]
+        );
+    }
+
+    #[test]
+    fn only_top_level_variables() {
+        let symbols = symbols(
+            "a.js",
+            "const a = 1;\nlet b = () => {};\nfunction f() { let c = 2; const d = function() {}; }",
+        );
+        assert_eq!(
+            names(&symbols),
+            vec![
+                ("a", SymbolKind::CONSTANT),
+                ("b", SymbolKind::FUNCTION),
+                ("f", SymbolKind::FUNCTION),
+            ]
+        );
+        assert_eq!(
+            names(symbols[2].children.as_deref().unwrap()),
+            vec![("d", SymbolKind::FUNCTION)]
+        );
+    }
+
+    #[test]
+    fn typescript_declarations() {
+        let symbols = symbols(
+            "a.ts",
+            "interface I { a: string; b(): void }\nenum E { A }\nnamespace N { export const x = 1 }",
+        );
+        assert_eq!(
+            names(&symbols),
+            vec![
+                ("I", SymbolKind::INTERFACE),
+                ("E", SymbolKind::ENUM),
+                ("N", SymbolKind::NAMESPACE),
+            ]
+        );
+        assert_eq!(
+            names(symbols[0].children.as_deref().unwrap()),
+            vec![("a", SymbolKind::PROPERTY), ("b", SymbolKind::METHOD)]
+        );
+        assert_eq!(
+            names(symbols[1].children.as_deref().unwrap()),
+            vec![("A", SymbolKind::ENUM_MEMBER)]
+        );
+        assert_eq!(
+            names(symbols[2].children.as_deref().unwrap()),
+            vec![("x", SymbolKind::CONSTANT)]
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Folding range provider, folding blocks, comments and imports of a document.
+
+use crate::core::{document::Document, session::Session};
+use anyhow::Result;
+use rslint_errors::file::Files;
+use rslint_parser::{ast::ImportDecl, AstNode, SyntaxKind::*, SyntaxNode, SyntaxToken, TextSize};
+use tower_lsp::lsp_types::{FoldingRange, FoldingRangeKind, FoldingRangeParams};
+
+/// Nodes which are folded from their opening to their closing delimiter.
+const DELIMITED: [rslint_parser::SyntaxKind; 16] = [
+    BLOCK_STMT,
+    CLASS_BODY,
+    OBJECT_EXPR,
+    ARRAY_EXPR,
+    OBJECT_PATTERN,
+    ARRAY_PATTERN,
+    SWITCH_STMT,
+    NAMED_IMPORTS,
+    EXPORT_NAMED,
+    ARG_LIST,
+    PARAMETER_LIST,
+    TS_MODULE_BLOCK,
+    TS_INTERFACE_DECL,
+    TS_ENUM,
+    TS_OBJECT_TYPE,
+    TS_MAPPED_TYPE,
+];
+
+pub async fn folding_ranges(
+    session: &Session,
+    params: FoldingRangeParams,
+) -> Result<Option<Vec<FoldingRange>>> {
+    let document = session.get_document(&params.text_document.uri).await?;
+    let mut folder = Folder {
+        document: &document,
+        ranges: vec![],
+    };
+
+    for node in document.root.descendants() {
+        if DELIMITED.contains(&node.kind()) {
+            folder.delimited(&node);
+        }
+    }
+    folder.comments();
+    folder.imports();
+
+    let mut ranges = folder.ranges;
+    ranges.sort_by_key(|range| (range.start_line, range.end_line));
+    Ok(Some(ranges))
+}
+
+struct Folder<'a> {
+    document: &'a Document,
+    ranges: Vec<FoldingRange>,
+}
+
+impl Folder<'_> {
+    fn line(&self, offset: TextSize) -> u32 {
+        self.document
+            .files
+            .line_index(self.document.file.id, offset.into())
+            .unwrap_or_default() as u32
+    }
+
+    fn push(&mut self, start_line: u32, end_line: u32, kind: Option<FoldingRangeKind>) {
+        if end_line > start_line {
+            self.ranges.push(FoldingRange {
+                start_line,
+                start_character: None,
+                end_line,
+                end_character: None,
+                kind,
+                collapsed_text: None,
+            });
+        }
+    }
+
+    /// Fold the contents of a node between its delimiters, leaving the closing delimiter visible
+    /// so `{ ... }` folds into a single line.
+    fn delimited(&mut self, node: &SyntaxNode) {
+        let mut delimiters = node
+            .children_with_tokens()
+            .filter_map(|elem| elem.into_token())
+            .filter(|tok| {
+                matches!(
+                    tok.kind(),
+                    L_CURLY | R_CURLY | L_BRACK | R_BRACK | L_PAREN | R_PAREN
+                )
+            });
+
+        let open = delimiters.next();
+        let close = delimiters.last();
+        if let (Some(open), Some(close)) = (open, close) {
+            let start = self.line(open.text_range().start());
+            let end = self.line(close.text_range().start());
+            self.push(start, end.saturating_sub(1), None);
+        }
+    }
+
+    /// Fold multiline comments, runs of line comments, and `#region` / `#endregion` markers.
+    fn comments(&mut self) {
+        let comments = self
+            .document
+            .root
+            .descendants_with_tokens()
+            .filter_map(|elem| elem.into_token())
+            .filter(|tok| tok.kind() == COMMENT);
+
+        let mut run: Option<(SyntaxToken, SyntaxToken)> = None;
+        let mut regions = vec![];
+
+        for comment in comments {
+            let text = comment.text().trim_start_matches(&['/', '*'][..]).trim();
+            let start = self.line(comment.text_range().start());
+
+            if text.starts_with("#region") {
+                regions.push(start);
+            } else if text.starts_with("#endregion") {
+                if let Some(region_start) = regions.pop() {
+                    self.push(region_start, start, Some(FoldingRangeKind::Region));
+                }
+            }
+
+            if !comment.text().starts_with("//") {
+                let end = self.line(comment.text_range().end());
+                self.push(start, end, Some(FoldingRangeKind::Comment));
+                continue;
+            }
+
+            run = match run {
+                Some((first, last)) if self.line(last.text_range().start()) + 1 == start => {
+                    Some((first, comment))
+                }
+                Some((first, last)) => {
+                    self.line_comments(&first, &last);
+                    Some((comment.clone(), comment))
+                }
+                None => Some((comment.clone(), comment)),
+            };
+        }
+
+        if let Some((first, last)) = run {
+            self.line_comments(&first, &last);
+        }
+    }
+
+    fn line_comments(&mut self, first: &SyntaxToken, last: &SyntaxToken) {
+        let start = self.line(first.text_range().start());
+        let end = self.line(last.text_range().start());
+        self.push(start, end, Some(FoldingRangeKind::Comment));
+    }
+
+    /// Fold groups of consecutive import declarations.
+    fn imports(&mut self) {
+        let mut group: Option<(ImportDecl, ImportDecl)> = None;
+        let items = self.document.root.children().map(ImportDecl::cast);
+
+        for import in items.chain(std::iter::once(None)) {
+            group = match (group, import) {
+                (Some((first, _)), Some(import)) => Some((first, import)),
+                (None, Some(import)) => Some((import.clone(), import)),
+                (Some((first, last)), None) => {
+                    let start = self.line(first.syntax().text_range().start());
+                    let end = self.line(last.syntax().text_range().end());
+                    self.push(start, end, Some(FoldingRangeKind::Imports));
+                    None
+                }
+                (None, None) => None,
+            };
+        }
+    }
+}
@@ -14,3 +14,21 @@ pub(crate) mod actions;
 
 // Provider for autocomplete for rslint config toml files
 pub(crate) mod toml_completion;
+
+// Provider for diagnostics of rslint config toml files.
+pub(crate) mod toml_diagnostics;
+
+// Provider for LSP semantic tokens.
+pub(crate) mod semantic_tokens;
+
+// Provider for LSP document symbols.
+pub(crate) mod document_symbols;
+
+// Provider for LSP folding ranges.
+pub(crate) mod folding_ranges;
+
+// Provider for LSP selection ranges.
+pub(crate) mod selection_ranges;
+
+// Provider for LSP commands.
+pub(crate) mod commands;
@@ -0,0 +1,58 @@
+//! Selection range provider, expanding a selection through the enclosing nodes of a position.
+
+use crate::core::session::Session;
+use anyhow::Result;
+use rslint_parser::{SyntaxNodeExt, TextRange, TextSize};
+use tower_lsp::lsp_types::{SelectionRange, SelectionRangeParams};
+
+pub async fn selection_ranges(
+    session: &Session,
+    params: SelectionRangeParams,
+) -> Result<Option<Vec<SelectionRange>>> {
+    let document = session.get_document(&params.text_document.uri).await?;
+    let (files, file_id) = (&document.files, document.file.id);
+
+    let mut selections = Vec::with_capacity(params.positions.len());
+    for position in params.positions {
+        let offset = rslint_errors::lsp::position_to_byte_index(files, file_id, &position)?;
+        let offset = TextSize::from(offset as u32);
+
+        // prefer the token on the right unless it is trivia, e.g. the cursor being right after an identifier
+        let tokens = document.root.token_at_offset(offset);
+        let token = match (tokens.clone().left_biased(), tokens.right_biased()) {
+            (Some(left), Some(right)) if right.kind().is_trivia() => Some(left),
+            (left, right) => right.or(left),
+        };
+
+        let mut ranges: Vec<TextRange> = vec![];
+        if let Some(token) = token {
+            if !token.kind().is_trivia() {
+                ranges.push(token.text_range());
+            }
+            for node in token.parent().ancestors() {
+                let range = node.trimmed_range();
+                let grows = match ranges.last() {
+                    Some(last) => last != &range && range.contains_range(*last),
+                    None => true,
+                };
+                if grows {
+                    ranges.push(range);
+                }
+            }
+        }
+        if ranges.is_empty() {
+            ranges.push(TextRange::empty(offset));
+        }
+
+        let mut selection = None;
+        for range in ranges.into_iter().rev() {
+            selection = Some(SelectionRange {
+                range: rslint_errors::lsp::byte_span_to_range(files, file_id, range.into())?,
+                parent: selection.map(Box::new),
+            });
+        }
+        selections.push(selection.unwrap());
+    }
+
+    Ok(Some(selections))
+}
@@ -0,0 +1,543 @@
+//! Semantic token provider, classifying the tokens of a document using its syntax tree.
+
+use crate::core::{document::Document, session::Session};
+use anyhow::Result;
+use rslint_errors::{file::Files, lsp::byte_index_to_position};
+use rslint_parser::{ast::VarDecl, SyntaxKind, SyntaxNode, SyntaxNodeExt, SyntaxToken, TextRange};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tower_lsp::lsp_types::*;
+use SyntaxKind::*;
+
+static RESULT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The token types reported by the server, in the order of [`TokenType`].
+const TOKEN_TYPES: [SemanticTokenType; 18] = [
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::ENUM,
+    SemanticTokenType::INTERFACE,
+    SemanticTokenType::TYPE_PARAMETER,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::METHOD,
+    SemanticTokenType::KEYWORD,
+    SemanticTokenType::COMMENT,
+    SemanticTokenType::STRING,
+    SemanticTokenType::NUMBER,
+    SemanticTokenType::REGEXP,
+    // rslint directive comments are reported as macros so editors can set them apart from comments.
+    SemanticTokenType::MACRO,
+];
+
+/// The token modifiers reported by the server, in the order of their bits.
+const TOKEN_MODIFIERS: [SemanticTokenModifier; 5] = [
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::STATIC,
+    SemanticTokenModifier::ASYNC,
+    SemanticTokenModifier::DEFAULT_LIBRARY,
+];
+
+const DECLARATION: u32 = 1 << 0;
+const READONLY: u32 = 1 << 1;
+const STATIC: u32 = 1 << 2;
+const ASYNC: u32 = 1 << 3;
+const DEFAULT_LIBRARY: u32 = 1 << 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenType {
+    Namespace,
+    Type,
+    Class,
+    Enum,
+    Interface,
+    TypeParameter,
+    Parameter,
+    Variable,
+    Property,
+    EnumMember,
+    Function,
+    Method,
+    Keyword,
+    Comment,
+    String,
+    Number,
+    Regexp,
+    Directive,
+}
+
+/// Contextual keywords which are lexed as identifiers.
+const CONTEXTUAL_KEYWORDS: [&str; 12] = [
+    "let",
+    "of",
+    "get",
+    "set",
+    "static",
+    "async",
+    "type",
+    "interface",
+    "namespace",
+    "module",
+    "declare",
+    "abstract",
+];
+
+/// The legend describing the token types and modifiers used by the server.
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+pub async fn full(
+    session: &Session,
+    params: SemanticTokensParams,
+) -> Result<Option<SemanticTokensResult>> {
+    let mut document = session.get_mut_document(&params.text_document.uri)?;
+    let tokens = semantic_tokens(&document, None);
+    document.semantic_tokens = Some(tokens.clone());
+
+    Ok(Some(tokens.into()))
+}
+
+pub async fn full_delta(
+    session: &Session,
+    params: SemanticTokensDeltaParams,
+) -> Result<Option<SemanticTokensFullDeltaResult>> {
+    let mut document = session.get_mut_document(&params.text_document.uri)?;
+    let tokens = semantic_tokens(&document, None);
+    let previous = document.semantic_tokens.replace(tokens.clone());
+
+    match previous {
+        Some(previous) if previous.result_id.as_ref() == Some(&params.previous_result_id) => {
+            Ok(Some(
+                SemanticTokensDelta {
+                    edits: token_edits(&previous.data, &tokens.data),
+                    result_id: tokens.result_id,
+                }
+                .into(),
+            ))
+        }
+        _ => Ok(Some(tokens.into())),
+    }
+}
+
+pub async fn range(
+    session: &Session,
+    params: SemanticTokensRangeParams,
+) -> Result<Option<SemanticTokensRangeResult>> {
+    let document = session.get_document(&params.text_document.uri).await?;
+    let range =
+        rslint_errors::lsp::range_to_byte_span(&document.files, document.file.id, &params.range)?;
+    let range = TextRange::new((range.start as u32).into(), (range.end as u32).into());
+
+    let mut tokens = semantic_tokens(&document, Some(range));
+    tokens.result_id = None;
+    Ok(Some(tokens.into()))
+}
+
+/// Compute the semantic tokens of a document, optionally only the ones intersecting a range.
+fn semantic_tokens(document: &Document, range: Option<TextRange>) -> SemanticTokens {
+    let directives = document
+        .directives
+        .iter()
+        .map(|directive| directive.comment.token.text_range())
+        .collect::<Vec<_>>();
+
+    let mut builder = TokensBuilder::new(document);
+    for token in document
+        .root
+        .descendants_with_tokens()
+        .filter_map(|elem| elem.into_token())
+    {
+        if matches!(range, Some(range) if range.intersect(token.text_range()).is_none()) {
+            continue;
+        }
+
+        let classified = if directives.contains(&token.text_range()) {
+            Some((TokenType::Directive, 0))
+        } else {
+            classify(&token)
+        };
+
+        if let Some((ty, modifiers)) = classified {
+            builder.push(token.text_range(), ty, modifiers);
+        }
+    }
+
+    SemanticTokens {
+        result_id: Some(RESULT_ID.fetch_add(1, Ordering::SeqCst).to_string()),
+        data: builder.data,
+    }
+}
+
+/// Compute the edits which turn the previous tokens into the new tokens.
+///
+/// The tokens only change around the place the user is editing, so a single edit which replaces
+/// everything between the common prefix and suffix is sent.
+fn token_edits(previous: &[SemanticToken], new: &[SemanticToken]) -> Vec<SemanticTokensEdit> {
+    let prefix = previous.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let deleted = previous.len() - prefix - suffix;
+    let inserted = &new[prefix..new.len() - suffix];
+    if deleted == 0 && inserted.is_empty() {
+        return vec![];
+    }
+
+    // edit offsets count the integers of the flattened token array, which are 5 per token
+    vec![SemanticTokensEdit {
+        start: (prefix * 5) as u32,
+        delete_count: (deleted * 5) as u32,
+        data: Some(inserted.to_vec()),
+    }]
+}
+
+/// A builder encoding tokens relative to each other, splitting tokens which span multiple lines.
+struct TokensBuilder<'a> {
+    document: &'a Document,
+    data: Vec<SemanticToken>,
+    prev_line: u32,
+    prev_start: u32,
+}
+
+impl<'a> TokensBuilder<'a> {
+    fn new(document: &'a Document) -> Self {
+        Self {
+            document,
+            data: vec![],
+            prev_line: 0,
+            prev_start: 0,
+        }
+    }
+
+    fn push(&mut self, range: TextRange, ty: TokenType, modifiers: u32) {
+        let files = &self.document.files;
+        let file_id = self.document.file.id;
+        let source = &self.document.file.source;
+        let (start, end) = (usize::from(range.start()), usize::from(range.end()));
+
+        let (first_line, last_line) = match (
+            files.line_index(file_id, start),
+            files.line_index(file_id, end),
+        ) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return,
+        };
+
+        for line in first_line..=last_line {
+            let line_range = match files.line_range(file_id, line) {
+                Some(line_range) => line_range,
+                None => return,
+            };
+            let text_start = start.max(line_range.start);
+            let text_end = end.min(line_range.end);
+            let text = source[text_start..text_end].trim_end_matches(&['\n', '\r'][..]);
+            if text.is_empty() {
+                continue;
+            }
+
+            let (start, end) = match (
+                byte_index_to_position(files, file_id, text_start),
+                byte_index_to_position(files, file_id, text_start + text.len()),
+            ) {
+                (Ok(start), Ok(end)) => (start, end),
+                _ => return,
+            };
+            let (line, start) = (start.line, start.character);
+            let delta_line = line - self.prev_line;
+            let delta_start = if delta_line == 0 {
+                start - self.prev_start
+            } else {
+                start
+            };
+
+            self.data.push(SemanticToken {
+                delta_line,
+                delta_start,
+                length: end.character - start,
+                token_type: ty as u32,
+                token_modifiers_bitset: modifiers,
+            });
+            self.prev_line = line;
+            self.prev_start = start;
+        }
+    }
+}
+
+/// Classify a single token, returning its type and modifiers.
+fn classify(token: &SyntaxToken) -> Option<(TokenType, u32)> {
+    Some(match token.kind() {
+        COMMENT => (TokenType::Comment, 0),
+        STRING | TEMPLATE_CHUNK | BACKTICK => (TokenType::String, 0),
+        NUMBER => (TokenType::Number, 0),
+        REGEX => (TokenType::Regexp, 0),
+        IDENT => return classify_ident(token),
+        kind if kind.is_keyword() => (TokenType::Keyword, 0),
+        _ => return None,
+    })
+}
+
+fn classify_ident(token: &SyntaxToken) -> Option<(TokenType, u32)> {
+    let parent = token.parent();
+    match parent.kind() {
+        NAME => classify_name(&parent),
+        NAME_REF => {
+            let ty = match parent.parent().map(|p| p.kind()) {
+                Some(CALL_EXPR) | Some(NEW_EXPR) => TokenType::Function,
+                Some(CLASS_DECL) | Some(CLASS_EXPR) => TokenType::Class,
+                _ => TokenType::Variable,
+            };
+            Some((ty, 0))
+        }
+        TS_TYPE_NAME => Some((TokenType::Type, 0)),
+        TS_TYPE_PARAM => Some((TokenType::TypeParameter, DECLARATION)),
+        TS_ENUM_MEMBER => Some((TokenType::EnumMember, DECLARATION | READONLY)),
+        TS_ANY | TS_UNKNOWN | TS_NUMBER | TS_OBJECT | TS_BOOLEAN | TS_BIGINT | TS_STRING
+        | TS_SYMBOL | TS_UNDEFINED | TS_NEVER => Some((TokenType::Type, DEFAULT_LIBRARY)),
+        _ if CONTEXTUAL_KEYWORDS.contains(&token.text().as_str()) => Some((TokenType::Keyword, 0)),
+        _ => None,
+    }
+}
+
+fn classify_name(name: &SyntaxNode) -> Option<(TokenType, u32)> {
+    let parent = name.parent()?;
+    let modifiers = DECLARATION | node_modifiers(&parent);
+    let ty = match parent.kind() {
+        FN_DECL | FN_EXPR => TokenType::Function,
+        CLASS_DECL | CLASS_EXPR => TokenType::Class,
+        METHOD | GETTER | SETTER | TS_METHOD_SIGNATURE => TokenType::Method,
+        CLASS_PROP | LITERAL_PROP | TS_PROPERTY_SIGNATURE | PRIVATE_NAME => TokenType::Property,
+        TS_INTERFACE_DECL => TokenType::Interface,
+        TS_ENUM => TokenType::Enum,
+        TS_TYPE_ALIAS_DECL => TokenType::Type,
+        TS_NAMESPACE_DECL | TS_MODULE_DECL => TokenType::Namespace,
+        // `a.b`, `a?.b`, and shorthand properties are uses, not declarations
+        DOT_EXPR | PRIVATE_PROP_ACCESS => return Some((TokenType::Property, 0)),
+        IDENT_PROP => return Some((TokenType::Variable, 0)),
+        SPECIFIER => return Some((TokenType::Variable, 0)),
+        SINGLE_PATTERN => return Some(classify_binding(&parent)),
+        _ => return None,
+    };
+    Some((ty, modifiers))
+}
+
+/// Classify a name bound by a pattern as either a parameter or a variable.
+fn classify_binding(pattern: &SyntaxNode) -> (TokenType, u32) {
+    let owner = pattern.ancestors().find(|node| {
+        !matches!(
+            node.kind(),
+            SINGLE_PATTERN
+                | OBJECT_PATTERN
+                | ARRAY_PATTERN
+                | REST_PATTERN
+                | ASSIGN_PATTERN
+                | KEY_VALUE_PATTERN
+                | TS_CONSTRUCTOR_PARAM
+        )
+    });
+
+    match owner.as_ref().map(|node| node.kind()) {
+        Some(PARAMETER_LIST)
+        | Some(CONSTRUCTOR_PARAMETERS)
+        | Some(ARROW_EXPR)
+        | Some(CATCH_CLAUSE) => (TokenType::Parameter, DECLARATION),
+        Some(DECLARATOR) => {
+            let decl = owner
+                .and_then(|declarator| declarator.parent())
+                .and_then(|decl| decl.try_to::<VarDecl>());
+            let modifiers = match decl {
+                Some(decl) if decl.is_const() => READONLY,
+                _ => 0,
+            };
+            (TokenType::Variable, DECLARATION | modifiers)
+        }
+        _ => (TokenType::Variable, DECLARATION),
+    }
+}
+
+/// Modifiers derived from keywords on a declaration, such as `static` or `async`.
+fn node_modifiers(node: &SyntaxNode) -> u32 {
+    node.children_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .fold(0, |modifiers, token| match token.kind() {
+            STATIC_KW => modifiers | STATIC,
+            ASYNC_KW => modifiers | ASYNC,
+            READONLY_KW => modifiers | READONLY,
+            IDENT if token.text() == "static" => modifiers | STATIC,
+            IDENT if token.text() == "async" => modifiers | ASYNC,
+            IDENT if token.text() == "readonly" => modifiers | READONLY,
+            _ => modifiers,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(delta_line: u32, delta_start: u32, length: u32) -> SemanticToken {
+        SemanticToken {
+            delta_line,
+            delta_start,
+            length,
+            token_type: 0,
+            token_modifiers_bitset: 0,
+        }
+    }
+
+    /// Classify the first token of `source` with the given text.
+    fn classify_in(source: &str, text: &str) -> Option<(TokenType, u32)> {
+        let root = rslint_parser::parse_module(source, 0).syntax();
+        let token = root
+            .descendants_with_tokens()
+            .filter_map(|elem| elem.into_token())
+            .find(|token| token.text() == text)
+            .expect("token not found");
+        classify(&token)
+    }
+
+    #[test]
+    fn token_edits_unchanged() {
+        let tokens = vec![token(0, 0, 3), token(0, 4, 1)];
+        assert!(token_edits(&tokens, &tokens).is_empty());
+    }
+
+    #[test]
+    fn token_edits_replace_middle() {
+        let previous = vec![token(0, 0, 3), token(0, 4, 1), token(1, 0, 2)];
+        let new = vec![
+            token(0, 0, 3),
+            token(0, 4, 5),
+            token(0, 6, 1),
+            token(1, 0, 2),
+        ];
+        assert_eq!(
+            token_edits(&previous, &new),
+            vec![SemanticTokensEdit {
+                start: 5,
+                delete_count: 5,
+                data: Some(vec![token(0, 4, 5), token(0, 6, 1)]),
+            }]
+        );
+    }
+
+    #[test]
+    fn token_edits_delete_tail() {
+        let previous = vec![token(0, 0, 3), token(0, 4, 1), token(1, 0, 2)];
+        let new = vec![token(0, 0, 3)];
+        assert_eq!(
+            token_edits(&previous, &new),
+            vec![SemanticTokensEdit {
+                start: 5,
+                delete_count: 10,
+                data: Some(vec![]),
+            }]
+        );
+    }
+
+    #[test]
+    fn token_edits_repeated_tokens() {
+        // the prefix and suffix must not overlap when the same token repeats
+        let previous = vec![token(0, 1, 1); 2];
+        let new = vec![token(0, 1, 1); 3];
+        assert_eq!(
+            token_edits(&previous, &new),
+            vec![SemanticTokensEdit {
+                start: 10,
+                delete_count: 0,
+                data: Some(vec![token(0, 1, 1)]),
+            }]
+        );
+    }
+
+    #[test]
+    fn classify_declarations() {
+        assert_eq!(
+            classify_in("const a = 1", "a"),
+            Some((TokenType::Variable, DECLARATION | READONLY))
+        );
+        assert_eq!(
+            classify_in("let a = 1", "a"),
+            Some((TokenType::Variable, DECLARATION))
+        );
+        assert_eq!(
+            classify_in("function f(p) {}", "p"),
+            Some((TokenType::Parameter, DECLARATION))
+        );
+        assert_eq!(
+            classify_in("async function f() {}", "f"),
+            Some((TokenType::Function, DECLARATION | ASYNC))
+        );
+        assert_eq!(
+            classify_in("class A { static m() {} }", "m"),
+            Some((TokenType::Method, DECLARATION | STATIC))
+        );
+    }
+
+    #[test]
+    fn classify_uses() {
+        assert_eq!(classify_in("f()", "f"), Some((TokenType::Function, 0)));
+        assert_eq!(classify_in("a.b", "a"), Some((TokenType::Variable, 0)));
+        assert_eq!(classify_in("a.b", "b"), Some((TokenType::Property, 0)));
+    }
+
+    #[test]
+    fn classify_literals_and_keywords() {
+        assert_eq!(classify_in("1", "1"), Some((TokenType::Number, 0)));
+        assert_eq!(classify_in("'a'", "'a'"), Some((TokenType::String, 0)));
+        assert_eq!(classify_in("/a/g", "/a/g"), Some((TokenType::Regexp, 0)));
+        assert_eq!(classify_in("// a\n", "// a"), Some((TokenType::Comment, 0)));
+        assert_eq!(
+            classify_in("if (a) {}", "if"),
+            Some((TokenType::Keyword, 0))
+        );
+        assert_eq!(
+            classify_in("for (let a of b) {}", "of"),
+            Some((TokenType::Keyword, 0))
+        );
+        assert_eq!(classify_in("a + b", "+"), None);
+    }
+
+    #[test]
+    fn utf16_columns() {
+        let uri = tower_lsp::lsp_types::Url::parse("file:///a.js").unwrap();
+        let text = "let s = '🦀é', t = `a\r\n🦀`;";
+        let document = Document::new(
+            uri,
+            "javascript".into(),
+            text.into(),
+            &rslint_config::Config::default(),
+        )
+        .unwrap();
+        let tokens = semantic_tokens(&document, None)
+            .data
+            .into_iter()
+            .map(|token| (token.delta_line, token.delta_start, token.length))
+            .collect::<Vec<_>>();
+
+        // `let`, `s`, the string, `t`, and the template, whose chunk is split across two lines
+        assert_eq!(
+            tokens,
+            vec![
+                (0, 0, 3),
+                (0, 4, 1),
+                (0, 4, 5),
+                (0, 7, 1),
+                (0, 4, 1),
+                (0, 1, 1),
+                (1, 0, 2),
+                (0, 2, 1)
+            ]
+        );
+    }
+}
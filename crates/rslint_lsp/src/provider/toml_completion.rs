@@ -27,7 +27,7 @@ pub(crate) fn toml_completions(
     let dom = doc.parse.clone().into_dom();
     let paths: HashSet<dom::Path> = dom.iter().map(|(p, _)| p).collect();
 
-    let offset = doc.mapper.offset(position).unwrap();
+    let offset = doc.offset(position).unwrap();
 
     let query = dom.query_position(offset);
 
@@ -51,13 +51,13 @@ pub(crate) fn toml_completions(
                 let range = before
                     .syntax
                     .range
-                    .map(|range| doc.mapper.range(range).unwrap())
+                    .map(|range| doc.range(range).unwrap())
                     .or_else(|| {
                         query
                             .after
                             .syntax
                             .range
-                            .map(|range| doc.mapper.range(range).unwrap())
+                            .map(|range| doc.range(range).unwrap())
                     });
 
                 return get_schema_objects(query_path.clone(), &root_schema, true)
@@ -121,13 +121,13 @@ pub(crate) fn toml_completions(
                         let range = before
                             .syntax
                             .range
-                            .map(|range| doc.mapper.range(range).unwrap())
+                            .map(|range| doc.range(range).unwrap())
                             .or_else(|| {
                                 query
                                     .after
                                     .syntax
                                     .range
-                                    .map(|range| doc.mapper.range(range).unwrap())
+                                    .map(|range| doc.range(range).unwrap())
                             });
 
                         let comma_before = false;
@@ -145,7 +145,7 @@ pub(crate) fn toml_completions(
                         //             );
 
                         //             additional_edits.push(TextEdit {
-                        //                 range: doc.mapper.range(range_after).unwrap(),
+                        //                 range: doc.range(range_after).unwrap(),
                         //                 new_text: ",".into(),
                         //             })
                         //         }
@@ -224,13 +224,13 @@ pub(crate) fn toml_completions(
                         let range = before
                             .syntax
                             .range
-                            .map(|range| doc.mapper.range(range).unwrap())
+                            .map(|range| doc.range(range).unwrap())
                             .or_else(|| {
                                 query
                                     .after
                                     .syntax
                                     .range
-                                    .map(|range| doc.mapper.range(range).unwrap())
+                                    .map(|range| doc.range(range).unwrap())
                             });
 
                         return get_schema_objects(query_path, &root_schema, true)
@@ -266,7 +266,7 @@ pub(crate) fn toml_completions(
                         //         );
 
                         //         additional_edits.push(TextEdit {
-                        //             range: doc.mapper.range(range_after).unwrap(),
+                        //             range: doc.range(range_after).unwrap(),
                         //             new_text: ",".into(),
                         //         })
                         //     }
@@ -340,7 +340,7 @@ pub(crate) fn toml_completions(
                             .syntax
                             .element
                             .as_ref()
-                            .map(|el| doc.mapper.range(el.text_range()).unwrap());
+                            .map(|el| doc.range(el.text_range()).unwrap());
 
                         return get_schema_objects(query_path, &root_schema, true)
                             .into_iter()
@@ -373,10 +373,8 @@ pub(crate) fn toml_completions(
                                     };
                                 }
 
-                                let range = before
-                                    .syntax
-                                    .range
-                                    .map(|range| doc.mapper.range(range).unwrap());
+                                let range =
+                                    before.syntax.range.map(|range| doc.range(range).unwrap());
 
                                 return get_schema_objects(query_path.clone(), &root_schema, true)
                                     .into_iter()
@@ -419,7 +417,7 @@ pub(crate) fn toml_completions(
                     .after
                     .syntax
                     .range
-                    .map(|range| doc.mapper.range(range).unwrap());
+                    .map(|range| doc.range(range).unwrap());
 
                 return get_schema_objects(query_path.clone(), &root_schema, true)
                     .into_iter()
@@ -529,9 +527,9 @@ fn key_completion(
         }),
         insert_text: Some(insert_text),
         kind: if schema.is(InstanceType::Object) {
-            Some(CompletionItemKind::Struct)
+            Some(CompletionItemKind::STRUCT)
         } else {
-            Some(CompletionItemKind::Variable)
+            Some(CompletionItemKind::VARIABLE)
         },
         detail: detail_text(
             Some(schema.clone()),
@@ -735,8 +733,8 @@ fn value_insert(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Struct),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                kind: Some(CompletionItemKind::STRUCT),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 insert_text: Some(with_leading_space(
                     with_comma(insert_text, comma_before),
                     space_before,
@@ -757,8 +755,8 @@ fn value_insert(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Constant),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                kind: Some(CompletionItemKind::CONSTANT),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 insert_text: Some(with_leading_space(
                     with_comma(insert_text, comma_before),
                     space_before,
@@ -780,8 +778,8 @@ fn value_insert(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Constant),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                kind: Some(CompletionItemKind::CONSTANT),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 insert_text: Some(with_leading_space(
                     with_comma(insert_text, comma_before),
                     space_before,
@@ -803,13 +801,13 @@ fn value_insert(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Constant),
+                kind: Some(CompletionItemKind::CONSTANT),
                 insert_text: Some(with_leading_space(
                     with_comma(insert_text, comma_before),
                     space_before,
                 )),
                 label: format_value(value, false, 0),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 ..Default::default()
             })
         }
@@ -826,8 +824,8 @@ fn value_insert(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Constant),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                kind: Some(CompletionItemKind::CONSTANT),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 insert_text: Some(with_leading_space(
                     with_comma(insert_text, comma_before),
                     space_before,
@@ -860,12 +858,12 @@ fn empty_value_inserts(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Value),
+                kind: Some(CompletionItemKind::VALUE),
                 insert_text: Some(with_leading_space(
                     with_comma("true".into(), comma_before),
                     space_before,
                 )),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 label: "true".into(),
                 ..Default::default()
             },
@@ -879,12 +877,12 @@ fn empty_value_inserts(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Value),
+                kind: Some(CompletionItemKind::VALUE),
                 insert_text: Some(with_leading_space(
                     with_comma("${0:false}".into(), comma_before),
                     space_before,
                 )),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 label: "false".into(),
                 ..Default::default()
             },
@@ -899,12 +897,12 @@ fn empty_value_inserts(
                     ),
                 })
             }),
-            kind: Some(CompletionItemKind::Value),
+            kind: Some(CompletionItemKind::VALUE),
             insert_text: Some(with_leading_space(
                 with_comma("[ $0 ]".into(), comma_before),
                 space_before,
             )),
-            insert_text_format: Some(InsertTextFormat::Snippet),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
             label: "empty array".into(),
             ..Default::default()
         }]),
@@ -915,12 +913,12 @@ fn empty_value_inserts(
                     new_text: with_comma("${0:0.0}".into(), comma_before),
                 })
             }),
-            kind: Some(CompletionItemKind::Value),
+            kind: Some(CompletionItemKind::VALUE),
             insert_text: Some(with_leading_space(
                 with_comma("${0:0.0}".into(), comma_before),
                 space_before,
             )),
-            insert_text_format: Some(InsertTextFormat::Snippet),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
             label: "number".into(),
             ..Default::default()
         }]),
@@ -935,12 +933,12 @@ fn empty_value_inserts(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Value),
+                kind: Some(CompletionItemKind::VALUE),
                 insert_text: Some(with_leading_space(
                     with_comma(r#""$0""#.into(), comma_before),
                     space_before,
                 )),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 sort_text: Some(required_text("1string")),
                 label: "string".into(),
                 ..Default::default()
@@ -955,12 +953,12 @@ fn empty_value_inserts(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Value),
+                kind: Some(CompletionItemKind::VALUE),
                 insert_text: Some(with_leading_space(
                     with_comma(r#""""$0""""#.into(), comma_before),
                     space_before,
                 )),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 sort_text: Some(required_text("2multi-line string")),
                 label: "multi-line string".into(),
                 ..Default::default()
@@ -975,12 +973,12 @@ fn empty_value_inserts(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Value),
+                kind: Some(CompletionItemKind::VALUE),
                 insert_text: Some(with_leading_space(
                     with_comma(r#"'$0'"#.into(), comma_before),
                     space_before,
                 )),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 sort_text: Some("3literal string".into()),
                 label: "literal string".into(),
                 ..Default::default()
@@ -995,12 +993,12 @@ fn empty_value_inserts(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Value),
+                kind: Some(CompletionItemKind::VALUE),
                 insert_text: Some(with_leading_space(
                     with_comma(r#"'''$0'''"#.into(), comma_before),
                     space_before,
                 )),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 sort_text: Some("4multi-line literal string".into()),
                 label: "multi-line literal string".into(),
                 ..Default::default()
@@ -1016,12 +1014,12 @@ fn empty_value_inserts(
                     ),
                 })
             }),
-            kind: Some(CompletionItemKind::Value),
+            kind: Some(CompletionItemKind::VALUE),
             insert_text: Some(with_leading_space(
                 with_comma("${0:0}".into(), comma_before),
                 space_before,
             )),
-            insert_text_format: Some(InsertTextFormat::Snippet),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
             label: "integer".into(),
             ..Default::default()
         }]),
@@ -1038,12 +1036,12 @@ fn empty_value_inserts(
                                 ),
                             })
                         }),
-                        kind: Some(CompletionItemKind::Value),
+                        kind: Some(CompletionItemKind::VALUE),
                         insert_text: Some(with_leading_space(
                             with_comma(r#"{ $0 }"#.into(), comma_before),
                             space_before,
                         )),
-                        insert_text_format: Some(InsertTextFormat::Snippet),
+                        insert_text_format: Some(InsertTextFormat::SNIPPET),
                         label: "table".into(),
                         ..Default::default()
                     }])
@@ -1091,12 +1089,12 @@ fn empty_value_inserts(
                                 ),
                             })
                         }),
-                        kind: Some(CompletionItemKind::Value),
+                        kind: Some(CompletionItemKind::VALUE),
                         insert_text: Some(with_leading_space(
                             with_comma(snippet, comma_before),
                             space_before,
                         )),
-                        insert_text_format: Some(InsertTextFormat::Snippet),
+                        insert_text_format: Some(InsertTextFormat::SNIPPET),
                         label: "table".into(),
                         ..Default::default()
                     }])
@@ -1112,12 +1110,12 @@ fn empty_value_inserts(
                         ),
                     })
                 }),
-                kind: Some(CompletionItemKind::Value),
+                kind: Some(CompletionItemKind::VALUE),
                 insert_text: Some(with_leading_space(
                     with_comma(r#"{ $0 }"#.into(), comma_before),
                     space_before,
                 )),
-                insert_text_format: Some(InsertTextFormat::Snippet),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
                 label: "table".into(),
                 ..Default::default()
             }]),
@@ -0,0 +1,538 @@
+//! Diagnostics for rslintrc.toml, validating it against the config schema and the way the
+//! config deserializes its rules.
+
+use crate::core::session::{Session, TomlDocument};
+use heck::KebabCase;
+use once_cell::sync::Lazy;
+use rslint_config::{check_rule_name, check_rule_options, rule_from_value, OptionError};
+use rslint_core::{get_group_rules_by_name, registry::registry, RuleLevel};
+use schemars::schema::{InstanceType, RootSchema, SingleOrVec};
+use std::{collections::HashMap, convert::TryFrom};
+use taplo::{
+    dom::{self, Entries, KeyNode, ValueNode},
+    rowan::TextRange,
+    schema::util::get_schema_objects,
+    value::Value as TomlValue,
+};
+use tower_lsp::lsp_types::*;
+
+static CONFIG_SCHEMA: Lazy<RootSchema> =
+    Lazy::new(|| schemars::schema_for!(rslint_config::ConfigRepr));
+
+/// Publish the diagnostics of the open config document.
+pub async fn publish_toml_diagnostics(session: &Session) -> anyhow::Result<()> {
+    let doc = match session.config_doc.read().unwrap().clone() {
+        Some(doc) => doc,
+        None => return Ok(()),
+    };
+
+    let diagnostics = toml_diagnostics(&doc);
+    session
+        .client()?
+        .publish_diagnostics(doc.uri, diagnostics, doc.version)
+        .await;
+    Ok(())
+}
+
+/// Validate a config document, reporting syntax errors, duplicate keys, values which do not match
+/// the config schema, and rules which the config would fail to load.
+pub(crate) fn toml_diagnostics(doc: &TomlDocument) -> Vec<Diagnostic> {
+    let mut validator = Validator {
+        doc,
+        diagnostics: vec![],
+    };
+
+    for error in &doc.parse.errors {
+        validator.push(
+            error.range,
+            DiagnosticSeverity::ERROR,
+            error.message.clone(),
+        );
+    }
+
+    let root = doc.parse.clone().into_dom();
+    for error in root.errors() {
+        let range = match error {
+            dom::Error::DuplicateKey { second: key, .. }
+            | dom::Error::DottedKeyConflict { second: key, .. }
+            | dom::Error::ExpectedTableArray { key, .. }
+            | dom::Error::ExpectedTable { key, .. }
+            | dom::Error::InlineTable { key, .. }
+            | dom::Error::SubTableBeforeTableArray { key, .. } => key.text_range(),
+            dom::Error::Spanned { range, .. } => *range,
+            dom::Error::Generic(_) => TextRange::default(),
+        };
+        validator.push(range, DiagnosticSeverity::ERROR, error.to_string());
+    }
+
+    validator.entries(&dom::Path::new(), root.entries());
+
+    let mut diagnostics = validator.diagnostics;
+    diagnostics.sort_by_key(|diagnostic| {
+        (
+            diagnostic.range.start.line,
+            diagnostic.range.start.character,
+        )
+    });
+    diagnostics
+}
+
+struct Validator<'a> {
+    doc: &'a TomlDocument,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator<'_> {
+    fn push(
+        &mut self,
+        range: TextRange,
+        severity: DiagnosticSeverity,
+        message: String,
+    ) -> &mut Diagnostic {
+        self.diagnostics.push(Diagnostic {
+            range: self.doc.range(range).unwrap_or_default(),
+            severity: Some(severity),
+            code: Some(NumberOrString::String("config".to_string())),
+            source: Some("rslint".to_string()),
+            message,
+            ..Default::default()
+        });
+        self.diagnostics.last_mut().unwrap()
+    }
+
+    /// Check the keys of a table against the properties of its schema.
+    fn entries(&mut self, path: &dom::Path, entries: &Entries) {
+        let schemas = get_schema_objects(path.clone(), &CONFIG_SCHEMA, true);
+        let properties = schemas
+            .iter()
+            .filter_map(|schema| schema.schema.object.as_ref())
+            .flat_map(|object| object.properties.keys())
+            .collect::<Vec<_>>();
+
+        for (key, entry) in entries.iter() {
+            let name = key.full_key_string_stripped();
+            let entry_path = path.join(&name);
+            if !properties.is_empty() && !properties.contains(&&name) {
+                self.push(
+                    key.text_range(),
+                    DiagnosticSeverity::WARNING,
+                    format!("unknown config key '{}'", entry_path.dotted()),
+                );
+                continue;
+            }
+
+            // the contents of the rules table are validated like the config deserializes them
+            if path.dotted() != "rules" {
+                self.value(&entry_path, entry.value());
+            }
+        }
+    }
+
+    /// Check that a value has one of the types allowed by its schema.
+    fn value(&mut self, path: &dom::Path, value: &ValueNode) {
+        let schemas = get_schema_objects(path.clone(), &CONFIG_SCHEMA, true);
+        let types = schemas
+            .iter()
+            .filter_map(|schema| schema.schema.instance_type.as_ref())
+            .flat_map(|ty| match ty {
+                SingleOrVec::Single(ty) => vec![**ty],
+                SingleOrVec::Vec(tys) => tys.clone(),
+            })
+            .filter(|ty| *ty != InstanceType::Null)
+            .collect::<Vec<_>>();
+
+        let found = match instance_type(value) {
+            Some(found) => found,
+            None => return,
+        };
+        let matches = |ty: &InstanceType| {
+            *ty == found || (*ty == InstanceType::Number && found == InstanceType::Integer)
+        };
+        if !types.is_empty() && !types.iter().any(matches) {
+            let expected = types
+                .iter()
+                .map(|ty| type_name(*ty))
+                .collect::<Vec<_>>()
+                .join(" or ");
+            self.push(
+                range(value),
+                DiagnosticSeverity::ERROR,
+                format!("expected {}, found {}", expected, type_name(found)),
+            );
+            return;
+        }
+
+        let variants = schemas
+            .iter()
+            .filter_map(|schema| schema.schema.enum_values.as_ref())
+            .flatten()
+            .filter_map(|variant| variant.as_str())
+            .collect::<Vec<_>>();
+        if let ValueNode::String(string) = value {
+            // enums such as the source type are deserialized case insensitively
+            if !variants.is_empty()
+                && !variants
+                    .iter()
+                    .any(|variant| variant.eq_ignore_ascii_case(string.content()))
+            {
+                self.push(
+                    range(value),
+                    DiagnosticSeverity::ERROR,
+                    format!(
+                        "invalid value '{}', expected one of {}",
+                        string.content(),
+                        variants.join(", ")
+                    ),
+                );
+            }
+        }
+
+        match value {
+            ValueNode::Table(table) => {
+                self.entries(path, table.entries());
+                if path.dotted() == "rules" {
+                    self.rules(table.entries());
+                }
+            }
+            ValueNode::Array(array) => {
+                for (idx, item) in array.items().iter().enumerate() {
+                    self.value(&path.join(idx), item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Validate the rules table the same way `rslint_config` loads it, reporting rules which do
+    /// not exist, rule configs and levels which fail to deserialize, and rules which are configured twice.
+    fn rules(&mut self, entries: &Entries) {
+        let get = |name: &str| {
+            entries
+                .iter()
+                .find(|(key, _)| key.full_key_string_stripped() == name)
+                .map(|(_, entry)| entry.value())
+        };
+
+        // the first config of every rule, which is the one the config uses
+        let mut configured: HashMap<String, KeyNode> = HashMap::new();
+        for level in &["errors", "warnings"] {
+            let table = match get(level) {
+                Some(ValueNode::Table(table)) => table,
+                Some(value) => {
+                    self.expected(value, "a table of rule configs");
+                    continue;
+                }
+                None => continue,
+            };
+
+            for (key, entry) in table.entries().iter() {
+                let name = key.full_key_string_stripped();
+                if let Err(message) = check_rule_name(&name) {
+                    self.push(key.text_range(), DiagnosticSeverity::ERROR, message);
+                    continue;
+                }
+
+                let rule = name.to_kebab_case();
+                if configured.contains_key(&rule) {
+                    self.push(
+                        key.text_range(),
+                        DiagnosticSeverity::WARNING,
+                        format!(
+                            "ignoring configuration for '{}' because it is already configured",
+                            rule
+                        ),
+                    );
+                    continue;
+                }
+
+                // invalid values are already reported as syntax errors
+                if let Some(value) = TomlValue::try_from(entry.value().clone())
+                    .ok()
+                    .and_then(|value| serde_json::to_value(value).ok())
+                {
+                    if let Err(message) = rule_from_value(&name, value.clone()) {
+                        if !self.options(&rule, entry.value(), &value) {
+                            // point at the header of `[rules.errors.rule]` tables instead of the whole table
+                            let range = match entry.value() {
+                                ValueNode::Table(table) if !table.is_inline() => key.text_range(),
+                                value => range(value),
+                            };
+                            self.push(range, DiagnosticSeverity::ERROR, message);
+                        }
+                    }
+                }
+                if let Some(message) = registry()
+                    .rule(&rule)
+                    .and_then(|meta| meta.deprecation_message())
+                {
+                    self.push(key.text_range(), DiagnosticSeverity::WARNING, message)
+                        .tags = Some(vec![DiagnosticTag::DEPRECATED]);
+                }
+                configured.insert(rule, key.clone());
+            }
+        }
+
+        match get("levels") {
+            Some(ValueNode::Table(table)) => {
+                for (key, entry) in table.entries().iter() {
+                    if let Err(message) = check_rule_name(&key.full_key_string_stripped()) {
+                        self.push(key.text_range(), DiagnosticSeverity::ERROR, message);
+                    }
+                    match entry.value() {
+                        ValueNode::String(string)
+                            if serde_json::from_value::<RuleLevel>(string.content().into())
+                                .is_err() =>
+                        {
+                            self.push(
+                                range(entry.value()),
+                                DiagnosticSeverity::ERROR,
+                                format!(
+                                    "invalid value '{}', expected one of off, hint, info, warn, error",
+                                    string.content()
+                                ),
+                            );
+                        }
+                        ValueNode::String(_) => {}
+                        value => self.expected(value, "a rule level"),
+                    }
+                }
+            }
+            Some(value) => self.expected(value, "a table of rule levels"),
+            None => {}
+        }
+
+        for (name, item) in self.names(get("allowed"), "rule") {
+            if let Err(message) = check_rule_name(&name) {
+                self.push(range(item), DiagnosticSeverity::WARNING, message);
+            } else if let Some(key) = configured.get(&name) {
+                self.push(
+                    key.text_range(),
+                    DiagnosticSeverity::WARNING,
+                    format!(
+                        "ignoring configuration for '{}' because it is explicitly allowed",
+                        name
+                    ),
+                );
+            }
+        }
+
+        for (name, item) in self.names(get("groups"), "group") {
+            if get_group_rules_by_name(&name).is_none() {
+                self.push(
+                    range(item),
+                    DiagnosticSeverity::WARNING,
+                    format!("unknown rule group '{}'", name),
+                );
+            }
+        }
+    }
+
+    /// Report the options of a rule config which fail to deserialize at the options themselves,
+    /// returns whether any option was reported.
+    fn options(&mut self, rule: &str, node: &ValueNode, value: &serde_json::Value) -> bool {
+        let (table, options) = match (node, value.as_object()) {
+            (ValueNode::Table(table), Some(options)) => (table, options),
+            _ => return false,
+        };
+
+        let mut reported = false;
+        for error in check_rule_options(rule, options) {
+            let (key, entry) = match table
+                .entries()
+                .iter()
+                .find(|(key, _)| key.full_key_string_stripped() == error.option())
+            {
+                Some(option) => option,
+                None => continue,
+            };
+            let (range, message) = match &error {
+                OptionError::Unknown { .. } => (key.text_range(), error.title(rule)),
+                OptionError::Invalid { message, .. } => (
+                    range(entry.value()),
+                    format!("{}: {}", error.title(rule), message),
+                ),
+            };
+            let message = match error.help() {
+                Some(help) => format!("{}, {}", message, help),
+                None => message,
+            };
+            self.push(range, DiagnosticSeverity::ERROR, message);
+            reported = true;
+        }
+        reported
+    }
+
+    /// Collect the names in an array of rules or groups, reporting items which are not strings
+    /// and names which appear more than once.
+    fn names<'v>(
+        &mut self,
+        value: Option<&'v ValueNode>,
+        kind: &str,
+    ) -> Vec<(String, &'v ValueNode)> {
+        let items = match value {
+            Some(ValueNode::Array(array)) => array.items(),
+            Some(value) => {
+                self.expected(value, &format!("an array of {} names", kind));
+                return vec![];
+            }
+            None => return vec![],
+        };
+
+        let mut names: Vec<(String, &ValueNode)> = vec![];
+        for item in items {
+            let name = match item {
+                ValueNode::String(string) => string.content().to_string(),
+                _ => {
+                    self.expected(item, &format!("a {} name", kind));
+                    continue;
+                }
+            };
+
+            if names.iter().any(|(prev, _)| *prev == name) {
+                self.push(
+                    range(item),
+                    DiagnosticSeverity::WARNING,
+                    format!("duplicate {} '{}'", kind, name),
+                );
+            } else {
+                names.push((name, item));
+            }
+        }
+        names
+    }
+
+    fn expected(&mut self, value: &ValueNode, expected: &str) {
+        let found = instance_type(value).map_or("an invalid value", type_name);
+        self.push(
+            range(value),
+            DiagnosticSeverity::ERROR,
+            format!("expected {}, found {}", expected, found),
+        );
+    }
+}
+
+fn range(value: &ValueNode) -> TextRange {
+    match value {
+        ValueNode::Invalid(syntax) => syntax.text_range(),
+        _ => value.text_ranges().first().copied().unwrap_or_default(),
+    }
+}
+
+fn instance_type(value: &ValueNode) -> Option<InstanceType> {
+    Some(match value {
+        ValueNode::Bool(_) => InstanceType::Boolean,
+        ValueNode::String(_) | ValueNode::Date(_) => InstanceType::String,
+        ValueNode::Integer(_) => InstanceType::Integer,
+        ValueNode::Float(_) => InstanceType::Number,
+        ValueNode::Array(_) => InstanceType::Array,
+        ValueNode::Table(_) => InstanceType::Object,
+        ValueNode::Invalid(_) | ValueNode::Empty => return None,
+    })
+}
+
+fn type_name(ty: InstanceType) -> &'static str {
+    match ty {
+        InstanceType::Null => "null",
+        InstanceType::Boolean => "a boolean",
+        InstanceType::Object => "a table",
+        InstanceType::Array => "an array",
+        InstanceType::Number => "a number",
+        InstanceType::String => "a string",
+        InstanceType::Integer => "an integer",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use taplo::util::coords::Mapper;
+
+    /// Validate a config, returning the line, severity and message of every diagnostic.
+    fn validate(text: &str) -> Vec<(u32, DiagnosticSeverity, String)> {
+        let doc = TomlDocument::new(
+            Url::parse("file:///rslintrc.toml").unwrap(),
+            None,
+            taplo::parser::parse(text),
+            Mapper::new_utf16(text, false),
+        );
+        toml_diagnostics(&doc)
+            .into_iter()
+            .map(|diag| (diag.range.start.line, diag.severity.unwrap(), diag.message))
+            .collect()
+    }
+
+    #[test]
+    fn valid_config() {
+        assert_eq!(
+            validate("[rules]\ngroups = [\"errors\"]\nallowed = [\"getter-return\"]\n\n[rules.warnings.no-empty]\n"),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn unknown_rules() {
+        assert_eq!(
+            validate("[rules]\nallowed = [\"no-emtpy\"]\n\n[rules.errors]\nno-emtpy = {}\n"),
+            vec![
+                (
+                    1,
+                    DiagnosticSeverity::WARNING,
+                    "Unknown rule 'no-emtpy'. did you mean 'no-empty'?".to_string()
+                ),
+                (
+                    4,
+                    DiagnosticSeverity::ERROR,
+                    "Unknown rule 'no-emtpy'. did you mean 'no-empty'?".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_names() {
+        assert_eq!(
+            validate("[rules]\nallowed = [\"no-empty\", \"no-empty\"]\ngroups = [\"errors\", \"errors\"]\n"),
+            vec![
+                (
+                    1,
+                    DiagnosticSeverity::WARNING,
+                    "duplicate rule 'no-empty'".to_string()
+                ),
+                (
+                    2,
+                    DiagnosticSeverity::WARNING,
+                    "duplicate group 'errors'".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            validate("[rules.errors]\nno-empty = {}\n\n[rules.warnings]\nno-empty = {}\n"),
+            vec![(
+                4,
+                DiagnosticSeverity::WARNING,
+                "ignoring configuration for 'no-empty' because it is already configured"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn invalid_options() {
+        let diagnostics = validate(
+            "[rules.errors.no-empty]\nallowEmptyCatch = 1\ndisallowEmptyFunction = true\n",
+        );
+        assert_eq!(diagnostics.len(), 2, "{:#?}", diagnostics);
+        assert_eq!(diagnostics[0].0, 1);
+        assert!(
+            diagnostics[0].2.contains("allowEmptyCatch"),
+            "{:#?}",
+            diagnostics
+        );
+        assert_eq!(diagnostics[1].0, 2);
+        assert!(
+            diagnostics[1].2.contains("disallowEmptyFunctions"),
+            "{:#?}",
+            diagnostics
+        );
+    }
+}
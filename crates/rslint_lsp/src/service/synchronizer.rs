@@ -3,26 +3,91 @@
 /// Functions related to processing events for a document.
 pub(crate) mod document {
     use crate::{
-        core::{document::Document, session::Session},
+        core::{
+            document::Document,
+            session::{LintJob, Session},
+        },
         provider,
     };
-    use rslint_core::DirectiveParser;
+    use futures::future::{AbortHandle, Abortable};
     use rslint_errors::file::SimpleFiles;
+    use std::{
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
     use tower_lsp::lsp_types::*;
 
+    /// How long to wait for more changes before linting a changed document.
+    const LINT_DEBOUNCE: Duration = Duration::from_millis(200);
+
+    /// The id of the next lint job, telling jobs apart from the jobs replacing them.
+    static NEXT_JOB: AtomicUsize = AtomicUsize::new(0);
+
+    /// Lint a document and publish its diagnostics once it stopped changing for a while,
+    /// cancelling the pending lint of a previous version of the document.
+    fn schedule_lint(session: Arc<Session>, uri: Url) {
+        let id = NEXT_JOB.fetch_add(1, Ordering::SeqCst);
+        let (handle, registration) = AbortHandle::new_pair();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let lint_job = LintJob {
+            id,
+            handle,
+            cancelled: cancelled.clone(),
+        };
+        if let Some(previous) = session.lint_jobs.insert(uri.clone(), lint_job) {
+            previous.cancel();
+        }
+
+        let job = async move {
+            if let Err(err) = run_lint_job(session.clone(), uri.clone(), cancelled).await {
+                log::error!("failed to publish diagnostics: {}", err);
+            }
+            // a newer job may have replaced this one while it was running
+            session.lint_jobs.remove_if(&uri, |_, job| job.id == id);
+        };
+        tokio::spawn(Abortable::new(job, registration));
+    }
+
+    async fn run_lint_job(
+        session: Arc<Session>,
+        uri: Url,
+        cancelled: Arc<AtomicBool>,
+    ) -> anyhow::Result<()> {
+        tokio::time::sleep(LINT_DEBOUNCE).await;
+        // linting blocks, so it runs on its own thread and checks whether it was cancelled
+        // between rules since aborting the job does not stop the thread
+        let lint = {
+            let (session, uri) = (session.clone(), uri.clone());
+            tokio::task::spawn_blocking(move || {
+                provider::diagnostics::lint(&session, &uri, &cancelled)
+                    .map(|lint| lint.map(|(diags, document)| (diags, document.version)))
+            })
+            .await??
+        };
+        match lint {
+            Some((diags, version)) => {
+                provider::diagnostics::publish(&session, uri, diags, version).await
+            }
+            None => Ok(()),
+        }
+    }
+
     /// Handle a document "change" event.
     pub(crate) async fn change(
-        session: &Session,
+        session: Arc<Session>,
         params: DidChangeTextDocumentParams,
     ) -> anyhow::Result<()> {
         let DidChangeTextDocumentParams {
-            text_document: VersionedTextDocumentIdentifier { uri, .. },
+            text_document: VersionedTextDocumentIdentifier { uri, version },
             content_changes,
         } = params;
         let TextDocumentContentChangeEvent { text, .. } = content_changes[0].clone();
 
         // NOTE: We use an explicit scope here because document (below) must be
-        // dropped before calling publish_diagnostics (further below) otherwise
+        // dropped before the document is linted (further below) otherwise
         // the server will block.
         {
             let mut files = SimpleFiles::new();
@@ -32,20 +97,46 @@ pub(crate) mod document {
             document.files = files;
             document.file.id = file_id;
             document.file.source = text.clone();
+            document.reparse();
+            document.version = Some(version);
+        }
 
-            let (parsing_errors, root) = document.file.parse_with_errors();
+        // clients pulling diagnostics request them again by themselves
+        if !session.pull_diagnostics.load(Ordering::SeqCst) {
+            schedule_lint(session, uri);
+        }
 
-            let res = DirectiveParser::new(root.clone(), &document.file).get_file_directives();
+        Ok(())
+    }
 
-            document.root = root;
-            document.directives = res.directives;
-            document.directive_errors = res.diagnostics;
-            document.parsing_errors = parsing_errors;
+    /// Parse the open documents again with the syntax set by the current config, and lint all
+    /// documents again since the config may enable other rules or change their levels.
+    pub(crate) fn reconfigure(session: Arc<Session>) {
+        let pull = session.pull_diagnostics.load(Ordering::SeqCst);
+        for uri in session.document_uris() {
+            match session.get_mut_document(&uri) {
+                Ok(mut document) => {
+                    document.configure(&uri, &session.lint_config.read().unwrap());
+                    // the previous diagnostics of a pulling client are outdated as well
+                    document.diagnostics_result_id = None;
+                }
+                Err(_) => continue,
+            }
+
+            if !pull {
+                schedule_lint(session.clone(), uri);
+            }
         }
 
-        provider::diagnostics::publish_diagnostics(session, uri).await?;
-
-        Ok(())
+        if pull && session.diagnostic_refresh.load(Ordering::SeqCst) {
+            tokio::spawn(async move {
+                if let Ok(client) = session.client() {
+                    if let Err(err) = client.workspace_diagnostic_refresh().await {
+                        log::error!("failed to refresh diagnostics: {}", err);
+                    }
+                }
+            });
+        }
     }
 
     /// Handle a document "close" event.
@@ -58,6 +149,9 @@ pub(crate) mod document {
         } = params;
 
         session.remove_document(&uri)?;
+        if let Some((_, job)) = session.lint_jobs.remove(&uri) {
+            job.cancel();
+        }
 
         let diagnostics = Default::default();
         let version = Default::default();
@@ -80,14 +174,20 @@ pub(crate) mod document {
                     uri,
                     language_id,
                     text,
-                    ..
+                    version,
                 },
         } = params;
 
-        let document = Document::new(uri.clone(), language_id, text)?;
+        let mut document = {
+            let config = session.lint_config.read().unwrap();
+            Document::new(uri.clone(), language_id, text, &config)?
+        };
+        document.version = Some(version);
         session.insert_document(uri.clone(), document)?;
 
-        provider::diagnostics::publish_diagnostics(session, uri).await?;
+        if !session.pull_diagnostics.load(Ordering::SeqCst) {
+            provider::diagnostics::publish_diagnostics(session, uri).await?;
+        }
 
         Ok(())
     }
@@ -8,8 +8,13 @@ pub fn mergeable(input: TokenStream) -> TokenStream {
     let mut methods = vec![];
     for field in strukt.fields.clone() {
         let name = field.ident.unwrap();
+        let option = camel_case(&name.to_string());
         let tokens = quote! {
-            let #name = most_frequent(items.iter().map(|x| x.#name.to_owned()).collect::<Vec<_>>()).to_owned();
+            let (#name, count) = most_frequent_with_count(items.iter().map(|x| x.#name.to_owned()).collect::<Vec<_>>());
+            options.push(crate::InferredOption {
+                name: #option,
+                confidence: count as f32 / items.len() as f32,
+            });
         };
         methods.push(tokens);
     }
@@ -20,18 +25,39 @@ pub fn mergeable(input: TokenStream) -> TokenStream {
             /// Take multiple instances of the struct and merge them into a single struct which
             /// uses the most common values for each field
             pub fn merge(items: Vec<Self>) -> Option<Self> {
-                use crate::util::most_frequent;
+                Self::merge_with_confidence(items).map(|(merged, _)| merged)
+            }
+
+            /// Merge multiple instances of the struct like [`Self::merge`], and return how many of the
+            /// instances agree with the value of each field
+            pub fn merge_with_confidence(items: Vec<Self>) -> Option<(Self, Vec<crate::InferredOption>)> {
+                use crate::util::most_frequent_with_count;
 
                 if items.is_empty() {
                     return None;
                 }
 
+                let mut options = vec![];
                 #(#methods)*
-                Some(Self {
+                Some((Self {
                     #(#field),*
-                })
+                }, options))
             }
         }
     };
     tokens.into()
 }
+
+/// Convert a snake_case field name to the camelCase name used in configs.
+fn camel_case(name: &str) -> String {
+    let mut parts = name.split('_');
+    let mut camel = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            camel.extend(first.to_uppercase());
+            camel.push_str(chars.as_str());
+        }
+    }
+    camel
+}
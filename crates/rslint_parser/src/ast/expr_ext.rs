@@ -195,12 +195,22 @@ impl BinExpr {
         self.op_details().map(|t| t.0)
     }
 
+    /// The left hand side of the expression, this is `None` for a brand check such as `#foo in bar`.
     pub fn lhs(&self) -> Option<Expr> {
+        if self.private_name().is_some() {
+            return None;
+        }
         support::children(self.syntax()).next()
     }
 
     pub fn rhs(&self) -> Option<Expr> {
-        support::children(self.syntax()).nth(1)
+        let idx = if self.private_name().is_some() { 0 } else { 1 };
+        support::children(self.syntax()).nth(idx)
+    }
+
+    /// The private name checked by a brand check such as `#foo in bar`.
+    pub fn private_name(&self) -> Option<PrivateName> {
+        support::child(self.syntax())
     }
 
     /// Whether this binary expr is a `||` or `&&` expression.
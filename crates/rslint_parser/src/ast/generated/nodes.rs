@@ -349,6 +349,9 @@ pub struct TsConstructorType {
     pub(crate) syntax: SyntaxNode,
 }
 impl TsConstructorType {
+    pub fn abstract_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![abstract])
+    }
     pub fn new_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![new]) }
     pub fn params(&self) -> Option<ParameterList> { support::child(&self.syntax) }
     pub fn fat_arrow_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [=>]) }
@@ -391,6 +394,9 @@ pub struct TsTypeParam {
     pub(crate) syntax: SyntaxNode,
 }
 impl TsTypeParam {
+    pub fn const_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![const]) }
+    pub fn in_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![in]) }
+    pub fn out_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![out]) }
     pub fn ident_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![ident]) }
     pub fn constraint(&self) -> Option<TsConstraint> { support::child(&self.syntax) }
     pub fn default(&self) -> Option<TsDefault> { support::child(&self.syntax) }
@@ -448,6 +454,18 @@ impl TsConstAssertion {
     pub fn const_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![const]) }
     pub fn r_angle_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [>]) }
 }
+#[doc = " An expression checked against a type without changing the type of the expression\n\n ```ts\n let colors = { red: [255, 0, 0] } satisfies Record<string, number[]>;\n ```\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TsSatisfies {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TsSatisfies {
+    pub fn expr(&self) -> Option<Expr> { support::child(&self.syntax) }
+    pub fn satisfies_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![satisfies])
+    }
+    pub fn ty(&self) -> Option<TsType> { support::child(&self.syntax) }
+}
 #[doc = ""]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TsEnum {
@@ -1229,6 +1247,9 @@ pub struct Getter {
 }
 impl Getter {
     pub fn decorators(&self) -> AstChildren<TsDecorator> { support::children(&self.syntax) }
+    pub fn override_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![override])
+    }
     pub fn get_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![get]) }
     pub fn key(&self) -> Option<PropName> { support::child(&self.syntax) }
     pub fn parameters(&self) -> Option<ParameterList> { support::child(&self.syntax) }
@@ -1241,6 +1262,9 @@ pub struct Setter {
 }
 impl Setter {
     pub fn decorators(&self) -> AstChildren<TsDecorator> { support::children(&self.syntax) }
+    pub fn override_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![override])
+    }
     pub fn set_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![set]) }
     pub fn key(&self) -> Option<PropName> { support::child(&self.syntax) }
     pub fn parameters(&self) -> Option<ParameterList> { support::child(&self.syntax) }
@@ -1548,6 +1572,9 @@ pub struct Method {
 impl Method {
     pub fn decorators(&self) -> AstChildren<TsDecorator> { support::children(&self.syntax) }
     pub fn static_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![static]) }
+    pub fn override_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![override])
+    }
     pub fn async_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![async]) }
     pub fn star_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [*]) }
     pub fn name(&self) -> Option<PropName> { support::child(&self.syntax) }
@@ -1576,6 +1603,12 @@ impl PrivateProp {
     }
     pub fn static_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![static]) }
     pub fn accessibility(&self) -> Option<TsAccessibility> { support::child(&self.syntax) }
+    pub fn override_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![override])
+    }
+    pub fn accessor_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![accessor])
+    }
     pub fn key(&self) -> Option<PrivateName> { support::child(&self.syntax) }
     pub fn question_mark_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T ! [?])
@@ -1598,6 +1631,12 @@ impl ClassProp {
     }
     pub fn static_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![static]) }
     pub fn accessibility(&self) -> Option<TsAccessibility> { support::child(&self.syntax) }
+    pub fn override_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![override])
+    }
+    pub fn accessor_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![accessor])
+    }
     pub fn key(&self) -> Option<PropName> { support::child(&self.syntax) }
     pub fn question_mark_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T ! [?])
@@ -1607,6 +1646,17 @@ impl ClassProp {
     pub fn ty(&self) -> Option<TsType> { support::child(&self.syntax) }
     pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
+#[doc = " A block of statements run once when the class is initialized\n\n ```js\n class A {\n     static {\n         A.b = 1;\n     }\n }\n ```\n"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClassStaticBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ClassStaticBlock {
+    pub fn static_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![static]) }
+    pub fn l_curly_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['{']) }
+    pub fn stmts(&self) -> AstChildren<Stmt> { support::children(&self.syntax) }
+    pub fn r_curly_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['}']) }
+}
 #[doc = ""]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Constructor {
@@ -1771,6 +1821,7 @@ pub enum ClassElement {
     Method(Method),
     PrivateProp(PrivateProp),
     ClassProp(ClassProp),
+    ClassStaticBlock(ClassStaticBlock),
     Constructor(Constructor),
     TsIndexSignature(TsIndexSignature),
     Getter(Getter),
@@ -1834,6 +1885,7 @@ pub enum Expr {
     TsNonNull(TsNonNull),
     TsAssertion(TsAssertion),
     TsConstAssertion(TsConstAssertion),
+    TsSatisfies(TsSatisfies),
 }
 #[doc = " Either a single type reference or a fully qualified path\n"]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -2403,6 +2455,17 @@ impl AstNode for TsConstAssertion {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for TsSatisfies {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == TS_SATISFIES }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for TsEnum {
     fn can_cast(kind: SyntaxKind) -> bool { kind == TS_ENUM }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -3569,6 +3632,17 @@ impl AstNode for ClassProp {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for ClassStaticBlock {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CLASS_STATIC_BLOCK }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for Constructor {
     fn can_cast(kind: SyntaxKind) -> bool { kind == CONSTRUCTOR }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -3898,6 +3972,9 @@ impl From<PrivateProp> for ClassElement {
 impl From<ClassProp> for ClassElement {
     fn from(node: ClassProp) -> ClassElement { ClassElement::ClassProp(node) }
 }
+impl From<ClassStaticBlock> for ClassElement {
+    fn from(node: ClassStaticBlock) -> ClassElement { ClassElement::ClassStaticBlock(node) }
+}
 impl From<Constructor> for ClassElement {
     fn from(node: Constructor) -> ClassElement { ClassElement::Constructor(node) }
 }
@@ -3918,6 +3995,7 @@ impl AstNode for ClassElement {
                 | METHOD
                 | PRIVATE_PROP
                 | CLASS_PROP
+                | CLASS_STATIC_BLOCK
                 | CONSTRUCTOR
                 | TS_INDEX_SIGNATURE
                 | GETTER
@@ -3930,6 +4008,7 @@ impl AstNode for ClassElement {
             METHOD => ClassElement::Method(Method { syntax }),
             PRIVATE_PROP => ClassElement::PrivateProp(PrivateProp { syntax }),
             CLASS_PROP => ClassElement::ClassProp(ClassProp { syntax }),
+            CLASS_STATIC_BLOCK => ClassElement::ClassStaticBlock(ClassStaticBlock { syntax }),
             CONSTRUCTOR => ClassElement::Constructor(Constructor { syntax }),
             TS_INDEX_SIGNATURE => ClassElement::TsIndexSignature(TsIndexSignature { syntax }),
             GETTER => ClassElement::Getter(Getter { syntax }),
@@ -3944,6 +4023,7 @@ impl AstNode for ClassElement {
             ClassElement::Method(it) => &it.syntax,
             ClassElement::PrivateProp(it) => &it.syntax,
             ClassElement::ClassProp(it) => &it.syntax,
+            ClassElement::ClassStaticBlock(it) => &it.syntax,
             ClassElement::Constructor(it) => &it.syntax,
             ClassElement::TsIndexSignature(it) => &it.syntax,
             ClassElement::Getter(it) => &it.syntax,
@@ -4168,6 +4248,9 @@ impl From<TsAssertion> for Expr {
 impl From<TsConstAssertion> for Expr {
     fn from(node: TsConstAssertion) -> Expr { Expr::TsConstAssertion(node) }
 }
+impl From<TsSatisfies> for Expr {
+    fn from(node: TsSatisfies) -> Expr { Expr::TsSatisfies(node) }
+}
 impl AstNode for Expr {
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
@@ -4201,6 +4284,7 @@ impl AstNode for Expr {
                 | TS_NON_NULL
                 | TS_ASSERTION
                 | TS_CONST_ASSERTION
+                | TS_SATISFIES
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -4234,6 +4318,7 @@ impl AstNode for Expr {
             TS_NON_NULL => Expr::TsNonNull(TsNonNull { syntax }),
             TS_ASSERTION => Expr::TsAssertion(TsAssertion { syntax }),
             TS_CONST_ASSERTION => Expr::TsConstAssertion(TsConstAssertion { syntax }),
+            TS_SATISFIES => Expr::TsSatisfies(TsSatisfies { syntax }),
             _ => return None,
         };
         Some(res)
@@ -4269,6 +4354,7 @@ impl AstNode for Expr {
             Expr::TsNonNull(it) => &it.syntax,
             Expr::TsAssertion(it) => &it.syntax,
             Expr::TsConstAssertion(it) => &it.syntax,
+            Expr::TsSatisfies(it) => &it.syntax,
         }
     }
 }
@@ -4909,6 +4995,11 @@ impl std::fmt::Display for TsConstAssertion {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for TsSatisfies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for TsEnum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -5439,6 +5530,11 @@ impl std::fmt::Display for ClassProp {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for ClassStaticBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for Constructor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
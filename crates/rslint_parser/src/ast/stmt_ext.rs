@@ -284,19 +284,46 @@ impl ExportDecl {
 
 impl Specifier {
     pub fn as_token(&self) -> Option<SyntaxToken> {
-        self.syntax()
-            .children_with_tokens()
-            .filter_map(|x| x.into_token())
-            .nth(1)
+        support::token(self.syntax(), T![as])
+    }
+
+    /// The inline `type` modifier of a specifier such as `import { type Foo } from "foo"`.
+    pub fn type_token(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), T![type])
     }
 
     pub fn alias(&self) -> Option<Name> {
         self.syntax().children().nth(1).and_then(|x| x.try_to())
     }
 
+    /// The alias of a specifier if it is a string such as `export { foo as "bar" }`.
+    pub fn alias_string(&self) -> Option<Literal> {
+        self.syntax().children().nth(1).and_then(|x| x.try_to())
+    }
+
     pub fn name(&self) -> Option<SyntaxNode> {
         self.syntax().first_child()
     }
+
+    /// The name of a specifier if it is a string such as `import { "foo" as bar } from "baz"`.
+    pub fn name_string(&self) -> Option<Literal> {
+        self.syntax().first_child().and_then(|x| x.try_to())
+    }
+}
+
+impl ExportWildcard {
+    pub fn alias(&self) -> Option<Name> {
+        self.syntax().children().find_map(|x| x.try_to())
+    }
+
+    /// The alias of the namespace if it is a string such as `export * as "foo" from "bar"`.
+    pub fn alias_string(&self) -> Option<Literal> {
+        let from = self.from_token()?.text_range().start();
+        self.syntax()
+            .children()
+            .filter_map(|x| x.try_to::<Literal>())
+            .find(|lit| lit.syntax().text_range().end() <= from)
+    }
 }
 
 impl WildcardImport {
@@ -100,6 +100,7 @@ pub use rslint_syntax::*;
 pub type ParserError = rslint_errors::Diagnostic;
 
 use std::ops::Range;
+use std::path::Path;
 
 /// Abstracted token for `TokenSource`
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -165,14 +166,23 @@ macro_rules! match_ast {
 }
 
 /// A structure describing the syntax features the parser will accept. The
-/// default is an ECMAScript 2021 Script without any proposals.
+/// default is a Script targeting the latest ECMAScript version without any proposals.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Syntax {
     pub file_kind: FileKind,
+    /// The ECMAScript version targeted by the source, syntax added in later versions is an error.
+    /// Syntax from ES2015 and earlier is always accepted.
+    pub ecma_version: EcmaVersion,
     pub top_level_await: bool,
     pub global_return: bool,
     pub class_fields: bool,
     pub decorators: bool,
+    /// Whether the file is a TypeScript declaration file (`.d.ts`), where the whole file
+    /// is an ambient context and implementations are not allowed.
+    pub declaration: bool,
+    /// Whether a TypeScript file is an ES module (`.mts`), which makes it strict mode code
+    /// like a JavaScript module.
+    pub esm: bool,
 }
 
 impl Syntax {
@@ -207,6 +217,21 @@ impl Syntax {
         self
     }
 
+    pub fn declaration(mut self) -> Self {
+        self.declaration = true;
+        self
+    }
+
+    pub fn esm(mut self) -> Self {
+        self.esm = true;
+        self
+    }
+
+    pub fn ecma_version(mut self, ecma_version: EcmaVersion) -> Self {
+        self.ecma_version = ecma_version;
+        self
+    }
+
     pub fn script(mut self) -> Self {
         self.file_kind = FileKind::Script;
         self
@@ -221,6 +246,122 @@ impl Syntax {
         self.file_kind = FileKind::TypeScript;
         self.class_fields().decorators().top_level_await()
     }
+
+    /// Whether the file has the semantics of an ES module, this is the case for JavaScript modules
+    /// and TypeScript ES modules.
+    pub fn is_module(&self) -> bool {
+        match self.file_kind {
+            FileKind::Module => true,
+            FileKind::TypeScript => self.esm,
+            FileKind::Script => false,
+        }
+    }
+
+    /// Infer the syntax of a file from its extension, returns `None` if the extension is not
+    /// a JavaScript or TypeScript one.
+    ///
+    /// `.mts` files are ES modules. `.cjs` and `.cts` files are CommonJS, therefore they disallow top level `await`, `.cjs` files
+    /// also allow top level `return` like node does. `.d.ts`, `.d.mts` and `.d.cts` files are TypeScript declaration files.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_str()?;
+        let declaration = [".d.ts", ".d.mts", ".d.cts"]
+            .iter()
+            .any(|ext| file_name.ends_with(ext));
+
+        let syntax = match path.extension()?.to_str()? {
+            "js" => Syntax::default().script(),
+            "mjs" => Syntax::default().module(),
+            "cjs" => Syntax::default().script().global_return(),
+            "ts" => Syntax::default().typescript(),
+            "mts" => Syntax::default().typescript().esm(),
+            "cts" => Syntax {
+                top_level_await: false,
+                ..Syntax::default().typescript()
+            },
+            _ => return None,
+        };
+        Some(if declaration {
+            syntax.declaration()
+        } else {
+            syntax
+        })
+    }
+}
+
+/// An ECMAScript version the parser can target. ES2015 is the oldest version which can be
+/// targeted, the syntax of older versions is a subset of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EcmaVersion {
+    ES2015,
+    ES2016,
+    ES2017,
+    ES2018,
+    ES2019,
+    ES2020,
+    ES2021,
+    ES2022,
+}
+
+impl EcmaVersion {
+    /// The latest version of ECMAScript.
+    pub const LATEST: EcmaVersion = EcmaVersion::ES2022;
+
+    /// Every version which can be targeted, from the oldest to the latest.
+    pub const ALL: [EcmaVersion; 8] = [
+        EcmaVersion::ES2015,
+        EcmaVersion::ES2016,
+        EcmaVersion::ES2017,
+        EcmaVersion::ES2018,
+        EcmaVersion::ES2019,
+        EcmaVersion::ES2020,
+        EcmaVersion::ES2021,
+        EcmaVersion::ES2022,
+    ];
+
+    /// The name of the version used in configs, such as `es2015`.
+    pub fn name(self) -> &'static str {
+        match self {
+            EcmaVersion::ES2015 => "es2015",
+            EcmaVersion::ES2016 => "es2016",
+            EcmaVersion::ES2017 => "es2017",
+            EcmaVersion::ES2018 => "es2018",
+            EcmaVersion::ES2019 => "es2019",
+            EcmaVersion::ES2020 => "es2020",
+            EcmaVersion::ES2021 => "es2021",
+            EcmaVersion::ES2022 => "es2022",
+        }
+    }
+}
+
+impl Default for EcmaVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl std::fmt::Display for EcmaVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::str::FromStr for EcmaVersion {
+    type Err = String;
+
+    /// Parse the name of a version, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_lowercase();
+        if let Some(version) = Self::ALL.iter().find(|version| version.name() == name) {
+            return Ok(*version);
+        }
+        match name.as_str() {
+            "es3" | "es5" => Err(format!(
+                "unsupported ecma version: {}, the oldest supported version is es2015",
+                s
+            )),
+            _ => Err(format!("invalid ecma version: {}", s)),
+        }
+    }
 }
 
 /// The kind of file we are parsing
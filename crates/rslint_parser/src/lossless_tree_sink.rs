@@ -20,6 +20,8 @@ pub struct LosslessTreeSink<'a> {
     state: State,
     errors: Vec<ParserError>,
     inner: SyntaxTreeBuilder,
+    /// Whether the last non-trivia token is a shebang.
+    after_shebang: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -48,6 +50,7 @@ impl<'a> TreeSink for LosslessTreeSink<'a> {
         let text: SmolStr = self.text[range].into();
         self.text_pos += len;
         self.token_pos += amount as usize;
+        self.after_shebang = false;
         self.inner.token(kind, text);
     }
 
@@ -105,6 +108,14 @@ impl<'a> TreeSink for LosslessTreeSink<'a> {
             });
             n_attached_trivias(kind, leading_trivias.rev())
         };
+        // A comment starting a file belongs to the root, which makes it a file level directive.
+        // A shebang must be the first line, so comments after it are on the next line and would be
+        // attached to the first statement, they are kept in the root like in a file without a shebang.
+        let n_attached_trivias = if self.after_shebang {
+            0
+        } else {
+            n_attached_trivias
+        };
         self.eat_n_trivias(n_trivias - n_attached_trivias);
         self.inner.start_node(kind);
         self.eat_n_trivias(n_attached_trivias);
@@ -133,6 +144,7 @@ impl<'a> LosslessTreeSink<'a> {
             state: State::PendingStart,
             inner: SyntaxTreeBuilder::default(),
             errors: vec![],
+            after_shebang: false,
         }
     }
 
@@ -153,6 +165,7 @@ impl<'a> LosslessTreeSink<'a> {
                     state: State::PendingStart,
                     inner: SyntaxTreeBuilder::default(),
                     errors: vec![],
+                    after_shebang: false,
                 };
             }
             len += tok.len;
@@ -194,6 +207,9 @@ impl<'a> LosslessTreeSink<'a> {
         let text: SmolStr = self.text[range].into();
         self.text_pos += len;
         self.token_pos += 1;
+        if !kind.is_trivia() {
+            self.after_shebang = kind == SHEBANG;
+        }
         self.inner.token(kind, text);
     }
 }
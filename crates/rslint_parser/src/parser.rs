@@ -3,13 +3,27 @@
 //! the parser yields events like `Start node`, `Error`, etc.
 //! These events are then applied to a `TreeSink`.
 
-use rslint_errors::Diagnostic;
+use rslint_errors::{Diagnostic, Span};
 use std::borrow::BorrowMut;
 use std::cell::Cell;
 use std::ops::Range;
 
 use crate::*;
 
+/// The label of the opening delimiter of a missing closing delimiter.
+const UNCLOSED_DELIMITER: &str = "unclosed delimiter";
+
+/// Whether a closing delimiter closes an opening delimiter.
+fn closes(closing: SyntaxKind, opening: SyntaxKind) -> bool {
+    match closing {
+        T![')'] => opening == T!['('],
+        T![']'] => opening == T!['['],
+        // template literal elements are closed by a normal curly bracket
+        T!['}'] => opening == T!['{'] || opening == SyntaxKind::DOLLARCURLY,
+        _ => false,
+    }
+}
+
 /// An extremely fast, error tolerant, completely lossless JavaScript parser
 ///
 /// The Parser yields lower level events instead of nodes.
@@ -87,20 +101,23 @@ pub struct Parser<'t> {
     pub state: ParserState,
     pub syntax: Syntax,
     pub errors: Vec<ParserError>,
+    /// The opening delimiters reported as unclosed, as the index of the error and the start of the delimiter.
+    reported_delimiters: Vec<(usize, usize)>,
 }
 
 impl<'t> Parser<'t> {
     /// Make a new parser
     pub fn new(tokens: TokenSource<'t>, file_id: usize, syntax: Syntax) -> Parser<'t> {
-        // TODO(RDambrosio016): Does TypeScript imply Module/Strict?
-        let strict = if syntax.file_kind == FileKind::Module {
+        let strict = if syntax.is_module() {
             Some(StrictMode::Module)
         } else {
             None
         };
         let state = ParserState {
-            is_module: syntax.file_kind == FileKind::Module,
+            is_module: syntax.is_module(),
             strict,
+            // declaration files are entirely an ambient context
+            in_declare: syntax.declaration && syntax.file_kind == FileKind::TypeScript,
             ..ParserState::default()
         };
 
@@ -112,6 +129,7 @@ impl<'t> Parser<'t> {
             state,
             syntax,
             errors: vec![],
+            reported_delimiters: vec![],
         }
     }
 
@@ -310,34 +328,78 @@ impl<'t> Parser<'t> {
     }
 
     /// Try to eat a specific token kind, if the kind is not there then add an error to the events stack.
+    ///
+    /// If the kind is a closing delimiter, the error also points to the opening delimiter which is left unclosed.
     pub fn expect(&mut self, kind: SyntaxKind) -> bool {
         if self.eat(kind) {
             true
         } else {
-            let err = if self.cur() == SyntaxKind::EOF {
+            // punctuation is displayed quoted, e.g. `')'`, and keywords have no display, so their
+            // name is taken from their kind instead
+            let expected = kind
+                .to_string()
+                .map(|x| x.trim_matches('\'').to_string())
+                .unwrap_or_else(|| {
+                    let name = format!("{:?}", kind);
+                    name.strip_suffix("_KW")
+                        .map_or_else(|| name.clone(), |kw| kw.to_ascii_lowercase())
+                });
+            let mut err = if self.cur() == SyntaxKind::EOF {
                 self.err_builder(&format!(
                     "expected `{}` but instead the file ends",
-                    kind.to_string()
-                        .map(|x| x.to_string())
-                        .unwrap_or_else(|| format!("{:?}", kind))
+                    expected
                 ))
                 .primary(self.cur_tok().range, "the file ends here")
             } else {
                 self.err_builder(&format!(
                     "expected `{}` but instead found `{}`",
-                    kind.to_string()
-                        .map(|x| x.to_string())
-                        .unwrap_or_else(|| format!("{:?}", kind)),
+                    expected,
                     self.cur_src()
                 ))
                 .primary(self.cur_tok().range, "unexpected")
             };
+            if let Some(opener) = self.unclosed_delimiter(kind) {
+                self.reported_delimiters
+                    .push((self.errors.len(), opener.start));
+                err = err.secondary(opener, UNCLOSED_DELIMITER);
+            }
 
             self.error(err);
             false
         }
     }
 
+    /// Find the opening delimiter which a missing closing delimiter should have closed.
+    ///
+    /// Delimiters which were already reported as unclosed are skipped, so nested unclosed
+    /// delimiters point to their own opener.
+    fn unclosed_delimiter(&self, closing: SyntaxKind) -> Option<Range<usize>> {
+        if !matches!(closing, T![')'] | T![']'] | T!['}']) {
+            return None;
+        }
+        self.tokens
+            .unclosed_delimiters()
+            .find(|(_, range)| {
+                !self
+                    .reported_delimiters
+                    .iter()
+                    .any(|(_, start)| *start == range.start)
+            })
+            .filter(|(kind, _)| closes(closing, *kind))
+            .map(|(_, range)| range)
+    }
+
+    /// Check if the current token is a closing delimiter which does not close any delimiter, such as the
+    /// second `)` in `foo())`.
+    pub fn at_unmatched_closing_delimiter(&self) -> bool {
+        let closing = self.cur();
+        matches!(closing, T![')'] | T![']'] | T!['}'])
+            && !self
+                .tokens
+                .unclosed_delimiters()
+                .any(|(kind, _)| closes(closing, kind))
+    }
+
     /// Get the byte index range of a completed marker for error reporting.
     pub fn marker_range(&self, marker: &CompletedMarker) -> Range<usize> {
         match self.events[marker.start_pos as usize] {
@@ -387,6 +449,8 @@ impl<'t> Parser<'t> {
         self.tokens.rewind(token_pos);
         self.drain_events(self.cur_event_pos() - event_pos);
         self.errors.truncate(errors_pos);
+        self.reported_delimiters
+            .retain(|(error, _)| *error < errors_pos);
     }
 
     /// Get a checkpoint representing the progress of the parser at this point in time
@@ -437,6 +501,27 @@ impl<'t> Parser<'t> {
         self.error(err);
     }
 
+    /// Whether syntax added in an ECMAScript version can be used. TypeScript files are not
+    /// checked since they are compiled down to the version they target.
+    pub fn supports(&self, version: EcmaVersion) -> bool {
+        self.typescript() || self.syntax.ecma_version >= version
+    }
+
+    /// Add an error for syntax which was added in a newer ECMAScript version than the one targeted.
+    pub fn err_if_newer(&mut self, version: EcmaVersion, feature: &str, span: impl Span) {
+        if self.supports(version) {
+            return;
+        }
+        let err = self
+            .err_builder(&format!(
+                "using {} requires {}, but the targeted version is {}",
+                feature, version, self.syntax.ecma_version
+            ))
+            .primary(span, "");
+
+        self.error(err);
+    }
+
     /// Try running a parser function and backtrack if any errors occured
     pub fn try_parse<F>(&mut self, func: F) -> Option<CompletedMarker>
     where
@@ -33,6 +33,8 @@ pub struct ParserState {
     pub potential_arrow_start: bool,
     /// Whether we are in an async function
     pub in_async: bool,
+    /// Whether we are directly inside of a class static block, where `await` is not allowed
+    pub in_static_block: bool,
     /// Whether we are in strict mode code
     pub strict: Option<StrictMode>,
     /// Whether the code we are parsing is a module
@@ -73,6 +75,7 @@ impl Default for ParserState {
             in_function: false,
             potential_arrow_start: false,
             in_async: false,
+            in_static_block: false,
             strict: None,
             is_module: false,
             default_item: None,
@@ -2,9 +2,11 @@
 
 use super::expr::{assign_expr, identifier_name, object_prop_name, STARTS_EXPR};
 use super::pat::{binding_identifier, opt_binding_identifier, pattern};
-use super::stmt::block_stmt;
+use super::stmt::{block_items, block_stmt};
 use super::typescript::*;
+use super::util::at_function_keyword_typo;
 use crate::{SyntaxKind::*, *};
+use rslint_errors::Applicability;
 use std::collections::HashMap;
 
 pub const BASE_METHOD_RECOVERY_SET: TokenSet = token_set![
@@ -67,7 +69,15 @@ fn class_prop_name(p: &mut Parser) -> Option<(CompletedMarker, Option<usize>)> {
         let m = p.start();
         p.bump_any();
         identifier_name(p);
-        Some((m.complete(p, PRIVATE_NAME), None))
+        let complete = m.complete(p, PRIVATE_NAME);
+        if !p.syntax.class_fields {
+            p.err_if_newer(
+                EcmaVersion::ES2022,
+                "private class members",
+                complete.range(p),
+            );
+        }
+        Some((complete, None))
     } else if let Some(obj) = object_prop_name(p, false) {
         // need to return an index to the token event so class_member can later
         // potentially change it to a get/set keyword
@@ -101,9 +111,17 @@ fn fn_body(p: &mut Parser) {
     if p.typescript() && !p.at(T!['{']) && is_semi(p, 0) {
         p.eat(T![;]);
     } else {
-        let mut complete = block_stmt(p, true, None);
+        let in_declare = p.state.in_declare;
+        let mut complete = block_stmt(
+            &mut p.with_state(ParserState {
+                in_declare: false,
+                ..p.state.clone()
+            }),
+            true,
+            None,
+        );
         if let Some(ref mut block) = complete {
-            if p.state.in_declare {
+            if in_declare {
                 let err = p
                     .err_builder(
                         "function implementations cannot be given in ambient (declare) contexts",
@@ -137,7 +155,28 @@ pub fn function_decl(p: &mut Parser, m: Marker, fn_expr: bool) -> CompletedMarke
     // async function *() {}
     // function *foo() {}
     // yield foo;
-    p.expect(T![function]);
+
+    // test_err function_keyword_typo
+    // functon foo() {}
+    // async fucntion bar() {}
+    // fn baz(a, b) { return a + b; }
+    if at_function_keyword_typo(p, 0) {
+        let err = p
+            .err_builder(&format!("unknown keyword `{}`", p.cur_src()))
+            .primary(p.cur_tok().range, "")
+            .suggestion_with_labels(
+                p.cur_tok().range,
+                "functions are declared with `function`",
+                "function",
+                Applicability::MaybeIncorrect,
+                std::iter::once(0.."function".len()),
+            );
+
+        p.error(err);
+        p.bump_remap(T![function]);
+    } else {
+        p.expect(T![function]);
+    }
     let in_generator = p.eat(T![*]);
     let guard = &mut *p.with_state(ParserState {
         labels: HashMap::new(),
@@ -591,6 +630,17 @@ pub(crate) fn class_body(p: &mut Parser) -> CompletedMarker {
                 p.bump_any();
                 inner.complete(p, EMPTY_STMT);
             }
+            // test class_static_block
+            // class foo {
+            //  static {}
+            //  static {
+            //    let bar = this.baz;
+            //    foo.qux = bar;
+            //  }
+            // }
+            T![ident] if p.cur_src() == "static" && p.nth_at(1, T!['{']) => {
+                class_static_block(p);
+            }
             // test static_method
             // class foo {
             //  static foo(bar) {}
@@ -607,6 +657,36 @@ pub(crate) fn class_body(p: &mut Parser) -> CompletedMarker {
     m.complete(p, CLASS_BODY)
 }
 
+/// A class static initialization block such as `static { foo(); }`, whose statements are
+/// parsed like a function body which cannot `await`, `yield`, `return`, or jump out of the block.
+fn class_static_block(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump_remap(T![static]);
+
+    let mut guard = p.with_state(ParserState {
+        in_function: false,
+        in_async: false,
+        in_static_block: true,
+        in_generator: false,
+        break_allowed: false,
+        continue_allowed: false,
+        labels: HashMap::new(),
+        ..p.state.clone()
+    });
+    guard.bump(T!['{']);
+    block_items(&mut guard, false, false, true, None);
+    guard.expect(T!['}']);
+    let complete = m.complete(&mut guard, CLASS_STATIC_BLOCK);
+    drop(guard);
+
+    p.err_if_newer(
+        EcmaVersion::ES2022,
+        "class static blocks",
+        complete.range(p),
+    );
+    complete
+}
+
 fn maybe_opt(p: &mut Parser) -> Option<Range<usize>> {
     if p.at(T![?]) {
         let range = p.cur_tok().range;
@@ -681,7 +761,11 @@ fn make_prop(
 
     maybe_ts_type_annotation(p);
     if p.eat(T![=]) {
-        assign_expr(p);
+        let init = assign_expr(&mut p.with_state(ParserState {
+            in_declare: false,
+            ..p.state.clone()
+        }));
+        ambient_initializer(p, init, true);
     }
     let start = match p.events[m.old_start as usize] {
         Event::Start { start, .. } => start,
@@ -697,12 +781,9 @@ fn make_prop(
         p.error(err);
     }
     let complete = m.complete(p, kind);
-    if !p.syntax.class_fields {
-        let err = p
-            .err_builder("class fields are unsupported")
-            .primary(complete.range(p), "");
-
-        p.error(err);
+    // private properties are already reported as private class members
+    if kind == CLASS_PROP && !p.syntax.class_fields {
+        p.err_if_newer(EcmaVersion::ES2022, "class fields", complete.range(p));
     }
     if constructor {
         let err = p
@@ -852,23 +933,25 @@ fn class_member_no_semi(p: &mut Parser) -> Option<CompletedMarker> {
     consume_leading_tokens(p, declare, has_accessibility, is_static, false);
 
     let maybe_err = p.start();
-    let (abstract_range, readonly_range) = abstract_readonly_modifiers(p);
-    let has_modifier = abstract_range
+    let ClassMemberModifiers {
+        abstract_: abstract_range,
+        readonly: readonly_range,
+        override_: override_range,
+    } = class_member_modifiers(p);
+    let modifier_range = abstract_range
         .clone()
         .or_else(|| readonly_range.clone())
-        .is_some();
+        .or_else(|| override_range.clone());
+    let has_modifier = modifier_range.is_some();
 
-    if has_modifier {
-        let range = abstract_range
-            .clone()
-            .or_else(|| readonly_range.clone())
-            .unwrap();
+    if let Some(range) = modifier_range.clone() {
         if !p.typescript() {
-            let err = p
-                .err_builder(
-                    "`abstract` and `readonly` modifiers can only be used in TypeScript files",
-                )
-                .primary(range, "");
+            let msg = if abstract_range.is_some() || readonly_range.is_some() {
+                "`abstract` and `readonly` modifiers can only be used in TypeScript files"
+            } else {
+                "`override` modifiers can only be used in TypeScript files"
+            };
+            let err = p.err_builder(msg).primary(range, "");
 
             p.error(err);
             maybe_err.complete(p, ERROR);
@@ -879,6 +962,18 @@ fn class_member_no_semi(p: &mut Parser) -> Option<CompletedMarker> {
         maybe_err.abandon(p);
     }
 
+    let accessor_range = ts_modifier(p, &["accessor"]);
+    if let Some(range) = accessor_range.clone() {
+        // TypeScript has `accessor` fields whether decorators are enabled or not
+        if !p.syntax.decorators && !p.typescript() {
+            let err = p
+                .err_builder("`accessor` fields require decorators to be enabled")
+                .primary(range, "");
+
+            p.error(err);
+        }
+    }
+
     if !is_static && !has_accessibility {
         let check = p.checkpoint();
         if let Some(range) = abstract_range.clone() {
@@ -914,6 +1009,7 @@ fn class_member_no_semi(p: &mut Parser) -> Option<CompletedMarker> {
 
             guard.error(err);
         }
+        accessor_not_allowed(&mut guard, accessor_range);
         if is_constructor {
             let err = guard
                 .err_builder("constructors can't be generators")
@@ -933,6 +1029,7 @@ fn class_member_no_semi(p: &mut Parser) -> Option<CompletedMarker> {
         let async_range = p.cur_tok().range;
         p.bump_remap(T![async]);
         let in_generator = p.eat(T![*]);
+        async_function_version(p, async_range.clone(), in_generator);
         let is_constructor = p.cur_src() == "constructor";
         let mut guard = p.with_state(ParserState {
             in_async: true,
@@ -957,6 +1054,7 @@ fn class_member_no_semi(p: &mut Parser) -> Option<CompletedMarker> {
 
             guard.error(err);
         }
+        accessor_not_allowed(&mut guard, accessor_range);
 
         args_body(&mut *guard);
         drop(guard);
@@ -974,6 +1072,7 @@ fn class_member_no_semi(p: &mut Parser) -> Option<CompletedMarker> {
 
             p.error(err);
         }
+        accessor_not_allowed(p, accessor_range.clone());
 
         if is_constructor {
             if let Some(range) = opt {
@@ -1019,10 +1118,9 @@ fn class_member_no_semi(p: &mut Parser) -> Option<CompletedMarker> {
             }
 
             if has_modifier {
-                let err = p.err_builder("constructors cannot have modifiers").primary(
-                    abstract_range.or_else(|| readonly_range.clone()).unwrap(),
-                    "",
-                );
+                let err = p
+                    .err_builder("constructors cannot have modifiers")
+                    .primary(modifier_range.unwrap(), "");
 
                 p.error(err);
             }
@@ -1064,6 +1162,7 @@ fn class_member_no_semi(p: &mut Parser) -> Option<CompletedMarker> {
 
                 p.error(err);
             }
+            accessor_not_allowed(p, accessor_range);
 
             args_body(p);
             return Some(m.complete(p, if getter { GETTER } else { SETTER }));
@@ -1081,6 +1180,16 @@ fn class_member_no_semi(p: &mut Parser) -> Option<CompletedMarker> {
     None
 }
 
+fn accessor_not_allowed(p: &mut Parser, accessor_range: Option<Range<usize>>) {
+    if let Some(range) = accessor_range {
+        let err = p
+            .err_builder("`accessor` modifiers can only be used on class fields")
+            .primary(range, "");
+
+        p.error(err);
+    }
+}
+
 /// A method definition, this takes an optional markers for object props
 pub fn method(
     p: &mut Parser,
@@ -1127,8 +1236,10 @@ pub fn method(
         //  async *foo() {}
         // }
         T![ident] if p.cur_src() == "async" && !p.has_linebreak_before_n(1) => {
+            let async_range = p.cur_tok().range;
             p.bump_remap(T![async]);
             let in_generator = p.eat(T![*]);
+            async_function_version(p, async_range, in_generator);
             let mut guard = p.with_state(ParserState {
                 in_async: true,
                 in_generator,
@@ -1176,3 +1287,12 @@ pub fn method(
     p.state = old;
     Some(complete)
 }
+
+/// Check that the targeted version supports an async function or method, or an async generator.
+pub(crate) fn async_function_version(p: &mut Parser, async_range: Range<usize>, generator: bool) {
+    if generator {
+        p.err_if_newer(EcmaVersion::ES2018, "async generators", async_range);
+    } else {
+        p.err_if_newer(EcmaVersion::ES2017, "async functions", async_range);
+    }
+}
@@ -12,6 +12,7 @@ use super::pat::pattern;
 use super::typescript::*;
 use super::util::*;
 use crate::{SyntaxKind::*, *};
+use rslint_errors::Applicability;
 
 pub const LITERAL: TokenSet = token_set![TRUE_KW, FALSE_KW, NUMBER, STRING, NULL_KW, REGEX];
 
@@ -60,6 +61,7 @@ pub const STARTS_EXPR: TokenSet = token_set![
     T![class],
     T![import],
     T![super],
+    T![#],
     BACKTICK,
 ]
 .union(LITERAL);
@@ -79,6 +81,16 @@ pub fn literal(p: &mut Parser) -> Option<CompletedMarker> {
     if !p.at_ts(LITERAL) {
         return None;
     }
+    if p.at(NUMBER) {
+        let range = p.cur_tok().range;
+        let (bigint, separators) = (p.cur_src().ends_with('n'), p.cur_src().contains('_'));
+        if bigint {
+            p.err_if_newer(EcmaVersion::ES2020, "BigInt literals", range.clone());
+        }
+        if separators {
+            p.err_if_newer(EcmaVersion::ES2021, "numeric separators", range);
+        }
+    }
     let m = p.start();
     p.bump_any();
     Some(m.complete(p, SyntaxKind::LITERAL))
@@ -187,6 +199,10 @@ fn assign_expr_recursive(
 ) -> Option<CompletedMarker> {
     // TODO: dont always reparse as pattern since it will yield wonky errors for `(foo = true) = bar`
     if p.at_ts(ASSIGN_TOKENS) {
+        if p.at_ts(token_set![T![&&=], T![||=], T![??=]]) {
+            let range = p.cur_tok().range;
+            p.err_if_newer(EcmaVersion::ES2021, "logical assignment operators", range);
+        }
         if p.at(T![=]) {
             if !is_valid_target(p, &target) && target.kind() != TEMPLATE {
                 p.rewind(checkpoint);
@@ -265,10 +281,36 @@ pub fn conditional_expr(p: &mut Parser) -> Option<CompletedMarker> {
 
 /// A binary expression such as `2 + 2` or `foo * bar + 2`
 pub fn binary_expr(p: &mut Parser) -> Option<CompletedMarker> {
-    let left = unary_expr(p);
+    let left = binary_operand(p, 0);
     binary_expr_recursive(p, left, 0)
 }
 
+// test private_name_in
+// class Foo {
+//  #bar;
+//  static isFoo(obj) {
+//    return #bar in obj && obj.#bar === 5 || #bar in {};
+//  }
+// }
+/// An operand of a binary expression, which may be the private name of a brand check like `#foo in bar`
+fn binary_operand(p: &mut Parser, min_prec: u8) -> Option<CompletedMarker> {
+    if p.at(T![#]) && p.nth_at(2, T![in]) && p.state.include_in && min_prec < 7 {
+        let m = p.start();
+        p.bump_any();
+        identifier_name(p);
+        let complete = m.complete(p, PRIVATE_NAME);
+        if !p.syntax.class_fields {
+            p.err_if_newer(
+                EcmaVersion::ES2022,
+                "private names in `in` expressions",
+                complete.range(p),
+            );
+        }
+        return Some(complete);
+    }
+    unary_expr(p)
+}
+
 // test binary_expressions
 // 5 * 5
 // 6 ** 6 ** 7
@@ -302,6 +344,17 @@ fn binary_expr_recursive(
         res.err_if_not_ts(p, "type assertions can only be used in TypeScript files");
         return binary_expr_recursive(p, Some(res), min_prec);
     }
+    if 7 > min_prec && !p.has_linebreak_before_n(0) && p.cur_src() == "satisfies" {
+        let m = left.map(|x| x.precede(p)).unwrap_or_else(|| p.start());
+        p.bump_remap(T![satisfies]);
+        ts_type(p);
+        let mut res = m.complete(p, TS_SATISFIES);
+        res.err_if_not_ts(
+            p,
+            "`satisfies` expressions can only be used in TypeScript files",
+        );
+        return binary_expr_recursive(p, Some(res), min_prec);
+    }
     let kind = match p.cur() {
         T![>] if p.nth_at(1, T![>]) && p.nth_at(2, T![>]) => T![>>>],
         T![>] if p.nth_at(1, T![>]) => T![>>],
@@ -326,6 +379,19 @@ fn binary_expr_recursive(
 
     let op = kind;
     let op_tok = p.cur_tok();
+    match op {
+        T![**] => p.err_if_newer(
+            EcmaVersion::ES2016,
+            "the exponentiation operator",
+            op_tok.range.clone(),
+        ),
+        T![??] => p.err_if_newer(
+            EcmaVersion::ES2020,
+            "the nullish coalescing operator",
+            op_tok.range.clone(),
+        ),
+        _ => {}
+    }
 
     let m = left.map(|m| m.precede(p)).unwrap_or_else(|| p.start());
     if op == T![>>] {
@@ -345,7 +411,7 @@ fn binary_expr_recursive(
         p.error(err);
         None
     } else {
-        unary_expr(p)
+        binary_operand(p, precedence)
     };
 
     binary_expr_recursive(
@@ -467,6 +533,10 @@ pub fn subscripts(p: &mut Parser, mut lhs: CompletedMarker, no_call: bool) -> Co
     // BAR`b
     let mut should_try_parsing_ts = true;
     while !p.at(EOF) {
+        if p.at(T![?.]) {
+            let range = p.cur_tok().range;
+            p.err_if_newer(EcmaVersion::ES2020, "optional chaining", range);
+        }
         match p.cur() {
             T![?.] if p.nth_at(1, T!['(']) => {
                 lhs = {
@@ -547,12 +617,11 @@ pub fn dot_expr(p: &mut Parser, lhs: CompletedMarker, optional_chain: bool) -> C
     };
     if let Some(priv_range) = maybe_private_name(p).filter(|x| x.kind() == PRIVATE_NAME) {
         if !p.syntax.class_fields {
-            let err = p
-                .err_builder("private identifiers are unsupported")
-                .primary(priv_range.range(p), "");
-
-            p.error(err);
-            return m.complete(p, ERROR);
+            p.err_if_newer(
+                EcmaVersion::ES2022,
+                "private class members",
+                priv_range.range(p),
+            );
         }
         if let Some(range) = range {
             let err = p
@@ -756,11 +825,18 @@ pub fn paren_or_arrow_expr(p: &mut Parser, can_be_arrow: bool) -> CompletedMarke
         }
     }
     let has_ret_type = !p.state.in_cond_expr && p.at(T![:]) && !p.state.in_case_cond;
+    // `() >= a` is most likely a typo of `=>` since it can't be a comparison
+    let is_arrow_typo =
+        p.at(T![>=]) && !p.has_linebreak_before_n(0) && (is_empty || spread_range.is_some());
 
     // This is an arrow expr, so we rewind the parser and reparse as parameters
     // This is kind of inefficient but in the grand scheme of things it does not matter
     // FIXME: verify that this logic is correct
-    if (p.at(T![=>]) && !p.has_linebreak_before_n(0)) || has_ret_type || params_marker.is_some() {
+    if (p.at(T![=>]) && !p.has_linebreak_before_n(0))
+        || has_ret_type
+        || params_marker.is_some()
+        || is_arrow_typo
+    {
         if !can_be_arrow && !p.at(T![:]) {
             let err = p
                 .err_builder("Unexpected token `=>`")
@@ -784,6 +860,24 @@ pub fn paren_or_arrow_expr(p: &mut Parser, can_be_arrow: bool) -> CompletedMarke
                 }
             }
 
+            // test_err arrow_expr_gteq_typo
+            // let f = () >= 5;
+            // let g = (...a) >= a;
+            // let h = (a: string) >= a;
+            if p.at(T![>=]) {
+                let err = p
+                    .err_builder("expected `=>` but instead found `>=`")
+                    .primary(p.cur_tok().range, "")
+                    .suggestion_with_labels(
+                        p.cur_tok().range,
+                        "an arrow function uses `=>`",
+                        "=>",
+                        Applicability::MaybeIncorrect,
+                        std::iter::once(0.."=>".len()),
+                    );
+
+                p.error(err);
+            }
             p.bump_any();
             arrow_body(p);
             return m.complete(p, ARROW_EXPR);
@@ -894,6 +988,8 @@ pub fn primary_expr(p: &mut Parser) -> Option<CompletedMarker> {
             // let b = async function foo() {};
             if p.nth_at(1, T![function]) {
                 let m = p.start();
+                let range = p.cur_tok().range;
+                p.err_if_newer(EcmaVersion::ES2017, "async functions", range);
                 p.bump_remap(T![async]);
                 let mut complete = function_decl(
                     &mut *p.with_state(ParserState {
@@ -915,6 +1011,8 @@ pub fn primary_expr(p: &mut Parser) -> Option<CompletedMarker> {
                     // let b = async (bar) => {}
                     // async (foo, bar, ...baz) => foo
                     let m = p.start();
+                    let range = p.cur_tok().range;
+                    p.err_if_newer(EcmaVersion::ES2017, "async functions", range);
                     p.bump_remap(T![async]);
                     if p.at(T!['(']) {
                         formal_parameters(p);
@@ -1123,7 +1221,7 @@ pub fn array_expr(p: &mut Parser) -> CompletedMarker {
             assign_expr(p);
         }
 
-        if p.at(T![']']) {
+        if p.at(T![']']) || at_unclosed_list_end(p, T![']']) {
             break;
         }
 
@@ -1155,6 +1253,9 @@ pub fn object_expr(p: &mut Parser) -> CompletedMarker {
         if first {
             first = false;
         } else {
+            if at_unclosed_list_end(p, T!['}']) {
+                break;
+            }
             p.expect(T![,]);
             if p.at(T!['}']) {
                 break;
@@ -1208,6 +1309,12 @@ pub fn object_property(p: &mut Parser) -> Option<CompletedMarker> {
         // test object_expr_spread_prop
         // let a = {...foo}
         T![...] => {
+            let range = p.cur_tok().range;
+            p.err_if_newer(
+                EcmaVersion::ES2018,
+                "object rest and spread properties",
+                range,
+            );
             p.bump_any();
             assign_expr(p);
             Some(m.complete(p, SPREAD_PROP))
@@ -1358,8 +1465,21 @@ pub fn unary_expr(p: &mut Parser) -> Option<CompletedMarker> {
     const UNARY_SINGLE: TokenSet =
         token_set![T![delete], T![void], T![typeof], T![+], T![-], T![~], T![!]];
 
+    // test top_level_await
+    // await foo;
+    // let bar = await baz();
+
+    // `await` is still parsed as an identifier when it is not followed by an operand, such as `await;`
+    let module_await = p.syntax.is_module()
+        && !p.state.in_function
+        && !p.state.in_static_block
+        && STARTS_EXPR.union(LITERAL).contains(p.nth(1));
     // FIXME: this shouldnt allow await in sync functions
-    if (p.state.in_async || p.syntax.top_level_await) && p.at(T![await]) {
+    if (p.state.in_async || p.syntax.top_level_await || module_await) && p.at(T![await]) {
+        if !p.state.in_async && !p.syntax.top_level_await {
+            let range = p.cur_tok().range;
+            p.err_if_newer(EcmaVersion::ES2022, "top level await", range);
+        }
         let m = p.start();
         p.bump_any();
         unary_expr(p);
@@ -197,6 +197,12 @@ pub fn object_binding_pattern(p: &mut Parser, parameters: bool) -> CompletedMark
 
         if p.at(T![...]) {
             let m = p.start();
+            let range = p.cur_tok().range;
+            p.err_if_newer(
+                EcmaVersion::ES2018,
+                "object rest and spread properties",
+                range,
+            );
             p.bump_any();
 
             pattern(p, parameters, false);
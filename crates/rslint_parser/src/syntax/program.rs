@@ -21,6 +21,10 @@ macro_rules! at_ident_name {
 
 pub fn parse(p: &mut Parser) -> CompletedMarker {
     let m = p.start();
+    // test shebang
+    // #!/usr/bin/env node
+    // // rslint-ignore
+    // let a = 5;
     p.eat(T![shebang]);
     block_items(p, true, true, false, None);
     m.complete(
@@ -53,24 +57,88 @@ fn named_list(p: &mut Parser) -> Marker {
     m
 }
 
+// test import_string_names
+// import { "foo" as bar, "a b" as c } from "mod";
 fn specifier(p: &mut Parser) -> CompletedMarker {
     let m = p.start();
-    identifier_name(p);
+    type_specifier_modifier(p);
+    let string_name = module_export_name(p).filter(|name| name.kind() == LITERAL);
     if p.cur_src() == "as" {
         p.bump_remap(T![as]);
         identifier_name(p);
+    } else if let Some(name) = string_name {
+        // test_err import_string_names_err
+        // import { "foo" } from "mod";
+        let err = p
+            .err_builder("string import names must be bound to an identifier with `as`")
+            .primary(name.range(p), "");
+
+        p.error(err);
     }
     m.complete(p, SPECIFIER)
 }
 
-fn named_export_specifier(p: &mut Parser) -> CompletedMarker {
+// test export_string_names
+// let foo;
+// export { foo as "bar" };
+// export { "baz", "a b" as qux } from "mod";
+// export * as "ns" from "mod";
+/// Parse an export specifier, returning it and whether its local name is a string.
+fn named_export_specifier(p: &mut Parser) -> (CompletedMarker, bool) {
     let m = p.start();
-    identifier_name(p);
+    type_specifier_modifier(p);
+    let string_name = module_export_name(p)
+        .filter(|name| name.kind() == LITERAL)
+        .is_some();
     if p.cur_src() == "as" {
         p.bump_remap(T![as]);
-        identifier_name(p);
+        module_export_name(p);
+    }
+    (m.complete(p, SPECIFIER), string_name)
+}
+
+/// An inline `type` modifier of an import or export specifier such as `import { type Foo } from "foo"`.
+/// `type` is only a modifier if it is followed by the name of the specifier, so `{ type }` and
+/// `{ type as foo }` refer to a binding called `type`.
+fn type_specifier_modifier(p: &mut Parser) {
+    if p.cur_src() != "type" {
+        return;
+    }
+    let is_modifier = match p.nth_src(1) {
+        "as" => p.nth_src(2) == "as" || p.nth_at(2, T![,]) || p.nth_at(2, T!['}']),
+        _ => crate::at_ident_name!(p, 1) || p.nth_at(1, STRING),
+    };
+    if !is_modifier {
+        return;
+    }
+
+    if p.typescript() {
+        p.bump_remap(T![type]);
+    } else {
+        let m = p.start();
+        let err = p
+            .err_builder("type specifiers can only be used in TypeScript files")
+            .primary(p.cur_tok().range, "");
+
+        p.error(err);
+        p.bump_any();
+        m.complete(p, ERROR);
+    }
+}
+
+/// The name of an export, which is either an identifier or an ES2022 string literal.
+fn module_export_name(p: &mut Parser) -> Option<CompletedMarker> {
+    if p.at(STRING) {
+        let complete = literal(p)?;
+        p.err_if_newer(
+            EcmaVersion::ES2022,
+            "arbitrary module namespace names",
+            complete.range(p),
+        );
+        Some(complete)
+    } else {
+        identifier_name(p)
     }
-    m.complete(p, SPECIFIER)
 }
 
 /// An import declaration
@@ -345,7 +413,7 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
         }
         if p.cur_src() == "as" {
             p.bump_remap(T![as]);
-            identifier_name(p);
+            module_export_name(p);
             exports_ns = true;
         }
     }
@@ -406,6 +474,8 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
                 ..p.state.clone()
             });
             let inner = guard.start();
+            let range = guard.cur_tok().range;
+            guard.err_if_newer(EcmaVersion::ES2017, "async functions", range);
             guard.bump_any();
             function_decl(&mut *guard, inner, false);
             return m.complete(&mut *guard, EXPORT_DEFAULT_DECL);
@@ -434,6 +504,8 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
             ..p.state.clone()
         });
         let inner = guard.start();
+        let range = guard.cur_tok().range;
+        guard.err_if_newer(EcmaVersion::ES2017, "async functions", range);
         guard.bump_any();
         function_decl(&mut *guard, inner, false);
     } else if !only_ty && p.at(T![function]) {
@@ -481,14 +553,18 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
         p.expect(T!['{']);
 
         let mut first = true;
+        let mut string_locals = vec![];
 
-        while (!p.at(EOF) && p.at(T![,])) || crate::at_ident_name!(p) {
+        while (!p.at(EOF) && p.at(T![,])) || crate::at_ident_name!(p) || p.at(STRING) {
             if first {
                 first = false;
             } else if p.eat(T![,]) && p.at(T!['}']) {
                 break;
             }
-            named_export_specifier(p);
+            let (specifier, string_local) = named_export_specifier(p);
+            if string_local {
+                string_locals.push(specifier.range(p));
+            }
         }
         p.expect(T!['}']);
         if p.cur_src() == "from" {
@@ -504,6 +580,15 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 
                 p.error(err);
             }
+            // test_err export_string_names_err
+            // export { "foo" as bar };
+            for range in string_locals {
+                let err = p
+                    .err_builder("string names can only be exported from another module")
+                    .primary(range, "");
+
+                p.error(err);
+            }
         }
 
         m.complete(p, EXPORT_NAMED);
@@ -7,7 +7,9 @@ use super::expr::{assign_expr, expr, primary_expr, EXPR_RECOVERY_SET, STARTS_EXP
 use super::pat::*;
 use super::program::{export_decl, import_decl};
 use super::typescript::*;
-use super::util::{check_for_stmt_declarators, check_label_use, check_lhs};
+use super::util::{
+    at_function_keyword_typo, check_for_stmt_declarators, check_label_use, check_lhs,
+};
 use crate::{SyntaxKind::*, *};
 
 pub const STMT_RECOVERY_SET: TokenSet = token_set![
@@ -46,7 +48,9 @@ pub const FOLLOWS_LET: TokenSet = token_set![T!['{'], T!['['], T![ident], T![yie
 pub fn semi(p: &mut Parser, err_range: Range<usize>) {
     // test_err semicolons_err
     // let foo = bar throw foo
-    if p.eat(T![;]) || p.at(EOF) || p.at(T!['}']) {
+
+    // a stray closing delimiter is reported by the next statement
+    if p.eat(T![;]) || p.at(EOF) || p.at(T!['}']) || p.at_unmatched_closing_delimiter() {
         return;
     }
     if !p.has_linebreak_before_n(0) {
@@ -96,7 +100,7 @@ pub fn stmt(
         T![continue] => continue_stmt(p),
         T![throw] => throw_stmt(p),
         T![debugger] => debugger_stmt(p),
-        T![function] => {
+        _ if p.at(T![function]) || at_function_keyword_typo(p, 0) => {
             p.state.decorators_were_valid = true;
             let m = decorator.map(|x| x.precede(p)).unwrap_or_else(|| p.start());
             // TODO: Should we change this to fn_expr if there is no name?
@@ -115,11 +119,13 @@ pub fn stmt(
         }
         T![ident]
             if p.cur_src() == "async"
-                && p.nth_at(1, T![function])
+                && (p.nth_at(1, T![function]) || at_function_keyword_typo(p, 1))
                 && !p.has_linebreak_before_n(1) =>
         {
             p.state.decorators_were_valid = true;
             let m = decorator.map(|x| x.precede(p)).unwrap_or_else(|| p.start());
+            let range = p.cur_tok().range;
+            p.err_if_newer(EcmaVersion::ES2017, "async functions", range);
             p.bump_any();
             function_decl(
                 &mut *p.with_state(ParserState {
@@ -145,6 +151,37 @@ pub fn stmt(
             p.state.decorators_were_valid = false;
             return complete;
         }
+        // test_err else_without_if
+        // let a = 1;
+        // else {
+        //   a = 2;
+        // }
+        // if (a) {} else {} else {}
+        T![else] => {
+            let m = p.start();
+            let err = p.err_builder("`else` without a preceding `if`").primary(
+                p.cur_tok().range,
+                "this `else` does not belong to an `if` statement",
+            );
+
+            p.error(err);
+            p.bump_any();
+            // the body is parsed normally so it doesn't produce more errors
+            stmt(p, recovery_set, None);
+            return Some(m.complete(p, ERROR));
+        }
+        // test_err stray_closing_delimiter
+        // foo());
+        // let a = [1]];
+        // }
+        _ if p.at_unmatched_closing_delimiter() => {
+            let err = p
+                .err_builder(&format!("unmatched `{}`", p.cur_src()))
+                .primary(p.cur_tok().range, "this delimiter is never opened");
+
+            p.err_and_bump(err);
+            return None;
+        }
         _ => {
             let err = p
                 .err_builder("Expected a statement or declaration, but found none")
@@ -412,7 +449,7 @@ pub fn return_stmt(p: &mut Parser) -> CompletedMarker {
     semi(p, start..p.cur_tok().range.end);
     let complete = m.complete(p, RETURN_STMT);
 
-    if !p.state.in_function && !p.syntax.global_return {
+    if !p.state.in_function && (p.state.in_static_block || !p.syntax.global_return) {
         let err = p
             .err_builder("Illegal return statement outside of a function")
             .primary(complete.range(p), "");
@@ -497,7 +534,7 @@ pub(crate) fn block_items(
         };
         let mut is_import_export = false;
 
-        let complete = match p.cur() {
+        let mut complete = match p.cur() {
             // test_err import_decl_not_top_level
             // {
             //  import foo from "bar";
@@ -565,6 +602,12 @@ pub(crate) fn block_items(
             _ => stmt(p, recovery_set, decorator),
         };
 
+        if let Some(ref mut complete) = complete {
+            if top_level {
+                ambient_stmt(p, complete);
+            }
+        }
+
         if let Some(decorator) =
             decorator.filter(|_| !p.state.decorators_were_valid && is_import_export)
         {
@@ -642,7 +685,15 @@ pub fn if_stmt(p: &mut Parser) -> CompletedMarker {
         ..p.state.clone()
     }));
     // allows us to recover from `if (true) else {}`
-    stmt(p, STMT_RECOVERY_SET.union(token_set![T![else]]), None);
+    if p.at(T![else]) {
+        let err = p
+            .err_builder("expected a statement for the body of `if`")
+            .primary(p.cur_tok().range, "expected a statement before this `else`");
+
+        p.error(err);
+    } else {
+        stmt(p, STMT_RECOVERY_SET.union(token_set![T![else]]), None);
+    }
     if p.eat(T![else]) {
         stmt(p, None, None);
     }
@@ -799,7 +850,13 @@ fn declarator(
     let marker = pat_m.complete(p, kind);
 
     if p.eat(T![=]) {
-        p.expr_with_semi_recovery(true);
+        let init = p
+            .with_state(ParserState {
+                in_declare: false,
+                ..p.state.clone()
+            })
+            .expr_with_semi_recovery(true);
+        ambient_initializer(p, init, is_const.is_some());
     } else if marker.kind() != SINGLE_PATTERN && !for_stmt && !p.state.in_declare {
         let err = p
             .err_builder("Object and Array patterns require initializers")
@@ -951,7 +1008,10 @@ pub fn for_stmt(p: &mut Parser) -> CompletedMarker {
     let m = p.start();
     p.expect(T![for]);
     // FIXME: This should emit an error for non-for-of
-    p.eat(T![await]);
+    let await_range = p.cur_tok().range;
+    if p.eat(T![await]) {
+        p.err_if_newer(EcmaVersion::ES2018, "async iteration", await_range);
+    }
 
     p.expect(T!['(']);
     let kind = for_head(p);
@@ -1062,8 +1122,12 @@ pub fn switch_stmt(p: &mut Parser) -> CompletedMarker {
 
 fn catch_clause(p: &mut Parser) {
     let m = p.start();
+    let catch_range = p.cur_tok().range;
     p.expect(T![catch]);
 
+    if !p.at(T!['(']) {
+        p.err_if_newer(EcmaVersion::ES2019, "optional catch binding", catch_range);
+    }
     if p.eat(T!['(']) {
         let m = p.start();
         let kind = pattern(p, false, false).map(|x| x.kind());
@@ -39,28 +39,30 @@ macro_rules! no_recover {
     };
 }
 
-pub(crate) fn abstract_readonly_modifiers(
-    p: &mut Parser,
-) -> (Option<Range<usize>>, Option<Range<usize>>) {
-    let (mut abstract_, mut readonly) = (None, None);
-    for _ in 0..2 {
-        match p.cur_src() {
-            "abstract" if abstract_.is_none() => {
-                abstract_ = ts_modifier(p, &["abstract"]);
-                if abstract_.is_none() {
-                    return (abstract_, readonly);
-                }
-            }
-            "readonly" if readonly.is_none() => {
-                readonly = ts_modifier(p, &["readonly"]);
-                if readonly.is_none() {
-                    return (abstract_, readonly);
-                }
-            }
-            _ => return (abstract_, readonly),
+/// The ranges of the `abstract`, `readonly`, and `override` modifiers of a class member.
+#[derive(Debug, Default)]
+pub(crate) struct ClassMemberModifiers {
+    pub abstract_: Option<Range<usize>>,
+    pub readonly: Option<Range<usize>>,
+    pub override_: Option<Range<usize>>,
+}
+
+/// Parse the `abstract`, `readonly`, and `override` modifiers of a class member, in any order.
+pub(crate) fn class_member_modifiers(p: &mut Parser) -> ClassMemberModifiers {
+    let mut modifiers = ClassMemberModifiers::default();
+    for _ in 0..3 {
+        let modifier = match p.cur_src() {
+            "abstract" if modifiers.abstract_.is_none() => &mut modifiers.abstract_,
+            "readonly" if modifiers.readonly.is_none() => &mut modifiers.readonly,
+            "override" if modifiers.override_.is_none() => &mut modifiers.override_,
+            _ => break,
+        };
+        *modifier = ts_modifier(p, &["abstract", "readonly", "override"]);
+        if modifier.is_none() {
+            break;
         }
     }
-    (abstract_, readonly)
+    modifiers
 }
 
 pub fn ts_modifier(p: &mut Parser, modifiers: &[&'static str]) -> Option<Range<usize>> {
@@ -79,6 +81,8 @@ pub fn ts_modifier(p: &mut Parser, modifiers: &[&'static str]) -> Option<Range<u
     let kind = match p.cur_src() {
         "abstract" => T![abstract],
         "readonly" => T![readonly],
+        "override" => T![override],
+        "accessor" => T![accessor],
         _ => unreachable!("unknown modifier"),
     };
     p.bump_remap(kind);
@@ -191,6 +195,75 @@ pub(crate) fn ts_declare(p: &mut Parser) -> Option<CompletedMarker> {
     })
 }
 
+/// Issue an error for an initializer in an ambient context, literal initializers are allowed if
+/// `allow_literal` is true, e.g. for `declare const a = 5;`
+pub(crate) fn ambient_initializer(
+    p: &mut Parser,
+    init: Option<CompletedMarker>,
+    allow_literal: bool,
+) {
+    let init = match init {
+        Some(init) if p.state.in_declare => init,
+        _ => return,
+    };
+    let literal = match init.kind() {
+        LITERAL => true,
+        UNARY_EXPR => {
+            let unary = p.parse_marker::<ast::UnaryExpr>(&init);
+            unary.op() == Some(ast::UnaryOp::Minus)
+                && unary.expr().map(|x| x.syntax().kind()) == Some(LITERAL)
+        }
+        _ => false,
+    };
+    if !literal || !allow_literal {
+        let err = p
+            .err_builder("initializers are not allowed in ambient (declare) contexts")
+            .primary(init.range(p), "");
+
+        p.error(err);
+    }
+}
+
+/// Issue an error for a statement in an ambient context which is not a declaration.
+///
+/// This is called for the items of the top level and of module blocks, which are parsed as top level,
+/// so the statements of nested namespaces are checked too. Statements nested in other statements
+/// are not checked, the statement containing them is already an error.
+pub(crate) fn ambient_stmt(p: &mut Parser, stmt: &mut CompletedMarker) {
+    if !p.state.in_declare {
+        return;
+    }
+    match stmt.kind() {
+        VAR_DECL
+        | FN_DECL
+        | CLASS_DECL
+        | IMPORT_DECL
+        | EXPORT_DECL
+        | EXPORT_DEFAULT_DECL
+        | EXPORT_DEFAULT_EXPR
+        | EXPORT_NAMED
+        | EXPORT_WILDCARD
+        | TS_ENUM
+        | TS_EXPORT_ASSIGNMENT
+        | TS_IMPORT_EQUALS_DECL
+        | TS_INTERFACE_DECL
+        | TS_MODULE_DECL
+        | TS_NAMESPACE_DECL
+        | TS_NAMESPACE_EXPORT_DECL
+        | TS_TYPE_ALIAS_DECL
+        | EMPTY_STMT
+        | ERROR => {}
+        _ => {
+            let err = p
+                .err_builder("statements are not allowed in ambient (declare) contexts")
+                .primary(stmt.range(p), "");
+
+            p.error(err);
+            stmt.change_kind(p, ERROR);
+        }
+    }
+}
+
 pub(crate) fn ts_decl(p: &mut Parser) -> Option<CompletedMarker> {
     if p.cur_src() == "abstract" {
         p.state.decorators_were_valid = true;
@@ -312,6 +385,11 @@ pub fn ts_ambient_external_module_decl(
         p.expect(STRING);
     }
     if p.at(T!['{']) {
+        // modules with a string name and global augmentations are always ambient
+        let p = &mut *p.with_state(ParserState {
+            in_declare: true,
+            ..p.state.clone()
+        });
         ts_module_block(p);
     } else {
         semi(p, start..end);
@@ -642,6 +720,9 @@ pub fn ts_type(p: &mut Parser) -> Option<CompletedMarker> {
 pub fn ts_fn_or_constructor_type(p: &mut Parser, fn_type: bool) -> Option<CompletedMarker> {
     let m = p.start();
     if !fn_type {
+        if p.cur_src() == "abstract" {
+            p.bump_remap(T![abstract]);
+        }
         p.expect_no_recover(T![new])?;
     }
 
@@ -682,7 +763,7 @@ pub fn ts_non_conditional_type(p: &mut Parser) -> Option<CompletedMarker> {
         return ts_fn_or_constructor_type(p, true);
     }
 
-    if p.at(T![new]) {
+    if p.at(T![new]) || (p.cur_src() == "abstract" && p.nth_at(1, T![new])) {
         return ts_fn_or_constructor_type(p, false);
     }
 
@@ -1062,6 +1143,17 @@ pub fn ts_type_params(p: &mut Parser) -> Option<CompletedMarker> {
 
 fn type_param(p: &mut Parser) -> Option<CompletedMarker> {
     let m = p.start();
+    // `const`, `in`, and `out` are modifiers unless they are the name of the parameter
+    while matches!(p.cur_src(), "const" | "in" | "out")
+        && (p.nth_at(1, T![ident]) || p.nth_src(1) == "in" || p.nth_src(1) == "out")
+    {
+        let kind = match p.cur_src() {
+            "const" => T![const],
+            "in" => T![in],
+            _ => T![out],
+        };
+        p.bump_remap(kind);
+    }
     let mut should_complete =
         if p.at_ts(token_set![T![ident], T![await], T![yield]]) || p.cur().is_keyword() {
             p.bump_remap(T![ident]);
@@ -362,3 +362,77 @@ pub fn check_for_stmt_declarators(p: &mut Parser, marker: &CompletedMarker) {
         p.error(err);
     }
 }
+
+/// Keywords which can only start a statement, and therefore never continue a list on a new line.
+const STMT_ONLY_KEYWORDS: TokenSet = token_set![
+    T![var],
+    T![const],
+    T![if],
+    T![for],
+    T![while],
+    T![do],
+    T![return],
+    T![switch],
+    T![try],
+    T![break],
+    T![continue],
+    T![export],
+];
+
+/// Check if a list which should be closed by `closing` is missing its closing delimiter, because
+/// the current token can not continue the list, so the list can end without swallowing the next statement.
+///
+/// For example `let a = [1, 2;`.
+pub fn at_unclosed_list_end(p: &Parser, closing: SyntaxKind) -> bool {
+    match p.cur() {
+        T![;] => true,
+        T![')'] | T![']'] | T!['}'] => p.cur() != closing,
+        _ if p.has_linebreak_before_n(0) => {
+            p.at_ts(STMT_ONLY_KEYWORDS)
+                || (p.cur_src() == "let" && matches!(p.nth(1), T![ident] | T!['['] | T!['{']))
+        }
+        _ => false,
+    }
+}
+
+/// Check if the nth token is a misspelled `function` keyword of a function declaration such as `functon foo() {}`.
+///
+/// Two identifiers followed by a parenthesis are never valid, so this can't mistake valid code for a typo.
+pub fn at_function_keyword_typo(p: &Parser, n: usize) -> bool {
+    p.nth_at(n, T![ident])
+        && p.nth_at(n + 1, T![ident])
+        && p.nth_at(n + 2, T!['('])
+        && !p.has_linebreak_before_n(n + 1)
+        && is_function_keyword_typo(p.nth_src(n))
+}
+
+fn is_function_keyword_typo(word: &str) -> bool {
+    matches!(word, "fn" | "fun" | "func" | "funct") || edit_distance(word, "function") <= 2
+}
+
+/// The amount of insertions, deletions, substitutions, and transpositions of adjacent chars
+/// needed to turn one string into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let mut dist = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in dist[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            dist[i][j] = (dist[i - 1][j] + 1)
+                .min(dist[i][j - 1] + 1)
+                .min(dist[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                dist[i][j] = dist[i][j].min(dist[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    dist[a.len()][b.len()]
+}
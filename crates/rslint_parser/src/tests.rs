@@ -1,4 +1,6 @@
-use crate::{ast::Module, parse_module, Parse, ParserError};
+use crate::{
+    ast::Module, parse_module, parse_with_syntax, EcmaVersion, FileKind, Parse, ParserError, Syntax,
+};
 use expect_test::expect_file;
 use rslint_errors::{file::SimpleFiles, Emitter};
 use std::fs;
@@ -38,30 +40,154 @@ fn parser_tests() {
         let parse = try_parse(path.to_str().unwrap(), text);
         let errors = parse.errors();
         assert_errors_are_present(errors, path);
-        let mut files = SimpleFiles::new();
-        files.add(
-            path.file_name().unwrap().to_string_lossy().to_string(),
-            text.to_string(),
-        );
         let mut ret = format!("{:#?}", parse.syntax());
-
-        for diag in parse.errors() {
-            let mut write = rslint_errors::termcolor::Buffer::no_color();
-            let mut emitter = Emitter::new(&files);
-            emitter
-                .emit_with_writer(diag, &mut write)
-                .expect("failed to emit diagnostic");
-
-            ret.push_str(&format!(
-                "--\n{}",
-                std::str::from_utf8(write.as_slice()).expect("non utf8 in error buffer")
-            ));
-        }
+        ret.push_str(&render_errors(errors, text, path));
         ret.push_str(&format!("--\n{}", text));
         ret
     });
 }
 
+/// The recovery corpus records how many errors every kind of mistake produces,
+/// any error after the first one of a file is a follow-on error of the recovery.
+#[test]
+fn recovery_tests() {
+    dir_tests(&test_data_dir(), &["recovery"], "errors", |text, path| {
+        let parse = try_parse(path.to_str().unwrap(), text);
+        let errors = parse.errors();
+        assert_errors_are_present(errors, path);
+        format!(
+            "errors: {}\n{}",
+            errors.len(),
+            render_errors(errors, text, path)
+        )
+    });
+}
+
+fn render_errors(errors: &[ParserError], text: &str, path: &Path) -> String {
+    let mut files = SimpleFiles::new();
+    files.add(
+        path.file_name().unwrap().to_string_lossy().to_string(),
+        text.to_string(),
+    );
+    let mut ret = String::new();
+
+    for diag in errors {
+        let mut write = rslint_errors::termcolor::Buffer::no_color();
+        let mut emitter = Emitter::new(&files);
+        emitter
+            .emit_with_writer(diag, &mut write)
+            .expect("failed to emit diagnostic");
+
+        ret.push_str(&format!(
+            "--\n{}",
+            std::str::from_utf8(write.as_slice()).expect("non utf8 in error buffer")
+        ));
+    }
+    ret
+}
+
+/// The files of the ECMAScript version corpus are parsed as modules targeting the version
+/// of their directory, such as `ecma_version/err/es2019/optional_chaining.js`.
+#[test]
+fn ecma_version_tests() {
+    let syntax = |path: &Path| {
+        let version = path
+            .parent()
+            .unwrap()
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap();
+        Syntax::default()
+            .module()
+            .ecma_version(version.parse::<EcmaVersion>().unwrap())
+    };
+
+    for dir in versions_in_dir(&test_data_dir().join("ecma_version/ok")) {
+        for (path, text) in collect_js_files(&dir, &["."]) {
+            let parse = parse_with_syntax(&text, 0, syntax(&path));
+            assert_errors_are_absent(parse.errors(), &path);
+        }
+    }
+    for dir in versions_in_dir(&test_data_dir().join("ecma_version/err")) {
+        dir_tests(&dir, &["."], "errors", |text, path| {
+            let parse = parse_with_syntax(text, 0, syntax(path));
+            assert_errors_are_present(parse.errors(), path);
+            render_errors(parse.errors(), text, path)
+        });
+    }
+}
+
+fn versions_in_dir(dir: &Path) -> Vec<PathBuf> {
+    let mut acc = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    acc.sort();
+    acc
+}
+
+/// The TypeScript error cases the parser does not reject yet.
+const TS_ERR_ACCEPTED: [&str; 8] = [
+    "class/parameter-properties-binding-patterns",
+    "custom/top-level-await-jsc-target",
+    "nullish-coalescing-operator/no-paren-and-nullish",
+    "nullish-coalescing-operator/no-paren-nullish-and",
+    "nullish-coalescing-operator/no-paren-nullish-or",
+    "nullish-coalescing-operator/no-paren-or-nullish",
+    "types/tuple-optional-invalid",
+    "variable-declarator/definite-assignment-not-allowed",
+];
+
+#[test]
+fn ts_parser_tests() {
+    for path in ts_files_in_dir(&test_data_dir().join("ts/ok")) {
+        let text = fs::read_to_string(&path).expect("Could not read ts file");
+        let parse = parse_with_syntax(&text, 0, Syntax::from_path(&path).unwrap());
+        assert_errors_are_absent(parse.errors(), &path);
+    }
+
+    let err_dir = test_data_dir().join("ts/err");
+    for path in ts_files_in_dir(&err_dir) {
+        let case = path.parent().unwrap().strip_prefix(&err_dir).unwrap();
+        if TS_ERR_ACCEPTED
+            .iter()
+            .any(|accepted| case == Path::new(accepted))
+        {
+            continue;
+        }
+        let text = fs::read_to_string(&path).expect("Could not read ts file");
+        let parse = parse_with_syntax(&text, 0, Syntax::from_path(&path).unwrap());
+        assert_errors_are_present(parse.errors(), &path);
+    }
+}
+
+#[test]
+fn ts_accessor_without_decorators() {
+    let mut syntax = Syntax::new(FileKind::TypeScript);
+    syntax.decorators = false;
+    let parse = parse_with_syntax("class A { accessor a = 1 }", 0, syntax);
+    assert_errors_are_absent(parse.errors(), Path::new("accessor.ts"));
+
+    let syntax = Syntax::new(FileKind::Module).class_fields();
+    let parse = parse_with_syntax("class A { accessor a = 1 }", 0, syntax);
+    assert_errors_are_present(parse.errors(), Path::new("accessor.js"));
+}
+
+/// Snapshots of the syntax trees of TypeScript files, for syntax the inline tests can not cover
+/// because they are parsed as JavaScript.
+#[test]
+fn ts_snapshot_tests() {
+    for path in ts_files_in_dir(&test_data_dir().join("ts_snapshots")) {
+        let text = fs::read_to_string(&path).expect("Could not read ts file");
+        let parse = parse_with_syntax(&text, 0, Syntax::from_path(&path).unwrap());
+        assert_errors_are_absent(parse.errors(), &path);
+        let actual = format!("{:#?}", parse.syntax());
+        expect_file![path.with_extension("rast")].assert_eq(&actual);
+    }
+}
+
 fn dir_tests<F>(test_data_dir: &Path, paths: &[&str], outfile_extension: &str, f: F)
 where
     F: Fn(&str, &Path) -> String,
@@ -106,6 +232,24 @@ fn js_files_in_dir(dir: &Path) -> Vec<PathBuf> {
     acc
 }
 
+/// The TypeScript test data is nested in a directory for every test.
+fn ts_files_in_dir(dir: &Path) -> Vec<PathBuf> {
+    let mut acc = Vec::new();
+    for file in fs::read_dir(dir).unwrap() {
+        let path = file.unwrap().path();
+        if path.is_dir() {
+            acc.extend(ts_files_in_dir(&path));
+        } else if ["ts", "mts", "cts"]
+            .iter()
+            .any(|ext| path.extension().unwrap_or_default() == *ext)
+        {
+            acc.push(path);
+        }
+    }
+    acc.sort();
+    acc
+}
+
 fn assert_errors_are_present(errors: &[ParserError], path: &Path) {
     assert!(
         !errors.is_empty(),
@@ -1,6 +1,7 @@
-use crate::{SyntaxKind::EOF, TextRange, TextSize, Token};
+use crate::{SyntaxKind, SyntaxKind::EOF, TextRange, TextSize, Token, T};
 use rslint_lexer::is_linebreak;
 use std::collections::HashSet;
+use std::ops::Range;
 
 /// The source of tokens for the parser
 #[derive(Clone)]
@@ -17,6 +18,10 @@ pub struct TokenSource<'t> {
 
     /// Current token and position
     cur: (Token, usize),
+
+    /// The index of the innermost opening delimiter which is not closed before each token, the last
+    /// entry is for the end of the file. An opening delimiter's entry is the delimiter enclosing it.
+    enclosing_delimiters: Vec<Option<usize>>,
 }
 
 fn mk_token(pos: usize, token_offset_pairs: &[(rslint_lexer::Token, TextSize)]) -> Token {
@@ -49,6 +54,25 @@ fn mk_token(pos: usize, token_offset_pairs: &[(rslint_lexer::Token, TextSize)])
     }
 }
 
+/// Match the delimiters of the tokens, see `TokenSource::enclosing_delimiters`. Closing delimiters
+/// close the innermost opening delimiter whatever its kind, and are ignored if nothing is open.
+fn enclosing_delimiters(tokens: &[(rslint_lexer::Token, TextSize)]) -> Vec<Option<usize>> {
+    let mut open = vec![];
+    let mut enclosing = Vec::with_capacity(tokens.len() + 1);
+    for (idx, (token, _)) in tokens.iter().enumerate() {
+        enclosing.push(open.last().copied());
+        match token.kind {
+            T!['('] | T!['['] | T!['{'] | SyntaxKind::DOLLARCURLY => open.push(idx),
+            T![')'] | T![']'] | T!['}'] => {
+                open.pop();
+            }
+            _ => {}
+        }
+    }
+    enclosing.push(open.last().copied());
+    enclosing
+}
+
 impl<'t> TokenSource<'t> {
     /// Generate input from tokens(except comments and whitespace).
     ///
@@ -82,12 +106,14 @@ impl<'t> TokenSource<'t> {
         }
 
         let first = mk_token(0, token_offset_pairs.as_slice());
+        let enclosing_delimiters = enclosing_delimiters(&token_offset_pairs);
         TokenSource {
             source,
             token_offset_pairs,
             cur: (first, 0),
             tokens_after_linebreaks,
             raw_tokens,
+            enclosing_delimiters,
         }
     }
 
@@ -145,6 +171,19 @@ impl<'t> TokenSource<'t> {
         self.token_offset_pairs[self.cur.1].1.into()
     }
 
+    /// The kinds and ranges of the opening delimiters before the current token which are not closed,
+    /// from the innermost one outwards.
+    pub fn unclosed_delimiters(&self) -> impl Iterator<Item = (SyntaxKind, Range<usize>)> + '_ {
+        let innermost = self.enclosing_delimiters[self.cur.1.min(self.token_offset_pairs.len())];
+        std::iter::successors(innermost, move |idx| self.enclosing_delimiters[*idx]).map(
+            move |idx| {
+                let (token, offset) = self.token_offset_pairs[idx];
+                let start = usize::from(offset);
+                (token.kind, start..start + token.len)
+            },
+        )
+    }
+
     pub fn cur_token_idx(&self) -> usize {
         self.cur.1
     }
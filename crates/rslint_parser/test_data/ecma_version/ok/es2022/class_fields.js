@@ -0,0 +1,4 @@
+class A {
+  a = 1;
+  static b;
+}
@@ -0,0 +1 @@
+await foo();
@@ -0,0 +1,3 @@
+let f = () >= 5;
+let g = (...a) >= a;
+let h = (a: string) >= a;
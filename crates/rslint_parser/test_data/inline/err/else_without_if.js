@@ -0,0 +1,5 @@
+let a = 1;
+else {
+  a = 2;
+}
+if (a) {} else {} else {}
@@ -0,0 +1 @@
+export { "foo" as bar };
@@ -0,0 +1,3 @@
+functon foo() {}
+async fucntion bar() {}
+fn baz(a, b) { return a + b; }
@@ -0,0 +1,3 @@
+foo());
+let a = [1]];
+}
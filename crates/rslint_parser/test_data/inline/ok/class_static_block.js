@@ -0,0 +1,7 @@
+class foo {
+ static {}
+ static {
+   let bar = this.baz;
+   foo.qux = bar;
+ }
+}
@@ -0,0 +1,4 @@
+let foo;
+export { foo as "bar" };
+export { "baz", "a b" as qux } from "mod";
+export * as "ns" from "mod";
@@ -0,0 +1 @@
+import { "foo" as bar, "a b" as c } from "mod";
@@ -0,0 +1,6 @@
+class Foo {
+ #bar;
+ static isFoo(obj) {
+   return #bar in obj && obj.#bar === 5 || #bar in {};
+ }
+}
@@ -0,0 +1,3 @@
+#!/usr/bin/env node
+// rslint-ignore
+let a = 5;
@@ -0,0 +1,2 @@
+await foo;
+let bar = await baz();
@@ -0,0 +1,3 @@
+let f = () >= 5;
+let g = (...a) >= a;
+let x = 5;
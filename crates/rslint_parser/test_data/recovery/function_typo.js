@@ -0,0 +1,7 @@
+functon foo() {
+  return 1;
+}
+funcion bar(a) {
+  return a;
+}
+async fucntion baz() {}
@@ -0,0 +1,2 @@
+foo());
+let x = 5;
@@ -0,0 +1,4 @@
+function foo() {
+  if (a) {
+    b();
+}
@@ -0,0 +1,2 @@
+foo(a, b;
+let x = 5;
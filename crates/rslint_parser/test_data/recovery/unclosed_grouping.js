@@ -0,0 +1,2 @@
+let x = (a + b;
+let y = 5;
@@ -0,0 +1,4 @@
+if (a {
+  b();
+}
+let x = 5;
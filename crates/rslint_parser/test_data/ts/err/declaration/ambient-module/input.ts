@@ -0,0 +1,5 @@
+declare module "foo" {
+    export function bar() {}
+    export const baz = compute();
+    bar();
+}
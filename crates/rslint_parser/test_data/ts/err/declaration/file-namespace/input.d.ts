@@ -0,0 +1,6 @@
+export namespace Outer {
+    export namespace Inner {
+        const value: number;
+        if (value) {}
+    }
+}
@@ -0,0 +1,9 @@
+export function parse(text: string) {
+    return text;
+}
+declare let current = 5;
+export declare class Parser {
+    source = getSource();
+    parse() {}
+}
+console.log("side effect");
@@ -0,0 +1,6 @@
+declare global {
+    interface Window {
+        app: string;
+    }
+    window.app = "rslint";
+}
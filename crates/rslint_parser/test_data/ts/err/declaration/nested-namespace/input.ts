@@ -0,0 +1,6 @@
+declare namespace Outer {
+    namespace Inner {
+        export function run(): void;
+        run();
+    }
+}
@@ -0,0 +1,4 @@
+import { config } from "./config";
+with (config) {
+    run();
+}
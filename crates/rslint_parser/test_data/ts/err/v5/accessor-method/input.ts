@@ -0,0 +1,3 @@
+class Foo {
+  accessor foo() {}
+}
@@ -0,0 +1,12 @@
+declare module "foo" {
+    export function bar(): void;
+    export const baz: number;
+}
+
+module "qux" {
+    export default function quux(): void;
+}
+
+function impl() {
+    return 5;
+}
@@ -0,0 +1,2 @@
+declare function create(): void;
+export = create;
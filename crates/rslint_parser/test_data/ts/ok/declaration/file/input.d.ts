@@ -0,0 +1,32 @@
+/// <reference types="node" />
+import { Foo } from "./foo";
+
+declare const VERSION = "1.0.0";
+declare const MAX: -1;
+declare let current: number;
+export declare function parse(text: string): Foo;
+export declare class Parser {
+    private readonly source;
+    static readonly DEFAULT = 5;
+    constructor(source: string);
+    parse(): Foo;
+    get length(): number;
+}
+export interface Options {
+    strict?: boolean;
+}
+export type Result = Foo | undefined;
+export declare enum Kind {
+    A,
+    B,
+}
+export declare namespace util {
+    function format(value: unknown): string;
+    const EMPTY: "";
+}
+declare global {
+    interface Window {
+        parser: Parser;
+    }
+}
+export default Parser;
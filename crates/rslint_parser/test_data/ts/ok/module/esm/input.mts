@@ -0,0 +1,3 @@
+import { load } from "./load";
+const config: Record<string, unknown> = await load();
+export default config;
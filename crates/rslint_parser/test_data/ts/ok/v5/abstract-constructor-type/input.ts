@@ -0,0 +1,3 @@
+type Ctor = abstract new () => object;
+let foo: abstract new <T>(x: T) => T;
+function bar(x: abstract new (...args: any[]) => Foo) {}
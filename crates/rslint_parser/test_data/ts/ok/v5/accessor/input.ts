@@ -0,0 +1,7 @@
+class Foo {
+  accessor foo = 5;
+  static accessor bar: string;
+  private accessor baz?: number;
+  accessor #qux;
+  accessor = 6;
+}
@@ -0,0 +1,5 @@
+function foo<const T extends readonly unknown[]>(x: T): T {
+  return x;
+}
+class Bar<const T> {}
+const baz = <const T,>(x: T) => x;
@@ -0,0 +1,8 @@
+import { type Foo, Bar, type Baz as Qux } from "foo";
+import { type as } from "bar";
+import { type as as alias } from "baz";
+import { type } from "qux";
+import type { Quux } from "quux";
+export { type Foo, Bar };
+export { type Corge as Grault } from "corge";
+export type { Quux };
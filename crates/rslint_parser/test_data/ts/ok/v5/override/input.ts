@@ -0,0 +1,10 @@
+abstract class Foo extends Bar {
+  override foo() {}
+  public override bar = 5;
+  static override readonly baz = 6;
+  override get qux() { return 5; }
+  abstract override quux(): void;
+  override async corge() {}
+  readonly override grault = 7;
+  override = 8;
+}
@@ -0,0 +1,5 @@
+const foo = { bar: 5 } satisfies Record<string, number>;
+const baz = [1, 2] satisfies number[] as readonly number[];
+qux(quux satisfies Corge && grault);
+let satisfies = 5;
+satisfies;
@@ -0,0 +1,8 @@
+type Getter<out T> = () => T;
+type Setter<in T> = (value: T) => void;
+interface State<in out T> {
+  get: () => T;
+  set: (value: T) => void;
+}
+type Out<out> = out;
+type In<in extends string> = string;
@@ -0,0 +1,2 @@
+type A = abstract new () => object;
+let b: abstract new (value: string) => Base;
@@ -0,0 +1,6 @@
+class A extends B {
+  override foo(): void {}
+  public override readonly bar = 1;
+  accessor baz = 2;
+  static accessor qux: string;
+}
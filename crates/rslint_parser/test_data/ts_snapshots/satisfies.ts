@@ -0,0 +1,2 @@
+const a = { b: 1 } satisfies Record<string, number>;
+const c = (d satisfies string) as unknown;
@@ -0,0 +1,6 @@
+function f<const T>(value: T): T {
+  return value;
+}
+interface Producer<out T> {}
+interface Consumer<in T> {}
+type Both<in out T> = (value: T) => T;
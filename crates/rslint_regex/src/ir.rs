@@ -19,6 +19,8 @@ bitflags! {
         const U = 0b00010000;
         /// "Sticky" mode
         const Y = 0b00100000;
+        /// Generate the start and end indices of the matched substrings
+        const D = 0b01000000;
     }
 }
 
@@ -104,6 +104,7 @@ pub fn validate_flags(flags_str: &str, ecma_version: EcmaVersion) -> Result<ir::
             'u' if ecma_version >= EcmaVersion::ES2015 => flags |= ir::Flags::U,
             'y' if ecma_version >= EcmaVersion::ES2015 => flags |= ir::Flags::Y,
             's' if ecma_version >= EcmaVersion::ES2018 => flags |= ir::Flags::S,
+            'd' if ecma_version >= EcmaVersion::ES2022 => flags |= ir::Flags::D,
             _ => return Err(format!("Invalid flag {}", flag)),
         }
     }
@@ -1131,3 +1131,12 @@ fn unicode_property_escape_invalid_2018() {
         Ok(())
     );
 }
+
+#[test]
+fn has_indices_flag() {
+    assert!(crate::validate_flags("dg", EcmaVersion::ES2022).is_ok());
+    assert!(crate::validate_flags("d", EcmaVersion::ES2021).is_err());
+
+    let parser = crate::Parser::new("/a/d", 0, 0, EcmaVersion::ES2022, false).unwrap();
+    assert!(parser.parse().unwrap().flags.contains(crate::Flags::D));
+}
@@ -14,6 +14,7 @@ pub enum EcmaVersion {
     ES2019,
     ES2020,
     ES2021,
+    ES2022,
 }
 
 struct PatternVersions {
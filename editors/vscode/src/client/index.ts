@@ -16,6 +16,7 @@ export async function launch(context: vscode.ExtensionContext): Promise<lspClien
     },
   };
   const serverOptions: lspClient.ServerOptions = { debug, run };
+  const config = vscode.workspace.getConfiguration("vscode-rslint");
   const clientOptions: lspClient.LanguageClientOptions = {
     diagnosticCollectionName: "rslint-lsp",
     documentSelector: [
@@ -30,11 +31,19 @@ export async function launch(context: vscode.ExtensionContext): Promise<lspClien
       fileEvents: [
         vscode.workspace.createFileSystemWatcher("**/*.js"),
         vscode.workspace.createFileSystemWatcher("**/*.mjs"),
+        vscode.workspace.createFileSystemWatcher("**/*.cjs"),
         vscode.workspace.createFileSystemWatcher("**/*.ts"),
+        vscode.workspace.createFileSystemWatcher("**/*.mts"),
+        vscode.workspace.createFileSystemWatcher("**/*.cts"),
         vscode.workspace.createFileSystemWatcher("**/rslintrc.toml"),
       ],
     },
     middleware: {} as lspClient.Middleware,
+    // plugins are only loaded when the server starts
+    initializationOptions: {
+      incorrectFileAutofixes: config.get("incorrectFileAutofixes", true),
+      loadPlugins: config.get("loadPlugins", false),
+    },
   };
   const languageClient = new lspClient.LanguageClient(
     "rslint-lsp",
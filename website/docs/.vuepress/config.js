@@ -46,6 +46,7 @@ module.exports = {
             "/guide/directives",
             "/guide/formatters",
             "/guide/autofix",
+            "/guide/plugins",
           ]
         }
       ],
@@ -136,6 +136,10 @@ pub(crate) const KINDS_SRC: KindsSrc = KindsSrc {
         "infer",
         "get",
         "set",
+        "satisfies",
+        "accessor",
+        "override",
+        "out",
     ],
     literals: &["NUMBER", "STRING", "REGEX"],
     tokens: &[
@@ -242,6 +246,7 @@ pub(crate) const KINDS_SRC: KindsSrc = KindsSrc {
         "FOR_STMT_INIT",
         "PRIVATE_NAME",
         "CLASS_PROP",
+        "CLASS_STATIC_BLOCK",
         "PRIVATE_PROP",
         "CONSTRUCTOR",
         "CONSTRUCTOR_PARAMETERS",
@@ -295,6 +300,7 @@ pub(crate) const KINDS_SRC: KindsSrc = KindsSrc {
         "TS_NON_NULL",
         "TS_ASSERTION",
         "TS_CONST_ASSERTION",
+        "TS_SATISFIES",
         "TS_ENUM",
         "TS_ENUM_MEMBER",
         "TS_TYPE_ALIAS_DECL",
@@ -631,6 +637,7 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
         }
 
         struct TsConstructorType {
+            T![abstract],
             T![new],
             params: ParameterList,
             T![=>],
@@ -657,6 +664,9 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
         }
 
         struct TsTypeParam {
+            T![const],
+            T![in],
+            T![out],
             T![ident],
             constraint: TsConstraint,
             default: TsDefault
@@ -698,6 +708,17 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
             T![>]
         }
 
+        /// An expression checked against a type without changing the type of the expression
+        ///
+        /// ```ts
+        /// let colors = { red: [255, 0, 0] } satisfies Record<string, number[]>;
+        /// ```
+        struct TsSatisfies {
+            expr: Expr,
+            T![satisfies],
+            ty: TsType
+        }
+
         struct TsEnum {
             T![ident],
             T![const],
@@ -1180,6 +1201,7 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
 
         struct Getter {
             decorators: [TsDecorator],
+            T![override],
             T![get],
             key: PropName,
             parameters: ParameterList,
@@ -1188,6 +1210,7 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
 
         struct Setter {
             decorators: [TsDecorator],
+            T![override],
             T![set],
             key: PropName,
             parameters: ParameterList,
@@ -1398,6 +1421,7 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
         struct Method {
             decorators: [TsDecorator],
             T![static],
+            T![override],
             T![async],
             T![*],
             name: PropName,
@@ -1416,6 +1440,8 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
             T![abstract],
             T![static],
             accessibility: TsAccessibility,
+            T![override],
+            T![accessor],
             key: PrivateName,
             T![?],
             T![!],
@@ -1430,6 +1456,8 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
             T![abstract],
             T![static],
             accessibility: TsAccessibility,
+            T![override],
+            T![accessor],
             key: PropName,
             T![?],
             T![!],
@@ -1438,6 +1466,22 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
             T![;]
         }
 
+        /// A block of statements run once when the class is initialized
+        ///
+        /// ```js
+        /// class A {
+        ///     static {
+        ///         A.b = 1;
+        ///     }
+        /// }
+        /// ```
+        struct ClassStaticBlock {
+            T![static],
+            T!['{'],
+            stmts: [Stmt],
+            T!['}'],
+        }
+
         struct Constructor {
             decorators: [TsDecorator],
             accessibility: TsAccessibility,
@@ -1553,6 +1597,7 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
             Method,
             PrivateProp,
             ClassProp,
+            ClassStaticBlock,
             Constructor,
             TsIndexSignature,
             Getter,
@@ -1640,7 +1685,8 @@ pub(crate) const AST_SRC: AstSrc = AstSrc {
             PrivatePropAccess,
             TsNonNull,
             TsAssertion,
-            TsConstAssertion
+            TsConstAssertion,
+            TsSatisfies
         }
 
         /// Either a single type reference or a fully qualified path
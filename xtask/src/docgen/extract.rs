@@ -43,6 +43,8 @@ impl Parse for Group {
 pub fn parse_rule_file(file: &str) -> Result<Option<RuleFile>> {
     let file = parse_file(file)?;
     let mut tests = None;
+    // typescript tests are only used for the examples of rules without javascript tests
+    let mut ts_tests = None;
     let mut declaration = None;
     for item in file.items {
         if let Item::Macro(macro_call) = item {
@@ -63,6 +65,18 @@ pub fn parse_rule_file(file: &str) -> Result<Option<RuleFile>> {
                 && tests.is_none()
             {
                 tests = Some(parse2::<RuleTests>(call.tokens)?);
+            } else if call
+                .path
+                .segments
+                .last()
+                .map(|x| x.ident == "ts_rule_tests")
+                == Some(true)
+                && ts_tests.is_none()
+            {
+                ts_tests = Some(RuleTests {
+                    typescript: true,
+                    ..parse2::<RuleTests>(call.tokens)?
+                });
             }
         }
     }
@@ -70,7 +84,7 @@ pub fn parse_rule_file(file: &str) -> Result<Option<RuleFile>> {
     if let Some(decl) = declaration {
         Ok(Some(RuleFile {
             lint_declaration: decl,
-            tests,
+            tests: tests.or(ts_tests),
         }))
     } else {
         Ok(None)
@@ -90,6 +104,10 @@ pub struct LintDeclaration {
     pub docstring: Option<String>,
     pub config_fields: Vec<ConfigField>,
     pub tags: Option<Tags>,
+    pub category: Option<String>,
+    pub since: Option<String>,
+    /// The replacement of the rule if it is deprecated, which may be `None` if the rule has no replacement.
+    pub deprecated: Option<Option<String>>,
 }
 
 #[derive(Clone)]
@@ -112,6 +130,36 @@ impl Parse for LintDeclaration {
         } else {
             None
         };
+        let category = if input.peek(kw::category) {
+            input.parse::<kw::category>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let category = content.parse::<Ident>()?.to_string();
+            input.parse::<Token!(,)>()?;
+            Some(category)
+        } else {
+            None
+        };
+        let since = if input.peek(kw::since) {
+            input.parse::<kw::since>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let since = content.parse::<LitStr>()?.value();
+            input.parse::<Token!(,)>()?;
+            Some(since)
+        } else {
+            None
+        };
+        let deprecated = if input.peek(kw::deprecated) {
+            input.parse::<kw::deprecated>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let replacement = content.parse::<Option<LitStr>>()?.map(|lit| lit.value());
+            input.parse::<Token!(,)>()?;
+            Some(replacement)
+        } else {
+            None
+        };
         let name = input.parse::<LitStr>()?.value();
         let _ = input.parse::<Token!(,)>();
 
@@ -125,6 +173,9 @@ impl Parse for LintDeclaration {
             docstring,
             config_fields,
             tags,
+            category,
+            since,
+            deprecated,
         })
     }
 }
@@ -184,12 +235,17 @@ mod kw {
     syn::custom_keyword!(err);
     syn::custom_keyword!(ok);
     syn::custom_keyword!(tags);
+    syn::custom_keyword!(category);
+    syn::custom_keyword!(since);
+    syn::custom_keyword!(deprecated);
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RuleTests {
     pub ok_examples: Vec<Example>,
     pub err_examples: Vec<Example>,
+    /// Whether the examples are TypeScript.
+    pub typescript: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -239,6 +295,7 @@ impl Parse for RuleTests {
         Ok(Self {
             ok_examples,
             err_examples,
+            typescript: false,
         })
     }
 }
@@ -103,12 +103,16 @@ pub fn group_markdown(data: &[(String, RuleFile)], group: &Group) -> String {
 
     for (name, rule) in data {
         let user_facing_name = &name.replace('_', "-")[..name.len() - 3];
+        let docstring = rule.lint_declaration.docstring.clone().unwrap_or_default();
+        let description = first_sentence(&docstring).unwrap_or_default();
+        let deprecated = if rule.lint_declaration.deprecated.is_some() {
+            "**Deprecated.** "
+        } else {
+            ""
+        };
         ret.push_str(&format!(
-            "| [{}](./{}.md) | {} |\n",
-            user_facing_name,
-            user_facing_name,
-            first_sentence(&rule.lint_declaration.docstring.clone().unwrap_or_default())
-                .unwrap_or_default()
+            "| [{}](./{}.md) | {}{} |\n",
+            user_facing_name, user_facing_name, deprecated, description
         ));
     }
     ret.push_str(&format!(
@@ -133,6 +137,10 @@ pub fn extract_group(group_name: &str) -> Result<HashMap<String, RuleFile>, Box<
     let dir = read_dir(project_root().join(GROUPS_ROOT).join(group_name))?;
     let mut res = HashMap::new();
     for file in dir.filter_map(Result::ok) {
+        // rule directories also contain the diagnostic snapshots of the rule tests
+        if file.path().extension() != Some("rs".as_ref()) {
+            continue;
+        }
         if let Some(parsed) = parse_rule_file(&read_to_string(file.path())?)? {
             res.insert(file.file_name().to_string_lossy().to_string(), parsed);
         }
@@ -144,8 +152,10 @@ pub fn rule_markdown(rule: RuleFile, group: &Group) -> String {
     let mut ret = rule
         .lint_declaration
         .docstring
+        .clone()
         .unwrap_or_default()
         .replace("```ignore", "```js");
+    ret.insert_str(0, &rule_metadata_markdown(&rule.lint_declaration));
     ret.insert_str(
         0,
         &format!(
@@ -180,12 +190,14 @@ pub fn rule_markdown(rule: RuleFile, group: &Group) -> String {
     }
 
     if let Some(tests) = rule.tests {
+        let lang = if tests.typescript { "ts" } else { "js" };
         if !tests.err_examples.is_empty() {
             ret.push_str("\n::: details More incorrect examples\n");
             for example in tests.err_examples {
                 ret.push_str(&format!(
-                    "{}\n```js\n{}\n```\n",
+                    "{}\n```{}\n{}\n```\n",
                     example.docstring.unwrap_or_default(),
+                    lang,
                     example.source
                 ));
             }
@@ -195,8 +207,9 @@ pub fn rule_markdown(rule: RuleFile, group: &Group) -> String {
             ret.push_str("\n::: details More correct examples\n");
             for example in tests.ok_examples {
                 ret.push_str(&format!(
-                    "{}\n```js\n{}\n```\n",
+                    "{}\n```{}\n{}\n```\n",
                     example.docstring.unwrap_or_default(),
+                    lang,
                     example.source
                 ));
             }
@@ -210,3 +223,43 @@ pub fn rule_markdown(rule: RuleFile, group: &Group) -> String {
     ));
     ret
 }
+
+/// A notice if the rule is deprecated, and a line with its category, fixes, and the version it was added in.
+pub fn rule_metadata_markdown(declaration: &LintDeclaration) -> String {
+    let mut ret = String::new();
+    if let Some(replacement) = &declaration.deprecated {
+        ret.push_str("::: warning\nThis rule is deprecated");
+        if let Some(replacement) = replacement {
+            ret.push_str(&format!(", use `{}` instead", replacement));
+        }
+        ret.push_str(".\n:::\n\n");
+    }
+
+    let tags = declaration
+        .tags
+        .as_ref()
+        .map(|tags| tags.tags.as_slice())
+        .unwrap_or_default();
+    let has_tag = |tag: &str| tags.iter().any(|t| t == tag);
+    let mut items = vec![];
+    if let Some(category) = &declaration.category {
+        items.push(format!("**Category**: {}", category.to_case(Case::Lower)));
+    }
+    let fix = match (has_tag("Fixable"), has_tag("Suggestions")) {
+        (true, true) => Some("autofix, suggestions"),
+        (true, false) => Some("autofix"),
+        (false, true) => Some("suggestions"),
+        (false, false) => None,
+    };
+    if let Some(fix) = fix {
+        items.push(format!("**Fix**: {}", fix));
+    }
+    if let Some(since) = &declaration.since {
+        items.push(format!("**Since**: v{}", since));
+    }
+    if !items.is_empty() {
+        ret.push_str(&items.join(" | "));
+        ret.push_str("\n\n");
+    }
+    ret
+}